@@ -0,0 +1,37 @@
+//! Turns the worked examples tagged in each day's module docs into tests; see `aoc_examples` for
+//! the tags.
+
+use std::path::Path;
+
+fn main() {
+    println!("cargo:rerun-if-changed=src");
+
+    let mut days: Vec<(u8, std::path::PathBuf)> = std::fs::read_dir("src")
+        .expect("no src directory")
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            let day = path
+                .file_stem()?
+                .to_str()?
+                .strip_prefix("day_")?
+                .parse()
+                .ok()?;
+            Some((day, path))
+        })
+        .collect();
+    days.sort();
+
+    let mut tests = String::new();
+    for (day, path) in days {
+        let source = std::fs::read_to_string(&path).expect("unreadable day module");
+        let examples =
+            aoc_examples::extract(&source).unwrap_or_else(|e| panic!("{}: {}", path.display(), e));
+        if !examples.is_empty() {
+            tests.push_str(&aoc_examples::generate_tests(day, &examples));
+        }
+    }
+
+    let out_dir = std::env::var("OUT_DIR").expect("OUT_DIR is set by cargo");
+    std::fs::write(Path::new(&out_dir).join("doc_examples.rs"), tests)
+        .expect("failed to write the generated tests");
+}
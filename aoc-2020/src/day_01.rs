@@ -24,7 +24,7 @@
 //! and then multiply those two numbers together.
 //!
 //! For example, suppose your expense report contained the following:
-//! ```skip
+//! ```skip example part_one=514579
 //! 1721
 //! 979
 //! 366
@@ -37,65 +37,86 @@
 //! Multiplying them together produces 1721 * 299 = 514579, so the correct
 //! answer is 514579.
 
-pub fn part_one(data: &[i32]) -> i32 {
+use aoc_common::error::parse_at;
+use aoc_common::{Answer, AocError, AocResult, Solution};
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = Vec<i32>;
+
+    fn parse(&self, input: &str) -> AocResult<Self::Input> {
+        parse(input)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> AocResult<Answer> {
+        part_one(input)
+    }
+
+    fn part_two(&self, input: &Self::Input) -> AocResult<Answer> {
+        part_two(input)
+    }
+}
+
+pub fn parse(input: &str) -> AocResult<Vec<i32>> {
+    input
+        .split_whitespace()
+        .map(|entry| parse_at(input, entry, "an expense entry"))
+        .collect()
+}
+
+pub fn part_one(data: &[i32]) -> AocResult<Answer> {
     for (index, first) in data.iter().enumerate() {
         for second in data[index..].iter() {
             if first + second == 2020 {
-                return first * second;
+                return Ok((first * second).into());
             }
         }
     }
-    0
+    Err(AocError::new("no two entries sum to 2020"))
 }
 
-pub fn part_two(data: &[i32]) -> i32 {
+pub fn part_two(data: &[i32]) -> AocResult<Answer> {
     for (index, first) in data.iter().enumerate() {
         for (inner_index, second) in data[index..].iter().enumerate() {
             for third in data[inner_index..].iter() {
                 if first + second + third == 2020 {
-                    return first * second * third;
+                    return Ok((first * second * third).into());
                 }
             }
         }
     }
-    0
+    Err(AocError::new("no three entries sum to 2020"))
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use aoc_common::personal_input;
 
     const EXAMPLE_DATA: [i32; 6] = [1721,979,366,299,675,1456];
 
-    const MY_DATA: [i32; 200] = [
-        1695, 1157, 1484, 1717, 622, 1513, 1924, 63, 1461, 1971, 1382, 1587, 1913, 1665,
-        1464, 1914, 1637, 1527, 1424, 1361, 1187, 272, 1909, 1448, 1623, 1164, 1931, 1646,
-        1096, 1655, 1962, 1961, 1694, 1792, 1989, 1616, 138, 1887, 1357, 1965, 1085, 308,
-        2007, 1254, 1179, 1124, 1719, 1467, 1928, 1630, 1676, 1359, 1241, 1511, 1413, 1656,
-        1818, 1919, 1422, 1745, 1208, 1609, 1544, 1775, 1154, 1057, 1440, 1242, 1202, 1266,
-        1305, 1836, 1760, 1730, 1396, 1315, 1496, 1964, 1300, 1195, 1583, 1607, 1743, 1682,
-        1453, 1848, 1320, 1601, 954, 1473, 1847, 1486, 1853, 1668, 1342, 1087, 1139, 1349,
-        1568, 1728, 1420, 1233, 1073, 1376, 1658, 1477, 1871, 1958, 1950, 1503, 1758, 1474,
-        1203, 1336, 1981, 1309, 1618, 1846, 1974, 1940, 1333, 1119, 1756, 1918, 961, 1307,
-        1375, 1346, 1611, 1284, 84, 1754, 1608, 2010, 1341, 1136, 1218, 1882, 1911, 1288, 1930,
-        1749, 1952, 1556, 1757, 1761, 1112, 1963, 1186, 1373, 1622, 1973, 1330, 1508, 1222,
-        1226, 1389, 1679, 1584, 1237, 1563, 1763, 1998, 1293, 1642, 95, 1661, 1674, 1100, 1262,
-        1895, 1548, 1400, 1205, 1435, 1156, 1034, 1577, 1701, 1198, 1173, 1500, 1858, 1809, 1780,
-        1412, 1982, 1070, 1523, 1776, 1598, 1113, 1144, 1777, 1313, 1102, 1999, 1405, 1784, 1196
-    ];
-
     #[test]
     fn example() {
-        assert_eq!(514579, part_one(&EXAMPLE_DATA));
+        assert_eq!(Ok(Answer::from(514579)), part_one(&EXAMPLE_DATA));
+    }
+
+    #[test]
+    fn malformed() {
+        let err = parse("1721\n97x9\n366").unwrap_err();
+        assert_eq!((Some(2), Some(1)), (err.line, err.column));
+        assert!(part_one(&[1, 2, 3]).is_err());
     }
 
     #[test]
     fn my_part_one() {
-        println!("part one: {}", part_one(&MY_DATA));
+        let input = personal_input!(crate::YEAR, 1);
+        println!("part one: {}", part_one(&parse(&input).unwrap()).unwrap());
     }
 
     #[test]
     fn my_part_two() {
-        println!("part two: {}", part_two(&MY_DATA));
+        let input = personal_input!(crate::YEAR, 1);
+        println!("part two: {}", part_two(&parse(&input).unwrap()).unwrap());
     }
 }
\ No newline at end of file
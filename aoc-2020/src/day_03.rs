@@ -60,58 +60,106 @@
 //! Starting at the top-left corner of your map and following a slope of right 3 and down 1, how many
 //! trees would you encounter?
 
-fn to_index(map_width: isize, coordinate: (isize, isize)) -> usize {
-    (coordinate.0 + coordinate.1 * map_width) as usize
+use aoc_common::{Answer, AocError, AocResult, Solution};
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Vec<Vec<bool>>;
+
+    fn parse(&self, input: &str) -> AocResult<Self::Input> {
+        parse(input)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> AocResult<Answer> {
+        Ok(part_one(input))
+    }
+
+    fn part_two(&self, input: &Self::Input) -> AocResult<Answer> {
+        Ok(part_two(input))
+    }
 }
 
-pub fn part_one(map: &str, slope: (isize, isize)) -> i32 {
+/// Parses the map into rows of squares, `true` where there's a tree. Every row must be as wide
+/// as the first.
+pub fn parse(map: &str) -> AocResult<Vec<Vec<bool>>> {
     const TREE: char = '#';
+    const OPEN: char = '.';
 
-    let mut trees_hit = 0;
-    let mut coordinate: (isize, isize) = (0, 0);
-    let map_chars: Vec<char> = map.chars().collect();
+    let mut rows: Vec<Vec<bool>> = Vec::new();
+    for (ind, line) in map.lines().enumerate() {
+        if line.is_empty() {
+            continue;
+        }
+
+        let mut row = Vec::with_capacity(line.len());
+        for (col, c) in line.char_indices() {
+            match c {
+                TREE => row.push(true),
+                OPEN => row.push(false),
+                _ => {
+                    let span = &line[col..col + c.len_utf8()];
+                    return Err(AocError::at(line, span, format!("expected `#` or `.`, found `{}`", c))
+                        .in_line(ind));
+                }
+            }
+        }
 
-    // find width for modulo operations when traversing
-    let mut width: isize = 0;
-    for character in &map_chars {
-        if *character == '\n' {
-            break;
+        if let Some(first) = rows.first() {
+            if first.len() != row.len() {
+                return Err(AocError::line(
+                    ind,
+                    line,
+                    format!("expected {} squares, found {}", first.len(), row.len()),
+                ));
+            }
         }
-        width += 1;
+        rows.push(row);
     }
-    let width = width;  // make width immutable
 
-    // find height by getting the overall len of the str divided by the width, but also accounting for '\n'
-    let height = map.len() as isize / (width + 1);
+    if rows.is_empty() {
+        return Err(AocError::new("the map is empty"));
+    }
+    Ok(rows)
+}
+
+pub fn count_trees(map: &[Vec<bool>], slope: (usize, usize)) -> i32 {
+    let mut trees_hit = 0;
+    let mut coordinate: (usize, usize) = (0, 0);
+
+    let width = map[0].len();
+    let height = map.len();
 
-    println!(" width: {}", width);
-    println!("height: {}", height);
+    log::info!("width: {}, height: {}", width, height);
 
     // check if we started on a tree (is this valid?)
-    if map_chars[0] == TREE {
+    if map[0][0] {
         trees_hit += 1;
     }
 
     // now traverse!
-    while coordinate.1 != height {
-        coordinate.0 = ((coordinate.0 + slope.0) % width).abs();
+    while coordinate.1 + slope.1 < height {
+        coordinate.0 = (coordinate.0 + slope.0) % width;
         coordinate.1 += slope.1;
 
-        print!("({}, {}) -> ", coordinate.0, coordinate.1);
-        if map_chars[to_index(width + 1, coordinate)] == TREE {
-            println!("HIT");
+        if map[coordinate.1][coordinate.0] {
+            log::trace!("({}, {}) -> HIT", coordinate.0, coordinate.1);
             trees_hit += 1;
         } else {
-            println!("MISS");
+            log::trace!("({}, {}) -> MISS", coordinate.0, coordinate.1);
         }
     }
 
     trees_hit
 }
 
-pub fn part_two(map: &str) -> usize {
+pub fn part_one(map: &[Vec<bool>]) -> Answer {
+    count_trees(map, (3, 1)).into()
+}
+
+pub fn part_two(map: &[Vec<bool>]) -> Answer {
     let mut multiplied_result: usize = 1;
-    const SLOPES: [(isize, isize); 5] = [
+    const SLOPES: [(usize, usize); 5] = [
         (1,1),
         (3,1),
         (5,1),
@@ -120,15 +168,16 @@ pub fn part_two(map: &str) -> usize {
     ];
 
     for slope in &SLOPES {
-        multiplied_result *= part_one(map, *slope) as usize;
+        multiplied_result *= count_trees(map, *slope) as usize;
     }
 
-    multiplied_result
+    multiplied_result.into()
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use aoc_common::personal_input;
 
     const EXAMPLE_DATA: &str = "..##.......
 #...#...#..
@@ -142,342 +191,28 @@ mod test {
 #...##....#
 .#..#...#.#";
 
-    const MY_DATA: &str = ".....#............#....#####.##
-.#.#....#......#....##.........
-......#.#.#.....###.#.#........
-......#...#.....#####....#..##.
-...#............##...###.##....
-#.....#...#....#......##....##.
-#...#.#....#..#..##.##...#.....
-.......#..........#..#..#.#....
-.#.....#.#.......#..#...#....#.
-#..#.##.#..................###.
-...#.#.##...##.###.....#..#...#
-..#.#...#............#.......#.
-#..#.#..#.#....#...#.#.....#..#
-#......##....#..#.#.#........#.
-....#..#.#.#.##............#..#
-....#..#..#...#.#.##......#...#
-##...#...........#.....###.#...
-..#...#.#...#.#.....#....##.##.
-....##...##.#....#.....#.##....
-#........##......#......#.#.#.#
-....#.#.#.........##......#....
-.#......#...#.....##..#....#..#
-....#..#.#.....#..........#..#.
-..##...#..##................#.#
-.....#....#.#..#......#........
-........#..#.#......#.#........
-.....#.#....##.###....#...#....
-...##.#.......#....###..#......
-............##.#..#...#........
-#..###..#.....#.####...........
-.......##.....#......#......#..
-#........##..#.....##.......#.#
-#.##...#...#...#......##..#.#.#
-......#....##.#.#...#...##....#
-#..#....##.#......#.......##...
-.#..........#..........#....#.#
-#.....##......##....#..........
-..#.#.....#.#...#........#.....
-...#........#..#..#.##..##.....
-......###.....#..#...#.###...##
-.##.##.......#.......###...#...
-#.#..#.#.#....#.....###..#...##
-......#.##..........#.......##.
-#..#.#.........#.....##...##...
-..#...#....#....###.#......#...
-.....#..#.######.....#..#.#....
-..#.#.....#.....##.#....##.#.##
-...#.#.#....#....##..#..#.#.##.
-...........#.#...#..#..####....
-.........#####.#.#.#...#.##.#..
-.......#...#......#.##.#.##....
-....#.....#.....###..........#.
-.#.###....##.#..#..........#...
-#...#.........##.....####....#.
-##....##...#..........#........
-...#.#.#.#....#..........#.....
-.......#....#......##.......#..
-.#.#..#.........#.#.##....#....
-..#.............#..##.#.##..###
-.#.##..............#..#..##..#.
-..##.#..#......#...##..#.##...#
-......#..#....#....#....##..#..
-...#...##.............#..###...
-...##....#.#.##........#.....##
-....#.#.......#..###..#....####
-#...#...##..#.####..#...##....#
-.......#..#.##..#...#.#........
-###.#......#..##..#......#.##..
-#....#............#.....#......
-..##...#..##......#..#....#....
-.#..##...#....#.#...#...#..#..#
-........#....###...#..##..###.#
-.........#....#....#..#.#.#...#
-.#....###.##...#.#...........##
-..#..#.#..#.#.##..#...##.......
-##..#.#.#....#...#..#..........
-#..#.......#....#..##...####...
-............#.#..........##.##.
-#...#..#.#....#..#.#....##.....
-......#...#...#.##............#
-#.....##..###..#.#..#.#.##..#.#
-#..#.#..#......#.......##.#....
-##..#.#..#...#......#.##...###.
-.#....#..............#....#.#..
-..#.#..##....#....#..##........
-.#.#...#..#.....#.#..##........
-.....#..#.#......#....#.#..#.#.
-....#.###...###.#.#.....#......
-...........#.#....##....##.....
-..#..#.##..........#...#...#..#
-.....#.###.#..........#........
-....#....##........###...#.....
-.#.....##.......#....#..##..###
-#.....#...............##......#
-#..#.#..#.#.#.....#.#...#......
-.##.###...#....#..........##...
-.#.......#.....................
-.#.#....#...##..#...#...##.....
-.#.#...#.......#.......#...#...
-....#.#..#.#..#...#....##......
-....##.....#.##....#.##..##..##
-..#............#...###.##..#...
-.#..#.........#.##....#....#..#
-.#..##..#..#........#.#.##.#.##
-.###.#...#...............#...#.
-...#.##.##.#......#...#....##.#
-#......##.......##...###....#.#
-#..##.....##......#.#.##....#.#
-...#.#....#.#.#...........##..#
-#.....##......##.#..........##.
-###....#.#...#.#..####.........
-.##.#.#...##..#.....#..#...#...
-#.....#.#......#..........#.#..
-..###.##.#...................#.
-#.............#..#........#.##.
-#.#.#.#..#.....##..##.#....#...
-...#...#..#...#..##..##........
-...##...##..#...##...........#.
-.####..#.#.#.##.#.......#......
-...#....#.......#......#.......
-.....#.#...#...#..##..#..#.....
-......#.....###.#..#..#.#..###.
-.#....#....#..#..##.....##...#.
-.#.............##.###.#...#.#..
-#..#..#......#.###............#
-##.#..##....#..........#.#.#...
-......#........#...#.......##..
-....#.#..#..........#.....#.#..
-...#..#...#.#...#........#.....
-.....##...#....#.........##.##.
-....#...#...#.##.##...#....#...
-.#..#.....##......#..#.#..#....
-........##...##.##......#.#.#.#
-.................#..#.....##.#.
-...#.....#...#.........#..#.#.#
-....##.#.....#........#...#..#.
-#...............#..#.....#...#.
-.....#..#....#...#.####.#.#....
-####.#..#.##...#....#...##.....
-#...##..#...####..#....#.#...#.
-..#.......#.##..##...#.#.......
-...........##.......#....#..#..
-#.##....#...#.....#....##......
-....##.#.......#..#...##.......
-...#.........##.#..#......#.###
-.#..#..#....#.#.##....###..###.
-....#.#........##........##....
-....########....#.#.#.###.#...#
-...#.###.###.##......##.......#
-.#...#.###.......#..........#..
-..#..##.........#............#.
-.......##.#...#...##...#...#..#
-#.##....#.#...#.....#..#.#.....
-..#........#..#.#.#.#....#.##..
-...#...#.#.........#...#.#..##.
-#....#......#.#...........#..##
-...#.#.#..#...##...#...#...#...
-###..........#.#..........#....
-..#....#.#.#.#............#.#..
-....#...#..###...#.#....#......
-#...........####......##.#.....
-..#..##.#...#.....#..#.......##
-#.....#..###.....#...##..##....
-##..###..##...........#.#...#..
-.....#......#..............#...
-#..#.##.###.......#.......#...#
-#........#....##......#.#......
-.#.#.#...#.......#........#.##.
-#..#..##.....#...#.#.#.#..###..
-.#.#....#..#..#.#....##.#.#....
-..#.#.........####.#...#.#.###.
-....##........##....#........#.
-................#..........#...
-..#...................###.##..#
-.........#..#..#.#...#....#.#.#
-......#.....###.....#.#..#...#.
-.#.#.....#..##............##...
-...##......##.#....#...........
-...##..##..###.#...##..........
-....###...#..#.#......#......#.
-....##..............#..#..#.#..
-####.......#...##.##..#.#......
-.#......#.....#....###..#....#.
-.#.......#...##...#..##.#......
-#.......#.......#.#....#.#.#..#
-........#..#..#............##.#
-#.#...#.#..##..#.......##..#...
-...#....#...#..........##..#...
-#.#...#.##....###......##....#.
-#..#...###........#..#....#..#.
-#....#....###....#..#.......#..
-....#.#........#.............#.
-.#.##........##...#...#...#...#
-#.....##.....#.......#.#.#.....
-.#.##..........##..#....#......
-.#..##.##.#...##....#.#....##..
-........#.#.##.#....#.#..#....#
-..#...........................#
-.#...........#....#....#.#..#..
-........##...........#...#...#.
-..#.....#..#......#..##.......#
-..#....###..###...#.#.#..#....#
-#..#.#...#......##......#......
-...........#...##..##....##....
-#.#......###..#.....#.......#.#
-#.....#....#....#.#...#...#....
-....#...#.......#....##.#..#...
-.####..##......##.#........#..#
-..###..#.#.....#...........##..
-..##.#.#..#....#..#..#.........
-..........#.#.#####...#........
-.###......##.#....#........#...
-.....#..#..#.#..#.........#....
-..#....#...#...#...##..........
-....#..##.#.........##.#..##...
-##.####..#...#.#...#.....#..###
-..#..#...#...#.....##....#..#.#
-#..##..#.....#....#.#.....##..#
-...#...........##.....#......#.
-......#...#.....#.#..###.......
-.........#.....###.##..#...#...
-.#...#.##...#..........#.#..##.
-......#.......##.....#.....##..
-........###..........#...#.....
-##.......###..###...##...#.....
-#.#.............#..#..#.#......
-..##........#.###.....#....##..
-......#...#......#....##......#
-..#.....#...##...#.......#..#..
-..#.###..##.##...#....#...##.#.
-........##...#..#.#..##.....#.#
-.......................#......#
-..##.###......#.#.............#
-....#...........###............
-##...##.....#.......##.......#.
-...#..##..##..#.#.###..#......#
-........#........#.#..#..#.....
-.#......#....##..........#...#.
-.##...........##....#..........
-.#..#....###.......#....#..##..
-.....###..........#....#.#.#...
-...#....###.#.#......#......#..
-#.#.##.#.....#..#........#...#.
-...#.##.........#..#.....#.....
-.##...##......##...###...#.....
-...#.....#.##..#...#..#........
-........#............#.#.#..##.
-###...#.....#...#..#........##.
-##...#..#.....#.#....#.#.#.....
-#..##.......#...#.#...##..#....
-#...#.##.....#.#..#.##......#.#
-..#......#.#.#.##.##..........#
-..#.##......#.#.#..##..........
-....#..#....#..#..............#
-..........###.....##..#........
-...#.....##.....#..#.#..#...##.
-.#..##.#..#....#.#......#.##...
-...#.....#..#.#...#..#.....#.#.
-#...#.#......##...#..#...#....#
-..#.......##...#..#.......#...#
-#.....#...........##.#.........
-.#......##.....####...#.......#
-........#..#.....#.......#..#..
-....#.#...##..##...#..#....#...
-#.#......#...#.#.###.....#.....
-..##...#.#........#.##....#.#.#
-.#....#......#.#...###.#.......
-.......#.#...##....#.#....#....
-.....##..##...#..#.#.....##..#.
-.##..#.#.#....##.#...#.....#...
-.#..#..##....#.##.......#...#..
-....#.##...#..##......#.....#..
-.#..#....##....#...............
-..##...#.....###...............
-..............#.#.##........#.#
-.#.#....#....#...#.#........#..
-.##...#...#.#....#....#.#.....#
-#..............#......#.####.#.
-......#...........#..#.....##..
-#.#..##.##.....#......#..#.#..#
-##.##..#.##.#.............#...#
-...#..#......#....#............
-........###.#.#..#...#.....#.##
-..#.......#.##.........#..#....
-...##.#........##...#.#.##..#..
-...#..#......#...#....#........
-...........#..#..#...##...#....
-...#.....#....#..####..##.....#
-.......#..#..#......#.........#
-#......#........###.....##....#
-..#..#..#.#.#....##...##......#
-#.#..#..###.#..#.....####......
-.#................#####....#...
-.#.........#...#.......#......#
-..#.......#######........#.....
-..#........#.....#..#...#..#..#
-.#..#.#..#....#.#..##...#..#.#.
-..#...........#.#...#.#.##.....
-...#.#.#....##.###....#...####.
-.....#..#.....#..#.#.........#.
-......##...#...###............#
-..#.#......###..####..#......#.
-###.##.#..#......##.#..##.....#
-....###...##............#.#....
-..#.....##...#...##....#...#...
-#.....#.....#...#...#.#..#.....
-####..........##.#.#..#.....##.
-...#..........#...#...##..##.#.
-..........#.........#.#..#..#..
-#....###.....#.#...#.......##.#
-#..#.##.....#..........#...#...
-...#.#.###.......##..#.....#...
-#...#.#..#.............#..#.#..
-#........#.................#..#
-..#.#....#.#..##.#...#..#....#.
-#...#..........#...###....#...#
-......#.............#....#....#
-#.#.......##.......#.#....##..#
-##...#....#.............#..#...
-........#...###.##.#..###.#...#
-...##...#..#..#...##..##......#
-..#.......##....#.#.##....#....
-.....#....#..#.#...##.#.#.....#";
-
     #[test]
     fn example() {
-        assert_eq!(7, part_one(EXAMPLE_DATA, (3, 1)));
+        assert_eq!(7, part_one(&parse(EXAMPLE_DATA).unwrap()));
+    }
+
+    #[test]
+    fn malformed() {
+        let err = parse("..#\n.o.\n").unwrap_err();
+        assert_eq!((Some(2), Some(2)), (err.line, err.column));
+        assert_eq!(Some(2), parse("..#\n..\n").unwrap_err().line);
+        assert!(parse("\n").is_err());
     }
 
     #[test]
     fn my_part_one() {
-        println!("part one: {}", part_one(MY_DATA, (3, 1)));
+        let input = personal_input!(crate::YEAR, 3);
+        println!("part one: {}", part_one(&parse(&input).unwrap()));
     }
 
     #[test]
     fn my_part_two() {
-        println!("part two: {}", part_two(MY_DATA));
+        let input = personal_input!(crate::YEAR, 3);
+        println!("part two: {}", part_two(&parse(&input).unwrap()));
     }
 }
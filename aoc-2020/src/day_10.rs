@@ -27,7 +27,7 @@
 //! between the charging outlet, the adapters, and your device?
 //!
 //! For example, suppose that in your bag, you have adapters with the following joltage ratings:
-//! ```skip
+//! ```skip example part_one=35
 //! 16
 //! 10
 //! 15
@@ -64,7 +64,7 @@
 //! In this example, when using every adapter, there are 7 differences of 1 jolt and 5 differences of 3 jolts.
 //!
 //! Here is a larger example:
-//! ```skip
+//! ```skip example part_one=220
 //! 28
 //! 33
 //! 18
@@ -108,12 +108,36 @@
 
 use std::collections::HashMap;
 
-pub fn parse_data(data: &str) -> Vec<usize> {
-    data.split("\n").map(|line| line.parse().unwrap()).collect()
+use aoc_common::error::parse_at;
+use aoc_common::{Answer, AocError, AocResult, Solution};
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Vec<usize>;
+
+    fn parse(&self, input: &str) -> AocResult<Self::Input> {
+        parse_data(input)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> AocResult<Answer> {
+        part_one(input)
+    }
+
+    fn part_two(&self, input: &Self::Input) -> AocResult<Answer> {
+        Ok(part_two(input))
+    }
+}
+
+pub fn parse_data(data: &str) -> AocResult<Vec<usize>> {
+    data.lines()
+        .filter(|line| !line.is_empty())
+        .map(|line| parse_at(data, line, "an adapter joltage"))
+        .collect()
 }
 
-pub fn part_one(data: &str) -> usize {
-    let mut input_adapters = parse_data(data);
+pub fn part_one(adapters: &[usize]) -> AocResult<Answer> {
+    let mut input_adapters = adapters.to_vec();
 
     // initialize our hashmap, tracking the amount of differences in joltage
     let mut differences: HashMap<isize, usize> = HashMap::new();
@@ -122,27 +146,34 @@ pub fn part_one(data: &str) -> usize {
     differences.insert(3, 0);
 
     // our device is always 3 jolts higher than the max adapter
-    let device_adapter: isize = *input_adapters.iter().max().unwrap() as isize + 3;
+    let max_adapter = input_adapters
+        .iter()
+        .max()
+        .ok_or_else(|| AocError::new("no adapters"))?;
+    let device_adapter: isize = *max_adapter as isize + 3;
     let mut joltage: isize = 0;
 
     loop {
         let mut difference = device_adapter;
-        if input_adapters.len() == 0 {
-            panic!("drained input adapters but aren't done?!");
+        if input_adapters.is_empty() {
+            return Err(AocError::new("ran out of adapters before reaching the device"));
         }
 
         for adapter in input_adapters.iter() {
             let diff = *adapter as isize - joltage;
             difference = diff.min(difference);
         }
-        if difference > 3 {
-            panic!("Difference is greater than 3! ({} > 3)", difference);
+        if !(1..=3).contains(&difference) {
+            return Err(AocError::new(format!(
+                "no adapter 1 to 3 jolts above {} (next is {} above)",
+                joltage, difference
+            )));
         }
         *differences.get_mut(&difference).unwrap() += 1;
         input_adapters.retain(|value| *value != (joltage + difference) as usize);
         joltage += difference;
-        println!(
-            "Difference: {}, Joltage: {} (adapter: {})",
+        log::trace!(
+            "difference: {}, joltage: {} (adapter: {})",
             difference, joltage, device_adapter
         );
 
@@ -152,177 +183,52 @@ pub fn part_one(data: &str) -> usize {
         }
     }
 
-    println!("1 diffs: {}", differences.get(&1).unwrap());
-    println!("3 diffs: {}", differences.get(&3).unwrap());
+    log::info!(
+        "1 diffs: {}, 3 diffs: {}",
+        differences.get(&1).unwrap(),
+        differences.get(&3).unwrap()
+    );
 
-    differences.get(&1).unwrap() * differences.get(&3).unwrap()
+    Ok((differences.get(&1).unwrap() * differences.get(&3).unwrap()).into())
 }
 
-pub fn part_two(_data: &str) -> usize {
-    0
+pub fn part_two(_adapters: &[usize]) -> Answer {
+    Answer::from(0)
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use aoc_common::personal_input;
 
     #[test]
     fn example() {
-        assert_eq!(220, part_one(&EXAMPLE_DATA));
-        // assert_eq!(19208, part_two(&EXAMPLE_DATA));
+        assert_eq!(Ok(Answer::from(220)), part_one(&parse_data(EXAMPLE_DATA).unwrap()));
+        // assert_eq!(19208, part_two(&parse_data(EXAMPLE_DATA).unwrap()));
+    }
+
+    #[test]
+    fn malformed() {
+        assert_eq!(Some(2), parse_data("16\n1O\n").unwrap_err().line);
+        // nothing between 1 and 7
+        assert!(part_one(&[1, 7, 8]).is_err());
+        assert!(part_one(&[]).is_err());
     }
 
     #[test]
     fn my_part_one() {
-        let answer = part_one(&MY_DATA);
+        let input = personal_input!(crate::YEAR, 10);
+        let answer = part_one(&parse_data(&input).unwrap()).unwrap();
         println!("part one: {}", answer);
         assert_eq!(2664, answer);
     }
 
     #[test]
     fn my_part_two() {
-        let answer = part_two(&MY_DATA);
+        let input = personal_input!(crate::YEAR, 10);
+        let answer = part_two(&parse_data(&input).unwrap());
         println!("part two: {}", answer);
     }
 
-    const EXAMPLE_DATA: &str = "28
-33
-18
-42
-31
-14
-46
-20
-48
-47
-24
-23
-49
-45
-19
-38
-39
-11
-1
-32
-25
-35
-8
-17
-7
-9
-4
-2
-34
-10
-3";
-
-    const MY_DATA: &str = "147
-174
-118
-103
-67
-33
-96
-28
-43
-22
-16
-138
-75
-148
-35
-6
-10
-169
-129
-115
-21
-52
-58
-79
-46
-7
-139
-104
-91
-51
-172
-57
-49
-126
-95
-149
-125
-123
-112
-30
-78
-44
-37
-167
-157
-29
-173
-98
-36
-63
-111
-160
-18
-8
-9
-159
-179
-72
-110
-2
-53
-150
-17
-81
-97
-108
-102
-56
-135
-166
-168
-163
-1
-25
-3
-158
-101
-132
-144
-45
-140
-34
-156
-178
-105
-68
-153
-80
-82
-59
-50
-122
-69
-85
-109
-40
-124
-119
-94
-88
-13
-180
-177
-133
-66
-134
-60
-141";
+    const EXAMPLE_DATA: &str = crate::doc_examples::day_10::EXAMPLES[1];
 }
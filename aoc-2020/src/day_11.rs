@@ -12,7 +12,7 @@
 //! The seat layout fits neatly on a grid. Each position is either floor (.),
 //! an empty seat (L), or an occupied seat (#). For example, the initial seat
 //! layout might look like this:
-//! ```skip
+//! ```skip example part_one=37
 //! L.LL.LL.LL
 //! LLLLLLL.LL
 //! L.L.L..L..
@@ -108,16 +108,41 @@
 //! Simulate your seating area by applying the seating rules repeatedly until no seats
 //! change state. How many seats end up occupied?
 
-use std::convert::TryFrom;
+use aoc_common::automaton::{Automaton, Neighbors};
+use aoc_common::neighbors::{Adjacency, Edge};
+use aoc_common::render::{Frame, Render, Tone};
+use aoc_common::{Answer, AocResult, Grid, Solution};
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = SeatingChart;
+
+    fn parse(&self, input: &str) -> AocResult<Self::Input> {
+        SeatingChart::from_input(input)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> AocResult<Answer> {
+        Ok(part_one(input))
+    }
 
-#[derive(Debug, PartialEq, Eq)]
-enum Seat {
+    fn part_two(&self, input: &Self::Input) -> AocResult<Answer> {
+        Ok(part_two(input))
+    }
+
+    fn animate(&self, input: &Self::Input) -> AocResult<Vec<Frame>> {
+        Ok(animate(input))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Seat {
     Ocupied,
     Empty,
     Floor,
 }
 
-enum NeighborCountingType {
+pub enum NeighborCountingType {
     LocalNeighbor,
     AnyNeighbor,
 }
@@ -144,319 +169,192 @@ impl Seat {
     }
 }
 
-impl Into<usize> for &Seat {
-    fn into(self) -> usize {
-        match self {
+impl From<&Seat> for usize {
+    fn from(seat: &Seat) -> usize {
+        match seat {
             Seat::Ocupied => 1,
             _ => 0
         }
     }
 }
 
-#[derive(Debug, PartialEq, Eq)]
-struct SeatingChart {
-    state: Vec<Vec<Seat>>,
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SeatingChart {
+    state: Grid<Seat>,
 }
 
 impl std::fmt::Display for SeatingChart {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        for column in self.state.iter() {
-            for seat in column.iter() {
-                write!(f, "{}", seat)?;
-            }
-            write!(f, "\n")?;
-        }
-
-        Ok(())
+        write!(f, "{}", self.state)
     }
 }
 
-impl SeatingChart {
-    pub fn from_str(input: &str) -> SeatingChart {
-        let mut chart = SeatingChart {
-            state: vec![],
-        };
-        for line in input.split("\n") {
-            let mut column = vec![];
-            for char in line.chars() {
-                column.push(Seat::from_char(char).unwrap());
-            }
-            chart.state.push(column);
-        }
-
-        chart
+impl Render for SeatingChart {
+    /// Occupied seats stand out against the empty ones, and the floor fades away
+    fn frame(&self) -> Frame {
+        Frame::from_grid(&self.state, |_, seat| {
+            let tone = match seat {
+                Seat::Ocupied => Tone::Highlight,
+                Seat::Empty => Tone::Normal,
+                Seat::Floor => Tone::Dim,
+            };
+            (seat.to_string(), tone)
+        })
     }
+}
 
-    pub fn get(&self, x: isize, y: isize) -> Option<&Seat> {
-        let x: usize = usize::try_from(x).or::<usize>(Ok(usize::MAX)).unwrap();
-        let y: usize = usize::try_from(y).or::<usize>(Ok(usize::MAX)).unwrap();
-
-        if let Some(column) = self.state.get(x) {
-            return column.get(y);
-        }
-        None
+impl SeatingChart {
+    pub fn from_input(input: &str) -> AocResult<SeatingChart> {
+        let state = Grid::parse(input, "`#`, `L` or `.`", Seat::from_char)?;
+        Ok(SeatingChart { state })
     }
 
     pub fn sum(&self) -> usize {
-        let mut sum: usize = 0;
-        for column in self.state.iter() {
-            for item in column.iter() {
-                let inc: usize = item.into();
-                sum = sum + inc;
-            }
-        }
-
-        sum
+        self.state.iter().map(usize::from).sum()
     }
 
-    pub fn calculate_neighbors(&self, coordinate: (isize, isize), count_type: &NeighborCountingType) -> usize {
-        let mut neighbors: usize = 0;
-        let (x_ind, y_ind) = coordinate;
-
+    /// The seats whose occupants `position` pays attention to. The floor never changes, so this
+    /// is the same every round.
+    fn neighborhood(seats: &Grid<Seat>, position: (usize, usize), count_type: &NeighborCountingType) -> Vec<(usize, usize)> {
         match count_type {
             NeighborCountingType::LocalNeighbor => {
-                for x in x_ind - 1..=x_ind + 1 {
-                    for y in y_ind - 1..=y_ind + 1 {
-                        // add neighbor, excluding ourselves
-                        if !(x == x_ind && y == y_ind)  {
-                            let inc: usize = self.get(x, y).or(Some(&Seat::Empty)).unwrap().into();
-                            neighbors += inc;
-                        }
-                    }
-                }
+                seats
+                    .neighbors(position, Adjacency::Surrounding, &Edge::Clip)
+                    .filter_map(|(position, _)| position)
+                    .collect()
             }
             NeighborCountingType::AnyNeighbor => {
-                // increments for the four coordinates to check
-                let increments: [(isize, isize); 8] = [
-                    (0, 1), (1, 1), (1, 0), (1, -1), (0, -1), (-1, -1), (-1, 0), (-1, 1),
-                ];
-
-                for (x_inc, y_inc) in increments.iter() {
-                    let mut x = x_ind;
-                    let mut y = y_ind;
+                // look outwards in each direction, for the first seat in view
+                let mut neighbors = vec![];
+                for (x_inc, y_inc) in Adjacency::Surrounding.offsets() {
+                    let (mut x, mut y) = (position.0 as isize, position.1 as isize);
                     loop {
                         x += x_inc;
                         y += y_inc;
-                        if let Some(value) = self.get(x, y) {
-                            if *value == Seat::Ocupied {
-                                neighbors += 1;
-                                break;
-                            } else if *value == Seat::Empty {
-                                break;
-                            }
-                        } else {
+                        let Some(seat) = seats.get((x as usize, y as usize)) else {
+                            break;
+                        };
+                        if *seat != Seat::Floor {
+                            neighbors.push((x as usize, y as usize));
                             break;
                         }
                     }
                 }
+                neighbors
             }
         }
+    }
 
-        neighbors
+    /// Plays musical chairs until nobody moves, returning the final arrangement
+    pub fn settle(&self, neighbor_type: NeighborCountingType) -> SeatingChart {
+        self.settle_watching(neighbor_type, |_| ())
     }
 
-    /// Runs a round of musical chairs, returning (newArrangement, changed)
-    pub fn run_iteration(self, neighbor_type: NeighborCountingType) -> (SeatingChart, bool) {
-        let mut new_chart = SeatingChart {state: vec![]};
+    /// [`SeatingChart::settle`], showing `watch` the arrangement after every round that changes
+    /// it
+    fn settle_watching(&self, neighbor_type: NeighborCountingType, mut watch: impl FnMut(&Grid<Seat>)) -> SeatingChart {
         let crouded_threshold = match neighbor_type {
             NeighborCountingType::LocalNeighbor => 4,
             NeighborCountingType::AnyNeighbor => 5,
         };
-
-        for (x_ind, column) in self.state.iter().enumerate() {
-            let mut new_column = vec![];
-            for (y_ind, value) in column.iter().enumerate() {
-                let x_ind = x_ind as isize;
-                let y_ind = y_ind as isize;
-
-                let neighbors = self.calculate_neighbors((x_ind, y_ind), &neighbor_type);
-
-                let new_value = match value {
-                    Seat::Floor => Seat::Floor,
-                    Seat::Empty => {
-                        if neighbors == 0 {
-                            Seat::Ocupied
-                        } else {
-                            Seat::Empty
-                        }
-                    },
-                    Seat::Ocupied => {
-                        if neighbors >= crouded_threshold {
-                            Seat::Empty
-                        } else {
-                            Seat::Ocupied
-                        }
-                    },
-                };
-                new_column.push(new_value);
+        let mut chart = Automaton::with_neighborhood(self.state.clone(), |seats, position| {
+            SeatingChart::neighborhood(seats, position, &neighbor_type)
+        });
+
+        let mut rule = |seat: &Seat, neighbors: Neighbors<'_, Seat>| {
+            let neighbors = neighbors.filter(|seat| **seat == Seat::Ocupied).count();
+            match seat {
+                Seat::Empty if neighbors == 0 => Seat::Ocupied,
+                Seat::Ocupied if neighbors >= crouded_threshold => Seat::Empty,
+                seat => seat.clone(),
             }
-            new_chart.state.push(new_column);
+        };
+        while chart.step(&mut rule) {
+            watch(chart.cells());
         }
+        log::debug!("stable after {} rounds", chart.generation());
 
-        let changed = self != new_chart;
-
-        (new_chart, changed)
+        SeatingChart { state: chart.cells().clone() }
     }
 }
 
-pub fn part_one(data: &str) -> usize {
-    let mut chart = SeatingChart::from_str(data);
-    println!("{}", chart);
-
-    let mut changed = true;
-    while changed {
-        (chart, changed) = chart.run_iteration(NeighborCountingType::LocalNeighbor);
-        println!("{}sum: {}\n", chart, chart.sum());
-    }
+pub fn part_one(chart: &SeatingChart) -> Answer {
+    log::trace!("initial:\n{}", chart);
+    let chart = chart.settle(NeighborCountingType::LocalNeighbor);
+    log::trace!("{}sum: {}", chart, chart.sum());
 
-    chart.sum()
+    chart.sum().into()
 }
 
-pub fn part_two(data: &str) -> usize {
-    let mut chart = SeatingChart::from_str(data);
-    println!("{}", chart);
+pub fn part_two(chart: &SeatingChart) -> Answer {
+    log::trace!("initial:\n{}", chart);
+    let chart = chart.settle(NeighborCountingType::AnyNeighbor);
+    log::trace!("{}sum: {}", chart, chart.sum());
 
-    let mut changed = true;
-    while changed {
-        (chart, changed) = chart.run_iteration(NeighborCountingType::AnyNeighbor);
-        println!("{}sum: {}\n", chart, chart.sum());
+    chart.sum().into()
+}
+
+/// Both parts' rounds of musical chairs, one after the other
+fn animate(chart: &SeatingChart) -> Vec<Frame> {
+    let mut frames = vec![];
+    for (part, neighbor_type) in [(1, NeighborCountingType::LocalNeighbor), (2, NeighborCountingType::AnyNeighbor)] {
+        let mut round = 0;
+        let mut draw = |seats: &Grid<Seat>| {
+            let mut frame = SeatingChart { state: seats.clone() }.frame();
+            frame.push_line(format!("part {}, round {}", part, round), Tone::Dim);
+            frames.push(frame);
+            round += 1;
+        };
+        draw(&chart.state);
+        chart.settle_watching(neighbor_type, draw);
     }
 
-    chart.sum()
+    frames
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use aoc_common::personal_input;
 
     #[test]
     fn example() {
-        // assert_eq!(37, part_one(&EXAMPLE_DATA));
-        assert_eq!(26, part_two(&EXAMPLE_DATA));
+        assert_eq!(37, part_one(&SeatingChart::from_input(EXAMPLE_DATA).unwrap()));
+        assert_eq!(26, part_two(&SeatingChart::from_input(EXAMPLE_DATA).unwrap()));
+    }
+
+    #[test]
+    fn animation() {
+        let frames = animate(&SeatingChart::from_input(EXAMPLE_DATA).unwrap());
+        // five rounds change the seats in part one, and six in part two
+        assert_eq!(6 + 7, frames.len());
+        assert_eq!(37, frames[5].text().matches('#').count());
+        assert!(frames[5].text().ends_with("part 1, round 5\n"));
+        assert!(frames[6].text().starts_with(EXAMPLE_DATA));
+    }
+
+    #[test]
+    fn malformed() {
+        let err = SeatingChart::from_input("L.L\nLxL\n").unwrap_err();
+        assert_eq!((Some(2), Some(2)), (err.line, err.column));
     }
 
     #[test]
     fn my_part_one() {
-        let answer = part_one(&MY_DATA);
+        let input = personal_input!(crate::YEAR, 11);
+        let answer = part_one(&SeatingChart::from_input(&input).unwrap());
         println!("part one: {}", answer);
+        assert_eq!(answer, 2483);
     }
 
     #[test]
     fn my_part_two() {
-        let answer = part_two(&MY_DATA);
+        let input = personal_input!(crate::YEAR, 11);
+        let answer = part_two(&SeatingChart::from_input(&input).unwrap());
         println!("part two: {}", answer);
         assert_eq!(answer, 2285);
     }
 
-    const EXAMPLE_DATA: &str = "L.LL.LL.LL
-LLLLLLL.LL
-L.L.L..L..
-LLLL.LL.LL
-L.LL.LL.LL
-L.LLLLL.LL
-..L.L.....
-LLLLLLLLLL
-L.LLLLLL.L
-L.LLLLL.LL";
-
-    const MY_DATA: &str = "LLLLLLLLLL.LLLLLLLLL.LLLLL.LLLLL.LLLLLLL.LLLL..LLLLLLLL.LLLLLLLL.LLLLLLLL..LLLLLLLL.LLLLLLLL.LLLLL
-LLLLLLLLLL.LLLLLLLLLLLLLLLLLLLLL..LLLLLL.LLLLL.LLLLLLLLLLLLLLLLL.LLLLLLLL.LLLLLLLLL.LLLLLLLL.LLLLL
-LLLLLLLLLLLLLLLLLLLL.LLLLL.LLLLL.LLLLLLL.LLLLLLLLLLLLLL.LLLL.LLLLLLLLLLLL.LLLLLLLLL.LLLL.LLL.LLLLL
-LLLLLLLLLLLLLLLLLLLL.LLLLL.LLLLLLLLLLLLL.LLLLL.LLLLLLLL.LLLLLLLL.LLLLLLLLLLLLLLLLLL.LLL.LLLLLLLLLL
-LLLLLLLLLL.LLLLLLLLL.LLLLL.LLLLL.LLLLLLL.LLLLLLLLLLLLLL.LLLLLLLL.LLLLLLLL.LLLLLLLLLLLLLLLLLL.LLLLL
-LLLLLLLLLL.LLLLLLLLL.LLLLL.L.LLLLLLLLLLL.LLLLLLLLLLLLLL.LLLLLLLL.LLLLLLLLLLLLLLLLLL.LLLLLLLL.LLLLL
-LLLLL.LLLL.LLLLLLLLL.LLLLL.LLLLLLLLLLLLLLL.LLL.LLLLLLLLLLLLL.LLL.LLLL.LLL.LLLLLLLLL.LLLLLLLL.LLLLL
-LLLLLLLLLL.LL.LLLLLL.LLLLL.LLLLL.LLLLLLL.LLLLL.LLLLLLLL.LLLLLLLL.LLLL.LLL.LLLLLLLLL.LLLLLLLL.LLLLL
-LLLLLLLLLLLLLLLLLLLL.LLLLL.LLLLLLLLLLLLL.LLLLL.LLLLLLLL.LLLLLLLLLLLLLLLLLLLLLLLLLLL...LLLLLLLLLLL.
-LL..LL..L..L.L...L.L..L.L....L.LL..L.L......LL.LL....LL..LL..LLLL.L.LLL.L.....LLL.LL........L.....
-LLLLLLLLLLLLLLLLLLLL.LLLLLLLLLLL.LLL..LL.LLLLLLL.LL.LLL.LLLL.LLLLLLLLLLLL.LLLLLLLLL.LLLLLLLL.LLLLL
-LLLLLLLLLL.LLLL.LLLLLLLLLL.LLLLLLLLLLLLLLLLLLL.LLLLLLLL.LLLLLLLLLLLLLLLLLLLLLLLLLLLLLLLLLLLL.LLLLL
-LLLLLLLLLL.LLLLLLLLL.LLLLL.LLLLL.LLLLLLL.LLLLL.LLLLL.LL.LLLLLLL..LLLLLLLL.LL.LLLLLLLLLLLLLLLLLLLLL
-LLLLLLLLL..LLLLLLLLLLLLLLL.LLLLLLLLLLLLL.LLLLLLLLLLLLLL.LLLLLLLLLLLLLLLLL.LLLLLLLLL.LLLLLLLL.LLLLL
-LLLLLLLLLL.LLLLLLLLL.LLLLLLLLLLL.LLLLLLLLLLLLLLLLLLLLLLLLLLLLLLL.LLLLLLLL.LLLLLLLLL.LLLLLLLLLLLLLL
-LLLLLLLLLL.LLLLLLLLLLLLLLLLLLLLL.LLLLLLL.LLLLL.LLLLLLLL.LLLLLLLLLLLLLLLLL.LLLLLLLLL.LLLLLLLLL.LLLL
-LLLLLLLLLL.LLLLLLLLL.LLLLL.LLLLLLLLLLLLL.LLLLLLLLLLLLLLLLLLLLLLL.LLLLLLLL.LLLLLLLLL.LLLLLLLLLLLLLL
-LLLLLLLLLL.LLLLLLLLL.L.LLL.LLLLLLLLLLLLL.LLLLL.LLLLLLLL.LLLLLLLLLLLL.LLLLLLLLLLLLLLLLLLLLLLL.LLLLL
-LLLLLLLLLL.LLLLLLLLLLLLLLL.LLLLL.LLLLLLLLLLLLL.LLLLLLLL.LLLLLLLL.LLLLLLLL.LLLLLLLLLLLLLLLLLLLLLLLL
-...........L.....L............LL..L...LL...L.LLL.L....L.L.L......L..L...LL......L.L.L.........LL..
-LLLLLLLLLL.LLLLLLLLL.LLLLLLLLLLL.LLLLLLL.LLLLLLLLLLLLLLLLLLLLLLL.LLLLLLLL.LLLLLLLLL.LLLLLLLL.LLLLL
-LL.LLLLLLLLLLLLLLLLL.LLLL.LLLLLL.LLLLLLL.LLLLL.LLLLLLLL.LLLLLLLL.LLLLLLLL.LLLLLLLLL.LLLLLLLL.LLLLL
-LLLLLLLLLL.LLLLLLLLL.LLLLL.LLLLLLLLLLLLL.LLLLL.LLLLLLLL.LLLLLLLL.LLLLLLLLLLLLLLLLLL.LLLLLLLL.LLLLL
-LLLLLLLLLLLLLLLLLLLLLLLLLL.LLLLLLLLLLLLLLLLLLL.LLLLLLLL.L.LLLLLLLLLLLLLLLLLLLLLLLLL.LLLLLLLLLLLLLL
-LLLLLLLLLL.LLLLLLLLL.LLLLL.LLLLL..LLLLLL.LLLLLLLLLLLLLL.LLLLLLLL.LLLLLLLLLLLLLLLLLLLLLLLLLLLLLLLLL
-............L......L......L...L....L.....LLL....L.......L.....L.L..L....L..L.L....LL.....L....L.L.
-LLLLLLLLLL..LLLLLLLLLLLLLL.LLLL.LLLLLLLL.LLLLL.LLLLLLLLLLLLLLLLL.LLLLLLLL.LLLLLLLLL.LLLLLLLL.LLLLL
-LLLLLLLLLL.LLLLLLLLLLLLLLL.LL.LLLLLLLLLLLLLLLLLLLLLLLLL.LLLLLLLL.LLLL.LLLLLLLLLLLLL.LLLLLLLLLLLLLL
-LLLLLLLLLL.LLL.LLLLL.LLLLL.LLLLLLLLLLLLLLLLLLL.LLLLLLLLLLLLLLLLL.LLLLLLLL.LLLLLLLLLLLLLLLLLL.LLLLL
-LLLL.LLLLL.LLLLLL.LLLLLLLL.LLLLL.LLLLLLLLLLLLL.LLLLLLLL.LLLLLLLL.LLLLLLLL.LLLLLL.LLLLLLLLLLLLLLLLL
-LLLLLLLLLL.LLLLLLLLL.LLLLL.LLLLL.LLLLLLLLLLLLL.LLLLLLLL.LLLLLLLL.LLLLLLLL.LLLLLLLLL.LLLLLLLLLLLLLL
-LLLLLLLLLLLLLLLLLLLLLLLL.L.LLLLL.LLLLLLL.LLLLLLLLLLLLLL.LLLLLLLL.LLLLLLLL.LLLLLLLLL.LLLLLLLLLLLLLL
-LLLLLLLLLLLLLLLLLLLL.LLLLLLLLLLLLLLLLLLLLLLLLLLLLLLLLLL.LLLLLLLLLLLLLLLLLLLLLLLLLLL.LLLLLLLL.LLLLL
-.LL........L.......L....LL.L..L.L...L.LLLLLL.L..............L.L.........LL........L....L.LL.....L.
-LLLLLLLLLL.LLLLLLLLL.LLLLL.LLL.L.LLLLLLL.LLLLL.LLLLLLLL.LLLLL.LL.LLLLLLLLLLLLLLLLLL.LLLLLLLL.LLLLL
-LLLLLLLLLL.LLLLLLLLL.LLLLL.LLLLL.LL.LLLLLL.LLL.LLLLLLLLLLLLLLLLLLLLL.LLLL.LLLLLLLLLLLLLLLLLL.LLLLL
-LLLLLLLLLL.LLLLLLLLLLLLLLLLLLLLL.LLLLLLL.LLLLL.LLLLLLLL.LLLLLLLLLLL.LLLLLLLLLLLLLLL.LLLLLLLLLLLLLL
-LLLLLLLLLLLLLLLLLLLL.LLLLL.LLLLL.LLLLLLLLLLLLL.LLLLLLLLLLLL.LLLL.LLLLLLLLLLLLLLLLLL.LLLLLLLLLLLLL.
-LLLLLLLLLL.LLLLLLLLL.LLLLL.LLLLL.LLLLLLL.LLLLLLLLLLLLLLLLLLLLLLLLLLLLLLLLLLLLLLLLLLLLLLLLLLLLLLLLL
-LLLLLLLLLL.LLLLLLLLL.LLLLLLLLLLL.LLLLLLLLLLLLLLLLLLLLLLLLLLLLLLL.LLLLLLLL.LLLLLLLLL.LLLLLLLLLLLLL.
-LLLLLLLLLL.LLLLLLLLL.LLLLLLLLLLL.LLLLLLLLLLLLL.LLLLLL.L.LLLLLLLL.LLLLLLLLLLLLLLLLLLLLLLLLLLL.LLLLL
-...L..L..L.LL.L.L.L......L.LLLL.L..L.L..L..L.L...L.LL.L...L....LL..L.L..L.L...........L.L..L..LL..
-LLLLLL.LLL.LLLLLLLLL.LLLLLLLLL.L.LLLLLLL.LLLLL.LLLLLLLL.LLLLLLLLLLLLLLLLL.LLLLLLLLL.LLLLLLLLLLLLLL
-LLLLL.LLLL.LLL.LLLLL.LLLLL.LLLLLLLLLLLL.LLLLLL.L.LLLLLLLLLLLLLLL..LLLLLLL.LLLLLLLLL.LLLLLLLLLLLL.L
-LLLLLLLLLL.LLLLLLLLLLLLLLL.LLLLLLLLLLLLL.LLLLLLLLLLLLLLL.LLLLLLL.LLLLL.LLLLLLLLLLLL.LLLLLLLLLLLLLL
-LLLL.LL.LLLLLLLLLLLL.LLLLL.L.LLLLLLLLLLLLLLLLLLLLLLLLLL.LLLLLLLL.LL.LLLLLLLLLLLLLLLLLLLLLLLL.LLLLL
-LLLLLLLLLL.LLL.LLLLL.LLLLL.LLLLLLLLLLLLLLLLLLL.LLLLLLLL.LLLLLLLLLLLLLLLLLLLLLLLLLLL.LLLLLLLL.LLLLL
-LLLLLLLLLL.LLLLLLLLL.L.LLL..LLL.LLL.LLLL.LLLLL.LLLLLLLL.LLLLLLLL.LLLLLLLLLLLLLLLLLL.LLLLLLLL.LLLLL
-...LL.L..L..........L......L.L..L.LL.......LL...L.LL..L.L..L.L......L..L......L..L..L..L.L........
-LLLLLLL.LL.LLLLLLLLLLLLLLL.LLLLL.LLLLLLL.LLLLL.LLLLLLLL.LLLLLLLL.LLLLLLLLLLLLLLLLLLLLLLLLLLL.LLLLL
-LLL.LLLLLL.LLLLLLLLL.LLLLL.LLLLL.LLLLLLL.LLLLLLLLLLLLLL.LLLLLLLL.LLLLLLLL.LLLLLLL.L.LLLLLLLL.LLLLL
-LLLLLLLLLL.LLLLLLLLL.LLLLLLLLLLLLLLLLLLLLLLLLLLLLLLLLLLLLLLLLLLL.LLLLLL.LLL.LLLLLLL.LLLLLLLL.LLLLL
-LLLLLLLLLL.LLLLLLLLL.LLLLL.LLLLL.LLLLLLLLLLLLL.LLLLLLLLLLLLLLLLL.LLLLLLLLLLLLLLLLLLLLLLLLLLL.LLLLL
-LLLLLLLLLL.LLLLL.LLL.LLL.L.LLLLLLLLLLLLL.LLLLL.LLLLLLLL.LLLLLLLL.LLLLLLLLLLLLLLLLLL.LLLLLLLL.LLLLL
-LLLLLLLLLL.LLLLLLLLLLLLLLL.LLLLLLLLLLLLL.LLLLL.LLLLLLLLLLLLLLLLLLLLLLLLLLLLLLLLLLLL.LLL.LLLLLLLLLL
-LLLLLLL.LLLLLL.LLLLLLLLLLL.LLLLLLLLLLLLLLLLL.L.LLLLLLLL.LLLLLLL.LLLLLLLLL.LLLLLLLLL.LLLLLLLL.LLLLL
-L.....L..LL.L.......L.L..L....L..L.L...L.LLLL.......L.L...L....LL.L...L.L.L.LL.L......L.L.L.L.L...
-LLL.LLLLLLLLLLLLLLLL.LLLLLLLLLLL.LLLLLLL.LLLLL.LLLLLLLL.LLLLLLLLLLLLLLLLL..L.LLLLLLLLLLLLLLLLLLLLL
-L.LLLLLLLL.LLLLLLLLLLLLLLL.LLLLL.LLLLLLL.LLLLLLLLLLLLLL.LLLLLLLL.LLLLLLLL.LLLLLLLLLLLLLLLLLL.LLLLL
-LLLLLLLLLL.LLLLLLLLLLLLLLL.LLLLL.LLLLLLLLLLLLL.LLLLLLLL.LLLLLLLL.LLLLLLLL.LLLLLLLLLLLLLLLLLLLLLLLL
-LLLLLL.LLLLLLLLLLLLL.LLLLLLLLLLL.LLLLLLLLLLLLL.LLLLLLLL..LLLLLLLLLLLLLLLLLLLL.LLLLL.LLLLLLLLLLLLLL
-LLLLLLLLLLLLLLLLLLLLLLLLLL.LLLLL.LLLLLLL.LLLLLLLLLLLLLL.LLLLLLL..LLLLLLLL.LLLLLLLLL.LLLLLLLLLLLLLL
-LLLLLLLLLL.LLLLLLLLLLLLLLL.LLLLL.LLLLLLLLLLLLL.LLLLLLLL.LLLLLLLL.LLLLLLLLLLLLLLLLL..LLLLLLLLLLLLLL
-LLLLLLLLLL.LLLLLLLLLLLLLLLLLLLLL.LLLLLLL.LLLLLLLLLLLLLLLLLLLLLL.LLLLLLLLL.LLLLLLLLLLLLLLLLLLLLLLLL
-.......L..L..L.L....LLLLLL..L..LL.LLL..LL..L..L...L...LL.....L..LL.LL..L.L..L.L..L.L.L....LL....L.
-LLLLLLLLLLLLLLLL.LLL.LLLLL.LLLLL.LLLLLLL.LLLLL.LLLLLLLL.LLLLLLLL.LLL.LLLL.LLLLLLLLL.L.LLLLLLLLLLLL
-LLLLLLL.LL.LLLLLLLLLLLL.LL.LLLLL.LLLLLLLLLLLLL.LLLLLLLL.LLLLLLLL.LLLLLLLL.LLLLLLLLL.LLLLLLLL.LLLLL
-LLLLLLLLLL.LLLLLLLL.LLLLLLLLLLLLL.LLLLLLLLLLLLLLLLL.LLL.LLLLL.LL.LLLLLLLLLLLLLLLLLL.LLLLLLLLLLLLLL
-LLLLLLLLLL.LLLLLLLLLLLL.LL.LLLLL.LLLLLLL.LLLLL.LLLLLLLL.LLLLLLLL.LLLLLLL..LLLLLLLLL.LLLLLLLL.LLLLL
-.....L.L..L.......L.LL..........L...LLLL.LL..L...L.L.L.LLLL...L.....L...L.LLLLLL..L..........LL...
-LLLLLLLLLL.LLLLLLLLLLLLLLL.LLLLL.LLLLLLL.LLLLL.LLLLLLLL.LLLLLLLL.LLLLLLLL.LLLLLLLLLLLLLLLLLLLLLLLL
-LLLLLLLLLL..LLLLLLLL.LLLLLLLLLLLLLLLLLLL.LLLLLLLLLLLLLL.LLLLLLLLLLLLLLLLL.LLLLLLLLLLLLLLLLLLLLLLLL
-LLLLLLLLLL.LLLLLLLLLLLLLLL.LLLLLLLLLLLLL.LLLLL.LLLLLL.L.LLLLLLLL.LLLLLLLLLLLLLLLLLL.LLLLLLLL.LLLLL
-L.LLLLLLLL.LLLLLLLLL.LLLLLLLLLLLLLLLLLLL.LLLLLLLLLLLLLLLLLLLLLLLLLLLLLLLL.LLLLL.LLLLLLLLLLLLLLLLLL
-..L.........L..L.L...L................LLL..L..LLL.L......L....L.L.....L.L.L.L.L..L.L.L.L....L....L
-LLLLLLLLLL..LLLLLLLL.LLLLLLLLLLL.LLLLLLLLLLLLL.LLLLLLLL.LLLLLLLL.LLLLLLLL.LL.LLLLLL.LLLLLLLLLLLLLL
-LLLLLLLLLL.LLLLLLLLL.LLLLLLLLLLL.LLLLLLL.LLLLL.LLLLLLLL.LLLLLLLL.LLLLLLLL.LLLLLLLLL.LLLLLLLL.LLLL.
-LLLLLLLLLL.LLLLLLLLL.LLLLL.LLLLLLLLLLLLL.LLLL..LLLLLLLLLLLLLLLLLLLLLLLLLL.LLLLLLLLL.L.LLLLLL.LLLLL
-LLLLL.LLLLLLLLLLLLLL.LLLLLLLLLLL.LLLLLLLLLLLL.LLLLLLLLL.LLLLLLLL.LLLLLLLLLLLLLLLLLLLLLLLLLLL.LLLLL
-LLLLLLLLLL.LLLL.LLLL.LLLLL.LLLLLLLLLLLLL.LLLLL.LLLLLLLLLLLLLLLLL.LLLLLLLLLLLLLLLLL.LLLLLLLLLLLLLLL
-LLLLLLLLLLLLLLLLLLL..LLLLL.LLLLL.LLLLLLLLLLLLLLLLLLLLLLLLLLLLLLLLLLLLLLLLLLLL.LLLLLLLLLLLLLLLLLLLL
-...LLL.....L..L.LL.L.L.LL.....L..LL.LLLL...L.L....LL.L...L..LL...L..LLL......LL..L..L.L..L.L...LL.
-LLLLLLLLLL.LLLLLLLLL.LLLLL.LLLLL.LLLLLLL.LLLLL.LLLLLLLL.LLL.LLLL.LLLLLLLL.LLLLLLLLL.LLLLLLLL.LLLLL
-LLLLLLLLLLLLLLLLLLLLLLLLLLLLL.LL.LLLLLLL.LLLLLLLLL.LLLL.LLLLLLLL.LLLLLLLL.LL.LLLLL.LLLLLLLLL.LLLLL
-LLLLLLLLLL.LLLLLLLLL.LLLLLLLLLLL.LLLLLLL.LLLLL.LLLLLL.LLLLLLLL.LLLLLLLLLL.LLLLLLLLLLLLLLL.LL.LLLLL
-LLLLLLLLLL.LLLLLLLLL.LLLLL.LLLLLLLLLLLLL.LLLLL.LLLLLLLLLLLLLLLLL.LLL.LLLL.LLLLLLLLL.LLLLLL.L.LLLLL
-LLLLLLLLLLLLLLLLLLLL.LLLLLLLLLLLLLLLLLL..LLLLLLLLLLLLLL.LLLLLLLL.LLLLLLLLLLLLLLLLLL.L.LLLLLL.LLLLL
-LL.LLLLLL..LLLLLLLLL.LLLLL.LLLLL.LLLLLLL.LLLLL.LLLLLLLL.LLLLLLLL.LLLLLLL..LLL.LLLLL.LLLLLLLL.LLLLL
-LLLLLLLLLL.LLLLLLLLL.LLLLL.LLLLLLLLLLLLL.LLLLL.LLLLLLLL..LLLLLL.LLLLLLLLLLLLLLLLLLLLLLLLLLLL.LLLLL
-LLLLLLLLLL.LLLLLLLLL.LLLLLLLLLLLLLLLLLLL.LLLLLLLLLLLLLL.LLLLLLLLLLLLLLLLL.LLLLLLLLLLLLLLLLLLLLLLLL
-...L....L..L.L..L..L.LLLL....L..L.....L.....L.L..L.L.L...L.L.........L....L..L..L.LL.L...L.L...L..
-LLLLLLLLLLLLLLLLLLLL.LLLLL.LLLLL.LLLLLLLLLLLLL.LLLLLLLLLLLLLLLLL.LLLLLLLL..LLLLL.LL.LLLLLLLLLLLLLL
-LLLLLLLLLLLLLLLLLLLL.LLLL..LLLLL.LLLLLLLLLLLLL.LLLLLLLLLLLLLLLLL.LLLLLLLL.LLLLLLLL..LL.LLLLL.LLLLL
-LLLLLLLLLL.LLLLLLLLLLLLLLLLLLLLL.LLLLLLL.LLLLL.LLLLLLLL.LLLLLLLL.LLLLLLLLLLLLLLLLLL.LLLLLLLL.LL.LL
-LLLLLLLLLL.LLLLLLLLL.LLLLL.LLLLL.L.LLLLL.LLLLL.LLLLLLLLLLLLLLLLL.LLLLLLLL.LLLLLLLLL.LLLLLLLL.LLLLL
-LLLLLLLLLLLLLLLLLLLL.LLLLLLLLLLLLLLLLLLLLLLLLL.LLLLLLLLLLLLLLLLLLLLLL.LLL.LLLLLLLLLL.LLLLLLL.LLLLL
-LLLLLLLLLL.LLLLLLLLLLLLLLLLLLLLL.LLLLLL..LLLLLLLLLLLLLL.LLLLLLLL.LLLLLLLL..LLLLLLLL.LLLLLLLL.LLLLL
-LLLLLL.L.L.LLLLLLLLLLLLLLLLLLLLL.LLLLLL.LLLLLL.LLLLLLLL.LLLLLLLL.LLLLLLLL.LLLLLLLLL.LLLLLLLL.LLLLL
-L.LLLLLLLLLLLLLLLLLLLLLLLL.LLLLL.LLLLLLL.LL.LLLLLLLLLLLLLLLLLLLLLLLLLLLLL.LLLLLLLLLLLLLL.LLL..LLLL";
+    const EXAMPLE_DATA: &str = crate::doc_examples::day_11::EXAMPLES[0];
 
 }
\ No newline at end of file
@@ -25,7 +25,7 @@
 //!     ship would move north 10 units, but would still move east if the following action were F.)
 //!
 //! For example:
-//! ```skip
+//! ```skip example part_one=25
 //! F10
 //! N3
 //! F7
@@ -83,6 +83,26 @@
 //! Figure out where the navigation instructions actually lead. What is the Manhattan distance between that
 //! location and the ship's starting position?
 
+use aoc_common::error::parse_at;
+use aoc_common::{Answer, AocError, AocResult, Point2, Solution};
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Vec<(Instruction, isize)>;
+
+    fn parse(&self, input: &str) -> AocResult<Self::Input> {
+        parse_instructions(input)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> AocResult<Answer> {
+        part_one(input)
+    }
+
+    fn part_two(&self, input: &Self::Input) -> AocResult<Answer> {
+        Ok(part_two(input))
+    }
+}
 
 #[derive(Debug, Copy, Clone)]
 pub enum Instruction {
@@ -96,8 +116,8 @@ pub enum Instruction {
 }
 
 impl Instruction {
-    pub fn to_coordinate(self) -> Option<Coordinate> {
-        let coordinate: Option<Coordinate> = match self {
+    pub fn to_coordinate(self) -> Option<Point2> {
+        let coordinate: Option<Point2> = match self {
             Instruction::MoveNorth => Some(Direction::North.into()),
             Instruction::MoveSouth => Some(Direction::South.into()),
             Instruction::MoveEast => Some(Direction::East.into()),
@@ -129,31 +149,6 @@ impl std::ops::Add<isize> for Angle {
     }
 }
 
-#[derive(Debug, Copy, Clone)]
-pub struct Coordinate(isize, isize);
-
-impl std::ops::Add<Coordinate> for Coordinate {
-    type Output = Self;
-
-    fn add(mut self, rhs: Self) -> Self::Output {
-        self.0 += rhs.0;
-        self.1 += rhs.1;
-
-        self
-    }
-}
-
-impl std::ops::Mul<isize> for Coordinate {
-    type Output = Coordinate;
-
-    fn mul(mut self, rhs: isize) -> Self::Output {
-        self.0 *= rhs;
-        self.1 *= rhs;
-
-        self
-    }
-}
-
 #[derive(Debug, Copy, Clone)]
 pub enum Direction {
     North,
@@ -162,20 +157,20 @@ pub enum Direction {
     West,
 }
 
-impl Into<Coordinate> for Direction {
-    fn into(self) -> Coordinate {
-        match self {
-            Direction::North => Coordinate(0, 1),
-            Direction::South => Coordinate(0, -1),
-            Direction::East => Coordinate(1, 0),
-            Direction::West => Coordinate(-1, 0),
+impl From<Direction> for Point2 {
+    fn from(val: Direction) -> Self {
+        match val {
+            Direction::North => Point2::new(0, 1),
+            Direction::South => Point2::new(0, -1),
+            Direction::East => Point2::new(1, 0),
+            Direction::West => Point2::new(-1, 0),
         }
     }
 }
 
-impl Into<Angle> for Direction {
-    fn into(self) -> Angle {
-        match self {
+impl From<Direction> for Angle {
+    fn from(val: Direction) -> Self {
+        match val {
             Direction::North => Angle(0),
             Direction::South => Angle(180),
             Direction::East => Angle(90),
@@ -184,28 +179,30 @@ impl Into<Angle> for Direction {
     }
 }
 
-impl Into<Direction> for Angle {
-    fn into(self) -> Direction {
-        match self {
-            Angle(0) => Direction::North,
-            Angle(180) => Direction::South,
-            Angle(90) => Direction::East,
-            Angle(270) => Direction::West,
-            Angle(_) => panic!("wtf if this {:?}", self),
+impl TryFrom<Angle> for Direction {
+    type Error = AocError;
+
+    fn try_from(val: Angle) -> AocResult<Self> {
+        match val {
+            Angle(0) => Ok(Direction::North),
+            Angle(180) => Ok(Direction::South),
+            Angle(90) => Ok(Direction::East),
+            Angle(270) => Ok(Direction::West),
+            Angle(angle) => Err(AocError::new(format!("{} degrees isn't a compass direction", angle))),
         }
     }
 }
 
 #[derive(Debug, Copy, Clone)]
 pub struct Ship {
-    coordinates: Coordinate,
+    coordinates: Point2,
     direction: Direction,
 }
 
 impl Default for Ship {
     fn default() -> Self {
         Ship {
-            coordinates: Coordinate(0, 0),
+            coordinates: Point2::ORIGIN,
             direction: Direction::East,
         }
     }
@@ -213,875 +210,114 @@ impl Default for Ship {
 
 impl Ship {
     pub fn manhattan_distance(&self) -> usize {
-        self.coordinates.0.abs() as usize + self.coordinates.1.abs() as usize
+        self.coordinates.manhattan()
     }
 
-    pub fn consume_instruction(&mut self, instruction: Instruction, magnitude: isize) {
+    pub fn consume_instruction(&mut self, instruction: Instruction, magnitude: isize) -> AocResult<()> {
         match instruction {
             // turning instructions
             Instruction::TurnLeft => {
                 let dir_angle: Angle = self.direction.into();
-                self.direction = Angle::new(dir_angle + -magnitude).into();
+                self.direction = Angle::new(dir_angle + -magnitude).try_into()?;
             },
             Instruction::TurnRight => {
                 let dir_angle: Angle = self.direction.into();
-                self.direction = Angle::new(dir_angle + magnitude).into();
+                self.direction = Angle::new(dir_angle + magnitude).try_into()?;
             },
 
             // Movement instructions
             Instruction::MoveForward => {
-                let dir_coord: Coordinate = self.direction.into();
-                self.coordinates = self.coordinates + dir_coord * magnitude;
+                let dir_coord: Point2 = self.direction.into();
+                self.coordinates += dir_coord * magnitude;
             },
             // Move E/W/N/S
             _ => {
-                let dir_coord: Coordinate = instruction.to_coordinate().unwrap();
-                self.coordinates = self.coordinates + dir_coord * magnitude;
+                let dir_coord: Point2 = instruction.to_coordinate().unwrap();
+                self.coordinates += dir_coord * magnitude;
             },
 
         }
+        Ok(())
     }
 }
 
-pub fn parse_instructions(data: &str) -> Vec<(Instruction, isize)> {
+pub fn parse_instructions(data: &str) -> AocResult<Vec<(Instruction, isize)>> {
     let mut instructions = vec![];
 
-    for line in data.split("\n") {
-        let chars: Vec<char> = line.chars().collect();
-        let instruction = match chars.get(0).unwrap() {
-            'N' => Instruction::MoveNorth,
-            'E' => Instruction::MoveEast,
-            'W' => Instruction::MoveWest,
-            'S' => Instruction::MoveSouth,
-            'L' => Instruction::TurnLeft,
-            'R' => Instruction::TurnRight,
-            'F' => Instruction::MoveForward,
-            _  => panic!("invalid instruction: {}", line),
+    for line in data.lines().filter(|line| !line.is_empty()) {
+        let letter_len = line.chars().next().map_or(0, char::len_utf8);
+        let (letter, magnitude_str) = line.split_at(letter_len);
+        let instruction = match letter {
+            "N" => Instruction::MoveNorth,
+            "E" => Instruction::MoveEast,
+            "W" => Instruction::MoveWest,
+            "S" => Instruction::MoveSouth,
+            "L" => Instruction::TurnLeft,
+            "R" => Instruction::TurnRight,
+            "F" => Instruction::MoveForward,
+            _  => return Err(AocError::at(data, letter, format!("unknown instruction `{}`", letter))),
         };
-        let magnitude: String = chars[1..].iter().collect();
-        let magnitude: isize = magnitude.parse().unwrap();
+        let magnitude: isize = parse_at(data, magnitude_str, "a distance or angle")?;
+        if matches!(instruction, Instruction::TurnLeft | Instruction::TurnRight) && magnitude % 90 != 0 {
+            return Err(AocError::at(data, magnitude_str, "turns must be a multiple of 90 degrees"));
+        }
 
         instructions.push((instruction, magnitude));
     }
 
-    instructions
+    Ok(instructions)
 }
 
-pub fn part_one(data: &str) -> usize {
+pub fn part_one(instructions: &[(Instruction, isize)]) -> AocResult<Answer> {
     let mut ship = Ship::default();
-    for (instruction, magnitude) in parse_instructions(data) {
-        ship.consume_instruction(instruction, magnitude);
+    for &(instruction, magnitude) in instructions {
+        ship.consume_instruction(instruction, magnitude)?;
     }
 
-    ship.manhattan_distance()
+    Ok(ship.manhattan_distance().into())
 }
 
-pub fn part_two(_data: &str) -> usize {
-    0
+pub fn part_two(_instructions: &[(Instruction, isize)]) -> Answer {
+    Answer::from(0)
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use aoc_common::personal_input;
 
     #[test]
     fn example() {
-        let answer = part_one(&EXAMPLE_DATA);
-        assert_eq!(25, answer);
+        let answer = part_one(&parse_instructions(EXAMPLE_DATA).unwrap());
+        assert_eq!(Ok(Answer::from(25)), answer);
+    }
+
+    #[test]
+    fn malformed() {
+        let err = parse_instructions("F10\nX3\n").unwrap_err();
+        assert_eq!((Some(2), Some(1)), (err.line, err.column));
+        let err = parse_instructions("F10\nN\n").unwrap_err();
+        assert_eq!("expected a distance or angle, found nothing", err.reason);
+        let err = parse_instructions("F10\nR45\n").unwrap_err();
+        assert_eq!((Some(2), Some(2)), (err.line, err.column));
+        assert!(Direction::try_from(Angle::new(45)).is_err());
     }
 
     #[test]
     fn my_part_one() {
-        let answer = part_one(&MY_DATA);
+        let input = personal_input!(crate::YEAR, 12);
+        let answer = part_one(&parse_instructions(&input).unwrap()).unwrap();
         println!("part one: {}", answer);
         assert_eq!(858, answer);
     }
 
     #[test]
     fn my_part_two() {
-        let answer = part_two(&MY_DATA);
+        let input = personal_input!(crate::YEAR, 12);
+        let answer = part_two(&parse_instructions(&input).unwrap());
         println!("part two: {}", answer);
     }
 
-    const EXAMPLE_DATA: &str = "F10
-N3
-F7
-R90
-F11";
-
-    const MY_DATA: &str = "E2
-L180
-S4
-R90
-S1
-F49
-N2
-F18
-N2
-L180
-S5
-L90
-E3
-N2
-F11
-L180
-N5
-W3
-L180
-W2
-N5
-F80
-R90
-F89
-N1
-L180
-N2
-R180
-E4
-R90
-S1
-L90
-N5
-R180
-N2
-F17
-L90
-E2
-F58
-W5
-L90
-W3
-N3
-F78
-L90
-N4
-L90
-F15
-W1
-R90
-S1
-W4
-R90
-F41
-W4
-S4
-F37
-E5
-S1
-E3
-F19
-R90
-S1
-W4
-S2
-E2
-L180
-F51
-W5
-R90
-F76
-E2
-F40
-N4
-R180
-E5
-N3
-F72
-S4
-R90
-F99
-E3
-F76
-W5
-R90
-E2
-S5
-R180
-F76
-N4
-L180
-F10
-F83
-S1
-F46
-L90
-S5
-E1
-S1
-F14
-N4
-E1
-R180
-E1
-R180
-S3
-F52
-L90
-S4
-L90
-W3
-F18
-S2
-F81
-L180
-F76
-L180
-W1
-S2
-F73
-N2
-F77
-W1
-F28
-L180
-N2
-F76
-L180
-W5
-F61
-N4
-E2
-R180
-S2
-L90
-F14
-R180
-N5
-E4
-F11
-E1
-L90
-N3
-E3
-F58
-W3
-F72
-W1
-N2
-W5
-F44
-L90
-W4
-F37
-L90
-F4
-W5
-N3
-F57
-N4
-L90
-S2
-F43
-S4
-W3
-S5
-F84
-S2
-L90
-N1
-R180
-W3
-L180
-N2
-W3
-R90
-N2
-R90
-F66
-L90
-F73
-E4
-S2
-L270
-W2
-E2
-S4
-E1
-R90
-W1
-F49
-L270
-F70
-S3
-W1
-N2
-E1
-F65
-W3
-R90
-F27
-E5
-F80
-S4
-W5
-F68
-E5
-R90
-F94
-W2
-L90
-F37
-L180
-E1
-F38
-N2
-F15
-N4
-E3
-L90
-E1
-R90
-S1
-E5
-N4
-N2
-E4
-L270
-N4
-R90
-E1
-S4
-E4
-F71
-E2
-R180
-N5
-E3
-F17
-L90
-N2
-W1
-L90
-F62
-W1
-F85
-W1
-L180
-F33
-S4
-W1
-N5
-F81
-W2
-R90
-S2
-F49
-L180
-S5
-F4
-W4
-N3
-W1
-F17
-R90
-W4
-N4
-E4
-N3
-L90
-S2
-R90
-S3
-L90
-E4
-R90
-S5
-F88
-S1
-L180
-N5
-E5
-F55
-R90
-F81
-E5
-L180
-R90
-F55
-R90
-W5
-F13
-R90
-N5
-F58
-L180
-S5
-F27
-E4
-S3
-F42
-R90
-F39
-W3
-S3
-F31
-S4
-L90
-W1
-S3
-W3
-N4
-W2
-S3
-L90
-F61
-E1
-F23
-S2
-F31
-S3
-L180
-W1
-N1
-L90
-N3
-F81
-E2
-N1
-R90
-F64
-S4
-F88
-E1
-N5
-W1
-S3
-F10
-N5
-L90
-F58
-S1
-R90
-E3
-L90
-N4
-F94
-S1
-W1
-S4
-L90
-F51
-L180
-N4
-R90
-L90
-N4
-F66
-W2
-S3
-S3
-W4
-F68
-L90
-F42
-E1
-F43
-R90
-N3
-F20
-E1
-N3
-E4
-N3
-F4
-S4
-R180
-W1
-R270
-N3
-F86
-L90
-E5
-F84
-N3
-W3
-F16
-L90
-N2
-E3
-L90
-S5
-E5
-F53
-L270
-N2
-F91
-R90
-E5
-N4
-F57
-E5
-S5
-F61
-S4
-F89
-E3
-N3
-N5
-F3
-S5
-F59
-E5
-F66
-R180
-S1
-W1
-N2
-R180
-S4
-E2
-L90
-N1
-W2
-F13
-L90
-E5
-F6
-W3
-F78
-E1
-F7
-W1
-N4
-W5
-F58
-R90
-E4
-N3
-E5
-N3
-W1
-S3
-R90
-F16
-L90
-F93
-R270
-N5
-F2
-W1
-S3
-F54
-R270
-F18
-R180
-F95
-L90
-W1
-E4
-N2
-W1
-L90
-S2
-L90
-W2
-S4
-F92
-W2
-S3
-R180
-N5
-E3
-N5
-E5
-F22
-F88
-S3
-E2
-R90
-S5
-W1
-L90
-E4
-F77
-N1
-W3
-F14
-E3
-R90
-W1
-F21
-N1
-F58
-W4
-N2
-R90
-N2
-W4
-F68
-W5
-N3
-L90
-F22
-R90
-F90
-F84
-S5
-F30
-N1
-W4
-R90
-F17
-R90
-W4
-S5
-E2
-N1
-F92
-N2
-R180
-N5
-E2
-R90
-F38
-R90
-F15
-E5
-N4
-N4
-E4
-S4
-F92
-R90
-F22
-S3
-W4
-N3
-E1
-R180
-F96
-L90
-E1
-N1
-F9
-W2
-N4
-F17
-N2
-R90
-F76
-S2
-F5
-S5
-F34
-R90
-F7
-N4
-F83
-N5
-L90
-W1
-S3
-R90
-S3
-W2
-S3
-F51
-N5
-W4
-F8
-E3
-F10
-N5
-F39
-S3
-E2
-L90
-E5
-L90
-N5
-E2
-N3
-F42
-S3
-F38
-N5
-F19
-F97
-W2
-R180
-S4
-E4
-S2
-W3
-F39
-W4
-F70
-S1
-W1
-R90
-F41
-L90
-E1
-N1
-E3
-W5
-F13
-E4
-F2
-R180
-F27
-E4
-N2
-L270
-E1
-N3
-W4
-F81
-W3
-R90
-E1
-F57
-S5
-R90
-F13
-L180
-N5
-F98
-F32
-N3
-R90
-N3
-W3
-S3
-W3
-N4
-F73
-L180
-N1
-E4
-F7
-E4
-R90
-N4
-R90
-S2
-E5
-F32
-S2
-N5
-W3
-R90
-W5
-S2
-L90
-F4
-R270
-N5
-E3
-L90
-S5
-F24
-N4
-R90
-F27
-L90
-F16
-R90
-N2
-R90
-N3
-L90
-S3
-L90
-F85
-S3
-F47
-N1
-E1
-N3
-R270
-S2
-L90
-F50
-L90
-S2
-F23
-N4
-L180
-E3
-F91
-R90
-E1
-W4
-F81
-W5
-R90
-F46
-E1
-W1
-F91
-N5
-W5
-N3
-W1
-L90
-F60
-S2
-L90
-E1
-F82
-S3
-W5
-N5
-F90
-E3
-S1
-F61
-E4
-F98
-R180
-F8
-R270
-F73
-W4
-L90
-W5
-L90
-F86
-W5
-L180
-F61
-N5
-F88
-E2
-L270
-F90
-N5
-F21
-R270
-F40
-L90
-W1
-N2
-L90
-E2
-S5
-E2
-S1
-E5
-N3
-F51
-S1
-F58
-W3
-L180
-F13
-R90
-N1
-F79
-W2
-F61
-R90
-F22
-E2
-N5
-F1
-S4
-F99
-S1
-S3
-E2
-F97";
+    const EXAMPLE_DATA: &str = crate::doc_examples::day_12::EXAMPLES[0];
 
 }
\ No newline at end of file
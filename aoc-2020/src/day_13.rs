@@ -24,7 +24,7 @@
 //! (There will be exactly one such bus.)
 //!
 //! For example, suppose you have the following notes:
-//!```skip
+//!```skip example part_one=295
 //! 939
 //! 7,13,x,x,59,x,31,19
 //! ```
@@ -63,35 +63,37 @@
 //! What is the ID of the earliest bus you can take to the airport multiplied by the number of minutes
 //! you'll need to wait for that bus?
 
-pub fn part_one(_data: &str) -> i32 {
-    0
+use aoc_common::Answer;
+
+pub fn part_one(_data: &str) -> Answer {
+    Answer::from(0)
 }
 
-pub fn part_two(_data: &str) -> i32 {
-    0
+pub fn part_two(_data: &str) -> Answer {
+    Answer::from(0)
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use aoc_common::personal_input;
 
-    const EXAMPLE_DATA: &str = "939
-7,13,x,x,59,x,31,19";
-
-    const MY_DATA: &str = "";
+    const EXAMPLE_DATA: &str = crate::doc_examples::day_13::EXAMPLES[0];
 
     #[test]
     fn example() {
-        assert_eq!(0, part_one(&EXAMPLE_DATA));
+        assert_eq!(0, part_one(EXAMPLE_DATA));
     }
 
     #[test]
     fn my_part_one() {
-        println!("part one: {}", part_one(&MY_DATA));
+        let input = personal_input!(crate::YEAR, 13);
+        println!("part one: {}", part_one(&input));
     }
 
     #[test]
     fn my_part_two() {
-        println!("part two: {}", part_two(&MY_DATA));
+        let input = personal_input!(crate::YEAR, 13);
+        println!("part two: {}", part_two(&input));
     }
 }
@@ -21,7 +21,7 @@
 //! while an X leaves the bit in the value unchanged.
 //!
 //! For example, consider the following program:
-//! ```skip
+//! ```skip example part_one=165
 //! mask = XXXXXXXXXXXXXXXXXXXXXXXXXXXXX1XXXX0X
 //! mem[8] = 11
 //! mem[7] = 101
@@ -66,37 +66,37 @@
 //! Execute the initialization program. What is the sum of all values left in memory after it
 //! completes? (Do not truncate the sum to 36 bits.)
 
-pub fn part_one(_data: &str) -> i32 {
-    0
+use aoc_common::Answer;
+
+pub fn part_one(_data: &str) -> Answer {
+    Answer::from(0)
 }
 
-pub fn part_two(_data: &str) -> i32 {
-    0
+pub fn part_two(_data: &str) -> Answer {
+    Answer::from(0)
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use aoc_common::personal_input;
 
-    const EXAMPLE_DATA: &str = "mask = XXXXXXXXXXXXXXXXXXXXXXXXXXXXX1XXXX0X
-mem[8] = 11
-mem[7] = 101
-mem[8] = 0";
-
-    const MY_DATA: &str = "";
+    const EXAMPLE_DATA: &str = crate::doc_examples::day_14::EXAMPLES[0];
 
     #[test]
     fn example() {
-        assert_eq!(0, part_one(&EXAMPLE_DATA));
+        assert_eq!(0, part_one(EXAMPLE_DATA));
     }
 
     #[test]
     fn my_part_one() {
-        println!("part one: {}", part_one(&MY_DATA));
+        let input = personal_input!(crate::YEAR, 14);
+        println!("part one: {}", part_one(&input));
     }
 
     #[test]
     fn my_part_two() {
-        println!("part two: {}", part_two(&MY_DATA));
+        let input = personal_input!(crate::YEAR, 14);
+        println!("part two: {}", part_two(&input));
     }
 }
@@ -39,46 +39,56 @@
 //!
 //! Their question for you is: what will be the 2020th number spoken? In the example above, the 2020th
 //! number spoken will be 436.
+//! <!-- example "0,3,6" part_one=436 -->
 //!
 //! Here are a few more examples:
 //!   - Given the starting numbers 1,3,2, the 2020th number spoken is 1.
+//!     <!-- example "1,3,2" part_one=1 -->
 //!   - Given the starting numbers 2,1,3, the 2020th number spoken is 10.
+//!     <!-- example "2,1,3" part_one=10 -->
 //!   - Given the starting numbers 1,2,3, the 2020th number spoken is 27.
+//!     <!-- example "1,2,3" part_one=27 -->
 //!   - Given the starting numbers 2,3,1, the 2020th number spoken is 78.
+//!     <!-- example "2,3,1" part_one=78 -->
 //!   - Given the starting numbers 3,2,1, the 2020th number spoken is 438.
+//!     <!-- example "3,2,1" part_one=438 -->
 //!   - Given the starting numbers 3,1,2, the 2020th number spoken is 1836.
+//!     <!-- example "3,1,2" part_one=1836 -->
 //!
 //! Given your starting numbers, what will be the 2020th number spoken?
 //! Your puzzle input is `8,11,0,19,1,2`.
 
-pub fn part_one(_data: &str) -> i32 {
-    0
+use aoc_common::Answer;
+
+pub fn part_one(_data: &str) -> Answer {
+    Answer::from(0)
 }
 
-pub fn part_two(_data: &str) -> i32 {
-    0
+pub fn part_two(_data: &str) -> Answer {
+    Answer::from(0)
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use aoc_common::personal_input;
 
-    const EXAMPLE_DATA: &str = "";
-
-    const MY_DATA: &str = "";
+    const EXAMPLE_DATA: &str = crate::doc_examples::day_15::EXAMPLES[0];
 
     #[test]
     fn example() {
-        assert_eq!(0, part_one(&EXAMPLE_DATA));
+        assert_eq!(0, part_one(EXAMPLE_DATA));
     }
 
     #[test]
     fn my_part_one() {
-        println!("part one: {}", part_one(&MY_DATA));
+        let input = personal_input!(crate::YEAR, 15);
+        println!("part one: {}", part_one(&input));
     }
 
     #[test]
     fn my_part_two() {
-        println!("part two: {}", part_two(&MY_DATA));
+        let input = personal_input!(crate::YEAR, 15);
+        println!("part two: {}", part_two(&input));
     }
 }
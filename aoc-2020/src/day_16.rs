@@ -40,7 +40,7 @@
 //! which aren't valid for any field. Ignore your ticket for now.
 //!
 //! For example, suppose you have the following notes:
-//! ```skip
+//! ```skip example part_one=71
 //! class: 1-3 or 5-7
 //! row: 6-11 or 33-44
 //! seat: 13-40 or 45-50
@@ -63,34 +63,37 @@
 //!
 //! Consider the validity of the nearby tickets you scanned. What is your ticket scanning error rate?
 
-pub fn part_one(_data: &str) -> i32 {
-    0
+use aoc_common::Answer;
+
+pub fn part_one(_data: &str) -> Answer {
+    Answer::from(0)
 }
 
-pub fn part_two(_data: &str) -> i32 {
-    0
+pub fn part_two(_data: &str) -> Answer {
+    Answer::from(0)
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use aoc_common::personal_input;
 
-    const EXAMPLE_DATA: &str = "";
-
-    const MY_DATA: &str = "";
+    const EXAMPLE_DATA: &str = crate::doc_examples::day_16::EXAMPLES[0];
 
     #[test]
     fn example() {
-        assert_eq!(0, part_one(&EXAMPLE_DATA));
+        assert_eq!(0, part_one(EXAMPLE_DATA));
     }
 
     #[test]
     fn my_part_one() {
-        println!("part one: {}", part_one(&MY_DATA));
+        let input = personal_input!(crate::YEAR, 16);
+        println!("part one: {}", part_one(&input));
     }
 
     #[test]
     fn my_part_two() {
-        println!("part two: {}", part_two(&MY_DATA));
+        let input = personal_input!(crate::YEAR, 16);
+        println!("part two: {}", part_two(&input));
     }
 }
@@ -32,7 +32,7 @@
 //!     the six-cycle boot process.
 //!
 //! For example, consider the following initial state:
-//! ```skip
+//! ```skip example part_one=112
 //! .#.
 //! ..#
 //! ###
@@ -163,34 +163,83 @@
 //! Starting with your given initial configuration, simulate six cycles. How many cubes are left
 //! in the active state after the sixth cycle?
 
-pub fn part_one(_data: &str) -> i32 {
-    0
+use aoc_common::automaton::{conway, Life};
+use aoc_common::{Answer, AocResult, Grid, Solution};
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = Grid<bool>;
+
+    fn parse(&self, input: &str) -> AocResult<Self::Input> {
+        parse_slice(input)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> AocResult<Answer> {
+        Ok(part_one(input))
+    }
+
+    fn part_two(&self, input: &Self::Input) -> AocResult<Answer> {
+        Ok(part_two(input))
+    }
+}
+
+/// How many cycles the boot process runs for
+const BOOT_CYCLES: usize = 6;
+
+/// Parses the flat starting slice, `true` for the active cubes.
+pub fn parse_slice(data: &str) -> AocResult<Grid<bool>> {
+    Grid::parse(data, "`#` or `.`", |c| match c {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    })
+}
+
+/// Boots the pocket dimension, `N` dimensions of it, returning how many cubes end up active.
+fn boot<const N: usize>(slice: &Grid<bool>) -> usize {
+    let mut cubes = Life::<N>::from_grid(slice, |active| *active);
+    cubes.run(BOOT_CYCLES, conway);
+    cubes.len()
+}
+
+pub fn part_one(slice: &Grid<bool>) -> Answer {
+    boot::<3>(slice).into()
 }
 
-pub fn part_two(_data: &str) -> i32 {
-    0
+pub fn part_two(slice: &Grid<bool>) -> Answer {
+    boot::<4>(slice).into()
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use aoc_common::personal_input;
 
-    const EXAMPLE_DATA: &str = "";
-
-    const MY_DATA: &str = "";
+    const EXAMPLE_DATA: &str = crate::doc_examples::day_17::EXAMPLES[0];
 
     #[test]
     fn example() {
-        assert_eq!(0, part_one(&EXAMPLE_DATA));
+        let slice = parse_slice(EXAMPLE_DATA).unwrap();
+        assert_eq!(112, part_one(&slice));
+        assert_eq!(848, part_two(&slice));
+    }
+
+    #[test]
+    fn malformed() {
+        let err = parse_slice(".#.\n.x#\n###\n").unwrap_err();
+        assert_eq!((Some(2), Some(2)), (err.line, err.column));
     }
 
     #[test]
     fn my_part_one() {
-        println!("part one: {}", part_one(&MY_DATA));
+        let input = personal_input!(crate::YEAR, 17);
+        println!("part one: {}", part_one(&parse_slice(&input).unwrap()));
     }
 
     #[test]
     fn my_part_two() {
-        println!("part two: {}", part_two(&MY_DATA));
+        let input = personal_input!(crate::YEAR, 17);
+        println!("part two: {}", part_two(&parse_slice(&input).unwrap()));
     }
 }
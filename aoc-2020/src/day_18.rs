@@ -1,31 +1,34 @@
-pub fn part_one(_data: &str) -> i32 {
-    0
+use aoc_common::Answer;
+
+pub fn part_one(_data: &str) -> Answer {
+    Answer::from(0)
 }
 
-pub fn part_two(_data: &str) -> i32 {
-    0
+pub fn part_two(_data: &str) -> Answer {
+    Answer::from(0)
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use aoc_common::personal_input;
 
     const EXAMPLE_DATA: &str = "";
 
-    const MY_DATA: &str = "";
-
     #[test]
     fn example() {
-        assert_eq!(0, part_one(&EXAMPLE_DATA));
+        assert_eq!(0, part_one(EXAMPLE_DATA));
     }
 
     #[test]
     fn my_part_one() {
-        println!("part one: {}", part_one(&MY_DATA));
+        let input = personal_input!(crate::YEAR, 18);
+        println!("part one: {}", part_one(&input));
     }
 
     #[test]
     fn my_part_two() {
-        println!("part two: {}", part_two(&MY_DATA));
+        let input = personal_input!(crate::YEAR, 18);
+        println!("part two: {}", part_two(&input));
     }
 }
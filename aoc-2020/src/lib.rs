@@ -1,3 +1,8 @@
+// the `part_one=` answers tagged on the module docs' examples are for `aoc_common::examples`
+#![allow(rustdoc::invalid_codeblock_attributes)]
+
+use aoc_common::Registry;
+
 pub mod day_01;
 pub mod day_02;
 pub mod day_03;
@@ -23,3 +28,41 @@ pub mod day_22;
 pub mod day_23;
 pub mod day_24;
 pub mod day_25;
+
+/// The event year these solutions belong to.
+pub const YEAR: u16 = 2020;
+
+/// Installs the logger before any test runs, so `AOC_LOG` picks what the solvers print.
+#[cfg(test)]
+#[ctor::ctor]
+fn init_logging() {
+    aoc_common::logging::init_from_env();
+}
+
+/// Registers every 2020 day with the given registry.
+pub fn register(registry: &mut Registry) {
+    registry.register(YEAR, 1, day_01::Day01);
+    registry.register(YEAR, 2, day_02::Day02);
+    registry.register(YEAR, 3, day_03::Day03);
+    registry.register(YEAR, 4, day_04::Day04);
+    registry.register(YEAR, 5, day_05::Day05);
+    registry.register(YEAR, 6, day_06::Day06);
+    registry.register(YEAR, 7, day_07::Day07);
+    registry.register(YEAR, 8, day_08::Day08);
+    registry.register(YEAR, 9, day_09::Day09);
+    registry.register(YEAR, 10, day_10::Day10);
+    registry.register(YEAR, 11, day_11::Day11);
+    registry.register(YEAR, 12, day_12::Day12);
+    registry.register(YEAR, 17, day_17::Day17);
+
+    // still stubs
+    for day in (13..=16).chain(18..=25) {
+        registry.register_unimplemented(YEAR, day);
+    }
+}
+
+/// Tests for the worked examples tagged in each day's docs, generated by `build.rs`.
+#[cfg(test)]
+mod doc_examples {
+    include!(concat!(env!("OUT_DIR"), "/doc_examples.rs"));
+}
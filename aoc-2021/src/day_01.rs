@@ -1,37 +1,59 @@
 //! AOC Day xx
 // use aoc_2021;
 
-const DAY_01_INPUT: Result<&str, std::str::Utf8Error> =
-    std::str::from_utf8(include_bytes!("../assets/day_01_part1.txt"));
+use aoc_common::error::parse_at;
+use aoc_common::{Answer, AocResult, Solution};
+#[cfg(test)]
+use aoc_common::personal_input;
 
-fn part_one(input: &str) -> isize {
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = Vec<isize>;
+
+    fn parse(&self, input: &str) -> AocResult<Self::Input> {
+        parse(input)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> AocResult<Answer> {
+        Ok(part_one(input))
+    }
+
+    fn part_two(&self, input: &Self::Input) -> AocResult<Answer> {
+        Ok(part_two(input))
+    }
+}
+
+fn parse(input: &str) -> AocResult<Vec<isize>> {
+    input
+        .split_ascii_whitespace()
+        .map(|line| parse_at(input, line, "a depth"))
+        .collect()
+}
+
+fn part_one(input: &[isize]) -> Answer {
     let mut count = 0;
     let mut previous_line = isize::MAX;
-    for line in input.split_ascii_whitespace() {
-        let line_parsed: isize = str::parse(line).unwrap();
-        print!("{:?} -> {}", line, line_parsed);
-
+    for &line_parsed in input {
         if line_parsed > previous_line {
-            println!(" inc!");
+            log::trace!("{} inc!", line_parsed);
             count += 1;
         } else {
-            println!(" dec.")
+            log::trace!("{} dec.", line_parsed);
         }
         previous_line = line_parsed;
     }
 
-    count
+    count.into()
 }
 
-fn part_two(input: &str) -> isize {
+fn part_two(input: &[isize]) -> Answer {
     let mut count = 0;
     let mut previous_sum = isize::MAX;
     let mut buf = vec![];
-    for line in input.split_ascii_whitespace() {
-        let line_parsed: isize = str::parse(line).unwrap();
+    for &line_parsed in input {
         buf.insert(0, line_parsed);
         if buf.len() < 3 {
-            println!("");
             continue;
         }
         if buf.len() == 4 {
@@ -41,43 +63,43 @@ fn part_two(input: &str) -> isize {
         let sum: isize = buf.iter().sum();
 
         if sum > previous_sum {
-            println!("{} > {}? inc!", sum, previous_sum);
+            log::trace!("{} > {}? inc!", sum, previous_sum);
             count += 1;
         } else {
-            println!("{} > {}? dec.", sum, previous_sum);
+            log::trace!("{} > {}? dec.", sum, previous_sum);
         }
         previous_sum = sum;
     }
 
-    count
+    count.into()
 }
 
 #[test]
 fn example_part_one() {
-    let example_result = part_one("199\n200\n208\n210\n200\n207\n240\n269\n260\n263\n");
+    let example_result = part_one(&parse("199\n200\n208\n210\n200\n207\n240\n269\n260\n263\n").unwrap());
     println!("result: {}", example_result);
     assert_eq!(7, example_result);
 }
 
 #[test]
 fn example_part_two() {
-    let example_result = part_two("199\n200\n208\n210\n200\n207\n240\n269\n260\n263\n");
+    let example_result = part_two(&parse("199\n200\n208\n210\n200\n207\n240\n269\n260\n263\n").unwrap());
     println!("result: {}", example_result);
     assert_eq!(5, example_result);
 }
 
 #[test]
 fn test_part_one() {
-    let input = DAY_01_INPUT.unwrap();
-    let result = part_one(input);
+    let input = personal_input!(crate::YEAR, 1);
+    let result = part_one(&parse(&input).unwrap());
     println!("output: {}", result);
     assert_eq!(1665, result);
 }
 
 #[test]
 fn test_part_two() {
-    let input = DAY_01_INPUT.unwrap();
-    let result = part_two(input);
+    let input = personal_input!(crate::YEAR, 1);
+    let result = part_two(&parse(&input).unwrap());
     println!("output: {}", result);
     assert_eq!(1702, result);
 }
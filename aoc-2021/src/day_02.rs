@@ -1,103 +1,146 @@
 //! AOC Day xx
 // use aoc_2021;
 
+use aoc_common::error::parse_at;
+use aoc_common::{Answer, AocError, AocResult, Solution};
+#[cfg(test)]
+use aoc_common::personal_input;
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<Command>;
+
+    fn parse(&self, input: &str) -> AocResult<Self::Input> {
+        parse(input)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> AocResult<Answer> {
+        Ok(part_one(input))
+    }
+
+    fn part_two(&self, input: &Self::Input) -> AocResult<Answer> {
+        Ok(part_two(input))
+    }
+}
+
+#[cfg(test)]
 const EXAMPLE_INPUT: &str = "forward 5\ndown 5\nforward 8\nup 3\ndown 8\nforward 2\n";
-const OUR_INPUT: Result<&str, std::str::Utf8Error> =
-    std::str::from_utf8(include_bytes!("../assets/day_02.txt"));
+#[derive(Debug, Clone, Copy)]
+pub enum Command {
+    Forward(isize),
+    Up(isize),
+    Down(isize),
+}
 
-fn part_one(input: &str) -> isize {
-    let mut horizontal: isize = 0;
-    let mut depth: isize = 0;
+fn parse(input: &str) -> AocResult<Vec<Command>> {
+    let mut commands = vec![];
 
-    for line in input.split("\n") {
-        if line.len() == 0 {
+    for (ind, line) in input.split('\n').enumerate() {
+        if line.is_empty() {
             continue;
         }
 
-        let mut split = line.split(" ");
-        let dir = split.next().unwrap_or_else(|| panic!("{:?} invalid", line));
-        let mag = split.next().unwrap_or_else(|| panic!("{:?} invalid", line));
+        let (dir, mag) = line
+            .split_once(' ')
+            .ok_or_else(|| AocError::line(ind, line, "expected `<direction> <distance>`"))?;
+        let mag: isize = parse_at(input, mag, "a distance")?;
+
+        commands.push(match dir {
+            "forward" => Command::Forward(mag),
+            "up" => Command::Up(mag),
+            "down" => Command::Down(mag),
+            _ => {
+                return Err(AocError::at(
+                    input,
+                    dir,
+                    format!("expected `forward`, `up` or `down`, found `{}`", dir),
+                ))
+            }
+        });
+    }
 
-        let mag: isize = mag
-            .parse()
-            .unwrap_or_else(|_| panic!("{:?} isn't a number", mag));
+    Ok(commands)
+}
 
-        let (horizontal_delta, depth_delta) = match dir {
-            "forward" => (mag, 0),
-            "up" => (0, -mag),
-            "down" => (0, mag),
-            _ => panic!("unexpected input {:?}", dir),
+fn part_one(input: &[Command]) -> Answer {
+    let mut horizontal: isize = 0;
+    let mut depth: isize = 0;
+
+    for command in input {
+        let (horizontal_delta, depth_delta) = match *command {
+            Command::Forward(mag) => (mag, 0),
+            Command::Up(mag) => (0, -mag),
+            Command::Down(mag) => (0, mag),
         };
 
         horizontal += horizontal_delta;
         depth += depth_delta;
     }
 
-    horizontal * depth
+    (horizontal * depth).into()
 }
 
-fn part_two(input: &str) -> isize {
+fn part_two(input: &[Command]) -> Answer {
     let mut horizontal: isize = 0;
     let mut depth: isize = 0;
     let mut aim = 0;
 
-    for line in input.split("\n") {
-        if line.len() == 0 {
-            continue;
-        }
-
-        let mut split = line.split(" ");
-        let dir = split.next().unwrap_or_else(|| panic!("{:?} invalid", line));
-        let mag = split.next().unwrap_or_else(|| panic!("{:?} invalid", line));
-
-        let mag: isize = mag
-            .parse()
-            .unwrap_or_else(|_| panic!("{:?} isn't a number", mag));
-
-        if let Some((horizontal_delta, depth_delta)) = match dir {
-            "forward" => Some((mag, mag * aim)),
-            "up" => {
+    for command in input {
+        if let Some((horizontal_delta, depth_delta)) = match *command {
+            Command::Forward(mag) => Some((mag, mag * aim)),
+            Command::Up(mag) => {
                 aim -= mag;
                 None
             }
-            "down" => {
+            Command::Down(mag) => {
                 aim += mag;
                 None
             }
-            _ => panic!("unexpected input {:?}", dir),
         } {
             horizontal += horizontal_delta;
             depth += depth_delta;
         }
     }
 
-    horizontal * depth
+    (horizontal * depth).into()
 }
 
 #[test]
 fn example_part_one() {
-    let result = part_one(EXAMPLE_INPUT);
+    let result = part_one(&parse(EXAMPLE_INPUT).unwrap());
     println!("example result: {}", result);
     assert_eq!(result, 150);
 }
 
 #[test]
 fn example_part_two() {
-    let result = part_two(EXAMPLE_INPUT);
+    let result = part_two(&parse(EXAMPLE_INPUT).unwrap());
     println!("example result: {}", result);
     assert_eq!(result, 900);
 }
 
+#[test]
+fn malformed() {
+    let err = parse("forward 5\nsideways 5\n").unwrap_err();
+    assert_eq!((Some(2), Some(1)), (err.line, err.column));
+    let err = parse("forward 5\ndown five\n").unwrap_err();
+    assert_eq!((Some(2), Some(6)), (err.line, err.column));
+    assert_eq!(Some(1), parse("forward\n").unwrap_err().line);
+}
+
 #[test]
 fn test_part_one() {
-    let result = part_one(OUR_INPUT.unwrap());
+    let input = personal_input!(crate::YEAR, 2);
+    let result = part_one(&parse(&input).unwrap());
     println!("Part one: {}", result);
     assert_eq!(result, 1855814);
 }
 
 #[test]
 fn test_part_two() {
-    let result = part_two(OUR_INPUT.unwrap());
+    let input = personal_input!(crate::YEAR, 2);
+    let result = part_two(&parse(&input).unwrap());
     println!("Part two: {}", result);
     assert_eq!(result, 1845455714);
 }
@@ -1,8 +1,30 @@
 //! AOC Day xx
 // use aoc_2021;
 
+use aoc_common::{Answer, AocError, AocResult, Solution};
+#[cfg(test)]
+use aoc_common::personal_input;
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Vec<String>;
+
+    fn parse(&self, input: &str) -> AocResult<Self::Input> {
+        parse(input)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> AocResult<Answer> {
+        Ok(part_one(input))
+    }
+
+    fn part_two(&self, input: &Self::Input) -> AocResult<Answer> {
+        part_two(input)
+    }
+}
+
 /// computes gamma and epsilon rates
-fn compute_gamma_epsilon<'a>(input: &mut impl Iterator<Item = &'a &'a str>) -> (usize, usize) {
+fn compute_gamma_epsilon<S: AsRef<str>>(input: impl Iterator<Item = S>) -> (usize, usize) {
     const MAX_BITS: usize = 16;
 
     let mut num_inputs: usize = 0;
@@ -10,6 +32,7 @@ fn compute_gamma_epsilon<'a>(input: &mut impl Iterator<Item = &'a &'a str>) -> (
     let mut bits: [usize; MAX_BITS] = [0; MAX_BITS];
 
     for line in input {
+        let line = line.as_ref();
         if num_bits == 0 {
             num_bits = line.len();
         }
@@ -28,9 +51,9 @@ fn compute_gamma_epsilon<'a>(input: &mut impl Iterator<Item = &'a &'a str>) -> (
 
     // compute gamma
     let mut gamma_rate = 0;
-    for bit in 0..num_bits {
+    for (bit, count) in bits.iter().enumerate().take(num_bits) {
         // check for most common bit, rounding up
-        if bits[bit] >= (num_inputs + 1) / 2 {
+        if *count >= num_inputs.div_ceil(2) {
             gamma_rate |= 1 << bit;
         }
     }
@@ -55,21 +78,45 @@ fn str_bits_to_num(line: &str) -> usize {
     num
 }
 
+#[cfg(test)]
 const EXAMPLE_INPUT: &str =
     "00100\n11110\n10110\n10111\n10101\n01111\n00111\n11100\n10000\n11001\n00010\n01010\n";
-const OUR_INPUT: Result<&str, std::str::Utf8Error> =
-    std::str::from_utf8(include_bytes!("../assets/day_03.txt"));
+/// Splits the report into lines, checking they're all binary numbers of the same width.
+fn parse(input: &str) -> AocResult<Vec<String>> {
+    const MAX_BITS: usize = 16;
 
-fn part_one(input: &'static str) -> usize {
-    let input: Vec<&'static str> = input.split_terminator("\n").collect();
-    let (gamma, epsilon) = compute_gamma_epsilon(&mut input.iter());
+    let mut lines: Vec<String> = vec![];
+    for (ind, line) in input.split_terminator('\n').enumerate() {
+        if let Some(col) = line.find(|c| c != '0' && c != '1') {
+            let span = &line[col..col + line[col..].chars().next().unwrap().len_utf8()];
+            return Err(AocError::at(input, span, format!("expected a bit, found `{}`", span)));
+        }
+        let width = lines.first().map_or(line.len(), String::len);
+        if line.is_empty() || line.len() != width || width > MAX_BITS {
+            return Err(AocError::line(
+                ind,
+                line,
+                format!("expected a binary number of {} bits", width.clamp(1, MAX_BITS)),
+            ));
+        }
+        lines.push(line.to_string());
+    }
 
-    gamma * epsilon
+    if lines.is_empty() {
+        return Err(AocError::new("the report is empty"));
+    }
+    Ok(lines)
 }
 
-fn part_two(input: &'static str) -> usize {
-    let mut oxy_input: Vec<&'static str> = input.split_terminator("\n").collect();
-    let mut co2_input: Vec<&'static str> = input.split_terminator("\n").collect();
+fn part_one(input: &[String]) -> Answer {
+    let (gamma, epsilon) = compute_gamma_epsilon(input.iter());
+
+    (gamma * epsilon).into()
+}
+
+fn part_two(input: &[String]) -> AocResult<Answer> {
+    let mut oxy_input: Vec<&str> = input.iter().map(String::as_str).collect();
+    let mut co2_input: Vec<&str> = input.iter().map(String::as_str).collect();
     let num_bits = oxy_input[0].len();
 
     for bit in (0..num_bits).rev() {
@@ -78,7 +125,7 @@ fn part_two(input: &'static str) -> usize {
         // }
         // println!("");
 
-        let (oxy_gamma, _) = compute_gamma_epsilon(&mut oxy_input.iter());
+        let (oxy_gamma, _) = compute_gamma_epsilon(oxy_input.iter());
         oxy_input.retain(|input| {
             let char = if oxy_gamma & (1 << bit) != 0 {
                 '1'
@@ -103,13 +150,13 @@ fn part_two(input: &'static str) -> usize {
         //     print!(" {}", oxy)
         // }
         // println!("");
-        let (_co2_gamma, co2_epsilon) = compute_gamma_epsilon(&mut co2_input.iter());
+        let (_co2_gamma, co2_epsilon) = compute_gamma_epsilon(co2_input.iter());
         let character = if co2_epsilon & (1 << bit) != 0 {
             '1'
         } else {
             '0'
         };
-        println!("bit {} {:05b} - expect {}", bit, co2_epsilon, character);
+        log::trace!("bit {} {:05b} - expect {}", bit, co2_epsilon, character);
 
         co2_input.retain(|input| {
             input
@@ -124,41 +171,52 @@ fn part_two(input: &'static str) -> usize {
         }
     }
 
-    assert_eq!(1, oxy_input.len());
-    assert_eq!(1, co2_input.len());
+    if oxy_input.len() != 1 || co2_input.len() != 1 {
+        return Err(AocError::new("the bit criteria didn't narrow the report to one number"));
+    }
 
     let oxy = str_bits_to_num(oxy_input[0]);
     let co2 = str_bits_to_num(co2_input[0]);
-    println!("oxy: {0:b} {0}", oxy);
-    println!("co2: {0:b} {0}", co2);
+    log::info!("oxy: {0:b} {0}", oxy);
+    log::info!("co2: {0:b} {0}", co2);
 
-    oxy * co2
+    Ok((oxy * co2).into())
 }
 
 #[test]
 fn example_part_one() {
-    let result = part_one(EXAMPLE_INPUT);
+    let result = part_one(&parse(EXAMPLE_INPUT).unwrap());
     println!("example result: {}", result);
     assert_eq!(result, 198);
 }
 
 #[test]
 fn example_part_two() {
-    let result = part_two(EXAMPLE_INPUT);
+    let result = part_two(&parse(EXAMPLE_INPUT).unwrap()).unwrap();
     println!("example result: {}", result);
     assert_eq!(result, 230);
 }
 
+#[test]
+fn malformed() {
+    let err = parse("00100\n11210\n").unwrap_err();
+    assert_eq!((Some(2), Some(3)), (err.line, err.column));
+    assert_eq!(Some(2), parse("00100\n1110\n").unwrap_err().line);
+    assert!(part_two(&parse("00100\n00100\n").unwrap()).is_err());
+}
+
 #[test]
 fn test_part_one() {
-    let result = part_one(OUR_INPUT.unwrap());
+    let input = personal_input!(crate::YEAR, 3);
+    let result = part_one(&parse(&input).unwrap());
     println!("part one: {}", result);
     assert_eq!(2261546, result);
 }
 
 #[test]
 fn test_part_two() {
-    let result = part_two(OUR_INPUT.unwrap());
+    let input = personal_input!(crate::YEAR, 3);
+    let result = part_two(&parse(&input).unwrap()).unwrap();
     println!("part two: {}", result);
     assert_eq!(6775520, result);
 }
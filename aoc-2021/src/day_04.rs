@@ -1,6 +1,36 @@
 //! AOC Day xx
 // use aoc_2021;
 
+use aoc_common::error::parse_at;
+use aoc_common::parse;
+use aoc_common::render::{Frame, Render, Tone};
+use aoc_common::{Answer, AocError, AocResult, Grid, Solution};
+#[cfg(test)]
+use aoc_common::personal_input;
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Bingo;
+
+    fn parse(&self, input: &str) -> AocResult<Self::Input> {
+        parse(input)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> AocResult<Answer> {
+        part_one(input)
+    }
+
+    fn part_two(&self, input: &Self::Input) -> AocResult<Answer> {
+        part_two(input)
+    }
+
+    fn animate(&self, input: &Self::Input) -> AocResult<Vec<Frame>> {
+        Ok(animate(input))
+    }
+}
+
+#[cfg(test)]
 const EXAMPLE_INPUT: &str = "7,4,9,5,11,17,23,2,0,14,21,24,10,16,13,6,15,25,12,22,18,20,8,19,3,26,1
 
 22 13 17 11  0
@@ -22,183 +52,153 @@ const EXAMPLE_INPUT: &str = "7,4,9,5,11,17,23,2,0,14,21,24,10,16,13,6,15,25,12,2
  2  0 12  3  7
 ";
 
-const OUR_INPUT: Result<&str, std::str::Utf8Error> =
-    std::str::from_utf8(include_bytes!("../assets/day_04.txt"));
-
-lazy_static::lazy_static! {
-    static ref REGEX: regex::Regex = regex::Regex::new(r"(([\d]+)[\s]*){25}").expect("invalid regex");
-}
-
-struct BingoBoard<const ROWS: usize, const COLS: usize> {
+#[derive(Debug, Clone)]
+pub struct BingoBoard {
     /// the numbers on our board.
-    nums: [[u8; ROWS]; COLS],
+    nums: Grid<u8>,
     /// whether or not we've seen the given number from `nums`.
-    bingo: [[bool; ROWS]; COLS],
-    /// the summation of our bingos in the given row. If we reach `COLS`, we have a bingo.
-    row_sum: [u8; ROWS],
-    /// the summation of our bingos in the given column. If we reach `ROWS`, we have a bingo.
-    col_sum: [u8; COLS],
+    bingo: Grid<bool>,
+    /// the summation of our bingos in the given row. If we reach the width, we have a bingo.
+    row_sum: Vec<u8>,
+    /// the summation of our bingos in the given column. If we reach the height, we have a bingo.
+    col_sum: Vec<u8>,
 }
 
-impl<const ROWS: usize, const COLS: usize> BingoBoard<ROWS, COLS> {
-    /// Creates a new bingo board from a string representation of the board.
+impl BingoBoard {
+    /// Creates a new bingo board from a string representation of the board. Boards can be any
+    /// size, so long as every row is as long as the first. Errors are located within
+    /// `bingo_board_string`.
     ///
     ///
-    /// ```
-    /// let bingo_board = BingoBoard::<5, 5>::new_from_string("14 21 17 24  4
+    /// ```ignore
+    /// let bingo_board = BingoBoard::new_from_string("14 21 17 24  4
     /// 10 16 15  9 19
     /// 18  8 23 26 20
     /// 22 11 13  6  5
     /// 2  0 12  3  7");
     /// ```
-    pub fn new_from_string(bingo_board_string: &str) -> BingoBoard<ROWS, COLS> {
-        let mut board = BingoBoard {
-            nums: [[0; ROWS]; COLS],
-            bingo: [[false; ROWS]; COLS],
-            row_sum: [0; ROWS],
-            col_sum: [0; COLS],
-        };
-
-        for (row, line) in bingo_board_string.split("\n").enumerate() {
-            for (col, num) in line.split_ascii_whitespace().enumerate() {
-                board.nums[col][row] = num.parse().expect("invalid number");
+    pub fn new_from_string(bingo_board_string: &str) -> AocResult<BingoBoard> {
+        let mut rows: Vec<Vec<u8>> = Vec::new();
+        for (row, line) in bingo_board_string.lines().enumerate() {
+            let nums = line
+                .split_ascii_whitespace()
+                .map(|num| parse_at(bingo_board_string, num, "a number up to 255"))
+                .collect::<AocResult<Vec<u8>>>()?;
+            if let Some(first) = rows.first().filter(|first| first.len() != nums.len()) {
+                return Err(AocError::line(
+                    row,
+                    line,
+                    format!("expected {} numbers, like the first row", first.len()),
+                ));
             }
+            rows.push(nums);
         }
 
-        board
+        let nums = Grid::from_rows(rows)?;
+        Ok(BingoBoard {
+            bingo: Grid::new(nums.width(), nums.height(), false),
+            row_sum: vec![0; nums.height()],
+            col_sum: vec![0; nums.width()],
+            nums,
+        })
     }
 
     /// Checks if this board has a bingo.
     fn has_bingo(&self) -> bool {
-        for sum in self.row_sum {
-            if usize::from(sum) == COLS {
-                return true;
-            }
-        }
-
-        for sum in self.col_sum {
-            if usize::from(sum) == ROWS {
-                return true;
-            }
-        }
-
-        false
+        self.row_sum.iter().any(|sum| usize::from(*sum) == self.nums.width())
+            || self.col_sum.iter().any(|sum| usize::from(*sum) == self.nums.height())
     }
 
     /// Takes in a new number and checks it against our board. Returns `true` if we now have a bingo.
     pub fn ingest_number(&mut self, number: u8) -> bool {
-        for row in 0..ROWS {
-            for col in 0..COLS {
-                if self.nums[col][row] == number {
-                    self.bingo[col][row] = true;
-                    self.row_sum[row] += 1;
-                    self.col_sum[col] += 1;
-
-                    if usize::from(self.row_sum[row]) == COLS
-                        || usize::from(self.col_sum[col]) == ROWS
-                    {
-                        return true;
-                    } else {
-                        return false;
-                    }
-                }
-            }
-        }
+        let Some(((col, row), _)) = self.nums.indexed().find(|(_, num)| **num == number) else {
+            return false;
+        };
+
+        self.bingo[(col, row)] = true;
+        self.row_sum[row] += 1;
+        self.col_sum[col] += 1;
 
-        false
+        usize::from(self.row_sum[row]) == self.nums.width()
+            || usize::from(self.col_sum[col]) == self.nums.height()
     }
 
     /// If this board has a bingo, compute the bingo score (summation of the numbers involved in the bingo).
     pub fn compute_score(&self) -> usize {
-        let mut score: usize = 0;
-        for row in 0..ROWS {
-            for col in 0..COLS {
-                if !self.bingo[col][row] {
-                    score += self.nums[col][row] as usize;
-                }
-            }
-        }
+        self.nums
+            .iter()
+            .zip(self.bingo.iter())
+            .filter(|(_, bingo)| !**bingo)
+            .map(|(num, _)| *num as usize)
+            .sum()
+    }
+}
 
-        score
+impl Render for BingoBoard {
+    /// The numbers that have been drawn stand out
+    fn frame(&self) -> Frame {
+        Frame::from_grid(&self.nums, |position, num| {
+            let tone = if self.bingo[position] { Tone::Highlight } else { Tone::Normal };
+            (format!("{:3}", num), tone)
+        })
     }
 }
 
-impl<const ROWS: usize, const COLS: usize> core::fmt::Display for BingoBoard<ROWS, COLS> {
+impl core::fmt::Display for BingoBoard {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        for row in 0..ROWS {
-            for col in 0..COLS {
-                let (pre, post) = if self.bingo[col][row] {
-                    ("\x1b[1;4m", "\x1b[0m")
-                } else {
-                    ("", "")
-                };
-                write!(f, "{}{:3}{}", pre, self.nums[col][row], post)?
-            }
-            write!(f, "\n")?;
-        }
-
-        Ok(())
+        write!(f, "{}", self.frame())
     }
 }
 
-fn part_one(input: &str) -> isize {
-    let first_line_ind = input.find("\n").expect("invalid input format");
-    let first_line = &input[..first_line_ind];
-    let boards_input = &input[first_line_ind..];
-    let moves: Vec<u8> = first_line
-        .split(",")
-        .map(|num| num.parse().expect("invalid input moves"))
-        .collect();
+/// The drawn numbers and the boards playing along.
+#[derive(Debug, Clone)]
+pub struct Bingo {
+    moves: Vec<u8>,
+    boards: Vec<BingoBoard>,
+}
 
-    let mut boards: Vec<BingoBoard<5, 5>> = Vec::new();
-    for cap in REGEX.captures_iter(boards_input) {
-        let board = BingoBoard::new_from_string(&cap[0].trim());
-        boards.push(board);
-    }
+fn parse(input: &str) -> AocResult<Bingo> {
+    // the drawn numbers, then the boards, all separated by blank lines
+    let mut blocks = parse::blocks(input);
+    let first_block = blocks
+        .next()
+        .ok_or_else(|| AocError::new("expected the drawn numbers followed by boards"))?;
+    let moves = parse::comma_list(input, first_block, "a drawn number")?;
 
-    let mut bingo_score: isize = 0;
-    for bingo_num in &moves {
-        let mut has_bingo = false;
+    // errors come back located within the board; move them to where the board is
+    let boards = blocks
+        .map(|board| BingoBoard::new_from_string(board).map_err(|e| e.within(input, board)))
+        .collect::<AocResult<_>>()?;
+
+    Ok(Bingo { moves, boards })
+}
+
+fn part_one(input: &Bingo) -> AocResult<Answer> {
+    let mut boards = input.boards.clone();
+
+    for bingo_num in &input.moves {
         for board in &mut boards {
             let bingo = board.ingest_number(*bingo_num);
-            println!("{}", board);
+            log::trace!("{}", board);
             if bingo {
-                bingo_score = board.compute_score() as isize * *bingo_num as isize;
-                has_bingo = true;
-                break;
+                log::info!("bingo!!");
+                return Ok((board.compute_score() as isize * *bingo_num as isize).into());
             }
         }
-
-        if has_bingo {
-            println!("bingo!!");
-            break;
-        }
     }
 
-    bingo_score
+    Err(AocError::new("no board ever got a bingo"))
 }
 
-fn part_two(input: &str) -> isize {
-    let first_line_ind = input.find("\n").expect("invalid input format");
-    let first_line = &input[..first_line_ind];
-    let boards_input = &input[first_line_ind..];
-    let moves: Vec<u8> = first_line
-        .split(",")
-        .map(|num| num.parse().expect("invalid input moves"))
-        .collect();
-
-    let mut boards: Vec<BingoBoard<5, 5>> = Vec::new();
-    for cap in REGEX.captures_iter(boards_input) {
-        let board = BingoBoard::new_from_string(&cap[0].trim());
-        boards.push(board);
-    }
+fn part_two(input: &Bingo) -> AocResult<Answer> {
+    let mut boards = input.boards.clone();
 
     let mut bingo_score: isize = 0;
-    for bingo_num in &moves {
+    for bingo_num in &input.moves {
         let mut has_bingo = false;
         for board in &mut boards {
             let bingo = board.ingest_number(*bingo_num);
-            println!("{}", board);
+            log::trace!("{}", board);
             if bingo {
                 bingo_score = board.compute_score() as isize * *bingo_num as isize;
                 has_bingo = true;
@@ -206,39 +206,98 @@ fn part_two(input: &str) -> isize {
         }
 
         if has_bingo && boards.len() == 1 {
-            println!("last board has finally won");
-            break;
+            log::info!("last board has finally won");
+            return Ok(bingo_score.into());
         }
         boards.retain(|b| !b.has_bingo());
+        if has_bingo && boards.is_empty() {
+            // the last few boards won together
+            return Ok(bingo_score.into());
+        }
+    }
+
+    Err(AocError::new("some boards never got a bingo"))
+}
+
+/// Every board after each number is drawn, until they've all won
+fn animate(input: &Bingo) -> Vec<Frame> {
+    let mut boards = input.boards.clone();
+    let mut frames = vec![];
+    for bingo_num in &input.moves {
+        let mut frame = Frame::new();
+        frame.push_line(format!("drew {}", bingo_num), Tone::Accent);
+        for board in &mut boards {
+            board.ingest_number(*bingo_num);
+            frame.newline();
+            frame.append(board.frame());
+        }
+        frames.push(frame);
+
+        if boards.iter().all(BingoBoard::has_bingo) {
+            break;
+        }
     }
 
-    bingo_score
+    frames
 }
 
 #[test]
 fn example_part_one() {
-    let result = part_one(EXAMPLE_INPUT);
+    let result = part_one(&parse(EXAMPLE_INPUT).unwrap()).unwrap();
     println!("example result: {}", result);
     assert_eq!(result, 4512);
 }
 
 #[test]
 fn example_part_two() {
-    let result = part_two(EXAMPLE_INPUT);
+    let result = part_two(&parse(EXAMPLE_INPUT).unwrap()).unwrap();
     println!("example result: {}", result);
     assert_eq!(result, 1924);
 }
 
+#[test]
+fn malformed() {
+    let err = parse("7,4,x9\n\n22 13 17 11  0\n").unwrap_err();
+    assert_eq!((Some(1), Some(5)), (err.line, err.column));
+
+    let input = EXAMPLE_INPUT.replacen("19  8  7 25 23", "19  8  7 256 23", 1);
+    let err = parse(&input).unwrap_err();
+    assert_eq!((Some(11), Some(10)), (err.line, err.column));
+
+    let err = parse("1,2\n\n1 2 3\n4 5\n").unwrap_err();
+    assert_eq!((Some(4), None), (err.line, err.column));
+
+    let no_winner = parse("1,2\n\n22 13 17 11  0\n 8  2 23  4 24\n21  9 14 16  7\n 6 10  3 18  5\n 1 12 20 15 19\n").unwrap();
+    assert!(part_one(&no_winner).is_err());
+}
+
+#[test]
+fn animation() {
+    let frames = animate(&parse(EXAMPLE_INPUT).unwrap());
+    // the last board wins on the 15th number drawn
+    assert_eq!(15, frames.len());
+    assert!(frames[0].text().starts_with("drew 7\n\n 22 13 17 11  0\n"));
+}
+
+#[test]
+fn other_sizes() {
+    let bingo = parse("5,1,2,9,3\n\n1 2 3\n4 5 6\n\n7 8\n9 3\n").unwrap();
+    assert_eq!(2 * 13, part_one(&bingo).unwrap());
+    assert_eq!(3 * 15, part_two(&bingo).unwrap());
+}
+
 #[test]
 fn test_part_one() {
-    let result = part_one(OUR_INPUT.unwrap());
+    let input = personal_input!(crate::YEAR, 4);
+    let result = part_one(&parse(&input).unwrap()).unwrap();
     println!("part one: {}", result);
     assert_eq!(55_770, result);
 }
 
 #[test]
 fn test_part_two() {
-    let result = part_two(OUR_INPUT.unwrap());
+    let input = personal_input!(crate::YEAR, 4);
+    let result = part_two(&parse(&input).unwrap()).unwrap();
     println!("part two: {}", result);
     assert_eq!(2980, result);
 }
@@ -1,260 +1,180 @@
 //! AOC Day xx
 // use aoc_2021;
 
-const EXAMPLE_INPUT: &str = "0,9 -> 5,9
-8,0 -> 0,8
-9,4 -> 3,4
-2,2 -> 2,1
-7,0 -> 7,4
-6,4 -> 2,0
-0,9 -> 2,9
-3,4 -> 1,4
-0,0 -> 8,8
-5,5 -> 8,2";
-
-const OUR_INPUT: Result<&str, std::str::Utf8Error> =
-    std::str::from_utf8(include_bytes!("../assets/day_05.txt"));
+use aoc_common::geometry::Segment;
+use aoc_common::image::{Rgb, Svg};
+use aoc_common::render::{Frame, Render, Tone};
+use aoc_common::{Answer, AocError, AocResult, Point2, Solution, SparseGrid};
+#[cfg(test)]
+use aoc_common::personal_input;
 
-#[derive(Debug)]
-struct Line {
-    start: (isize, isize),
-    end: (isize, isize),
-}
+pub struct Day05;
 
-impl Line {
-    pub fn new(start: (isize, isize), end: (isize, isize)) -> Option<Self> {
-        println!("Line::new({:?}, {:?})", start, end);
-        let x_eq: bool = start.0 == end.0;
-        let y_eq: bool = start.1 == end.1;
-        let diag = (end.0 - start.0).abs() == (end.1 - start.1).abs();
-        if !x_eq && !y_eq && !diag {
-            return None;
-        }
-
-        let swap = if x_eq && start.1 > end.1 {
-            true
-        } else if y_eq && start.0 > end.0 {
-            true
-        } else {
-            false
-        };
+impl Solution for Day05 {
+    type Input = Vec<Segment>;
 
-        if !swap {
-            Some(Self { start, end })
-        } else {
-            Some(Self {
-                start: end,
-                end: start,
-            })
-        }
+    fn parse(&self, input: &str) -> AocResult<Self::Input> {
+        parse(input)
     }
 
-    /// Creates a new line from an input string like `0,9 -> 5,9`.
-    pub fn from_string(string: &str) -> Option<Self> {
-        let mut line_split = string.split(" -> ");
-        let start_str = line_split.next()?;
-        let end_str = line_split.next()?;
-
-        let start: Vec<isize> = start_str
-            .split(",")
-            .map(|num| num.parse().expect("invalid num"))
-            .collect();
-        let end: Vec<isize> = end_str
-            .split(",")
-            .map(|num| num.parse().expect("invalid num"))
-            .collect();
-
-        assert_eq!(start.len(), 2);
-        assert_eq!(end.len(), 2);
-
-        Line::new((start[0], start[1]), (end[0], end[1]))
+    fn part_one(&self, input: &Self::Input) -> AocResult<Answer> {
+        part_one(input)
     }
 
-    pub fn iter<'a>(&'a self, allow_diagonal: bool) -> LineIter<'a> {
-        let mut diag_delta = (0, 0);
-        let iter_method = if self.start.1 == self.end.1 {
-            IterMethod::ByCols
-        } else if self.start.0 == self.end.0 {
-            IterMethod::ByRows
-        } else if (self.end.0 - self.start.0).abs() == (self.end.1 - self.start.1).abs() {
-            diag_delta = (
-                (self.end.0 - self.start.0) / (self.end.0 - self.start.0).abs(),
-                (self.end.1 - self.start.1) / (self.end.1 - self.start.1).abs(),
-            );
-            IterMethod::ByDiagonal
-        } else {
-            panic!("invalid line for iteration");
-        };
-        LineIter {
-            line: self,
-            /// if the y coords are the same, we're iterating along the x axis
-            method: iter_method,
-            allow_diagonal,
-            delta: 0,
-            diag_delta,
-        }
+    fn part_two(&self, input: &Self::Input) -> AocResult<Answer> {
+        part_two(input)
     }
 }
 
-enum IterMethod {
-    ByRows,
-    ByCols,
-    ByDiagonal,
-}
-
-struct LineIter<'a> {
-    line: &'a Line,
-    method: IterMethod,
-    allow_diagonal: bool,
-    delta: isize,
-    diag_delta: (isize, isize),
-}
-
-impl Iterator for LineIter<'_> {
-    type Item = (isize, isize);
-
-    fn next(&mut self) -> Option<Self::Item> {
-        let res = match self.method {
-            IterMethod::ByCols => {
-                if self.line.start.0 + self.delta > self.line.end.0 {
-                    None
-                } else {
-                    Some((self.line.start.0 + self.delta, self.line.start.1))
-                }
-            }
-            IterMethod::ByRows => {
-                if self.line.start.1 + self.delta > self.line.end.1 {
-                    None
-                } else {
-                    Some((self.line.start.0, self.line.start.1 + self.delta))
-                }
-            }
-            IterMethod::ByDiagonal => {
-                if !self.allow_diagonal || self.delta > (self.line.end.0 - self.line.start.0).abs()
-                {
-                    None
-                } else {
-                    Some((
-                        self.line.start.0 + self.diag_delta.0 * self.delta,
-                        self.line.start.1 + self.diag_delta.1 * self.delta,
-                    ))
-                }
-            }
-        };
+#[cfg(test)]
+const EXAMPLE_INPUT: &str = "0,9 -> 5,9
+8,0 -> 0,8
+9,4 -> 3,4
+2,2 -> 2,1
+7,0 -> 7,4
+6,4 -> 2,0
+0,9 -> 2,9
+3,4 -> 1,4
+0,0 -> 8,8
+5,5 -> 8,2";
 
-        self.delta += 1;
+/// Parses a line of vents like `0,9 -> 5,9`, at any slope. Errors are located within `string`.
+fn parse_line(string: &str) -> AocResult<Segment> {
+    let (start_str, end_str) = string
+        .split_once(" -> ")
+        .ok_or_else(|| AocError::line(0, string, "expected `x1,y1 -> x2,y2`"))?;
 
-        res
-    }
+    Ok(Segment::new(
+        Point2::parse_at(string, start_str)?,
+        Point2::parse_at(string, end_str)?,
+    ))
 }
 
-struct ThermalVentsField<const ROWS: usize, const COLS: usize> {
+struct ThermalVentsField {
     allow_diagonal: bool,
-    field: [[u8; COLS]; ROWS],
+    /// how many vents cover each point; the field grows to hold whatever lines it's given
+    field: SparseGrid<usize>,
 }
 
-impl<const ROWS: usize, const COLS: usize> ThermalVentsField<ROWS, COLS> {
-    pub const fn new(allow_diagonal: bool) -> Self {
+impl ThermalVentsField {
+    pub fn new(allow_diagonal: bool) -> Self {
         Self {
             allow_diagonal,
-            field: [[0; COLS]; ROWS],
+            field: SparseGrid::new(),
         }
     }
 
-    pub fn input_line(&mut self, line: Line) {
-        for (x, y) in line.iter(self.allow_diagonal) {
-            self.field[y as usize][x as usize] += 1;
+    /// Marks every point `line` covers, unless it's off the axes and those aren't allowed.
+    pub fn input_line(&mut self, line: &Segment) {
+        if !self.allow_diagonal && !line.is_axis_aligned() {
+            return;
         }
+        for point in line.rasterize() {
+            *self.field.entry_or_insert_with(point.into(), || 0) += 1;
+        }
+    }
+
+    pub fn check_intersections(&self, threshold: usize) -> isize {
+        self.field.iter().filter(|(_, vents)| **vents >= threshold).count() as isize
     }
+}
 
-    pub fn check_intersections(&self, threshold: u8) -> isize {
-        let mut count = 0;
-        for row in 0..ROWS {
-            for col in 0..COLS {
-                if self.field[col][row] >= threshold {
-                    count += 1;
-                }
+impl Render for ThermalVentsField {
+    /// Every point with a vent stands out
+    fn frame(&self) -> Frame {
+        let mut frame = Frame::new();
+        let Some(bounds) = self.field.bounds() else {
+            return frame;
+        };
+        for point in bounds.points() {
+            let vents = self.field.get(point).copied().unwrap_or(0);
+            let tone = if vents != 0 { Tone::Highlight } else { Tone::Normal };
+            frame.push(format!("{:2}", vents), tone);
+            if point.0 == bounds.max.0 {
+                frame.newline();
             }
         }
 
-        count
+        frame
     }
 }
 
-impl<const ROWS: usize, const COLS: usize> core::fmt::Display for ThermalVentsField<ROWS, COLS> {
+impl core::fmt::Display for ThermalVentsField {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        for row in 0..ROWS {
-            for col in 0..COLS {
-                let (pre, post) = if self.field[col][row] != 0 {
-                    ("\x1b[1;4m", "\x1b[0m")
-                } else {
-                    ("", "")
-                };
-                write!(f, "{}{:2}{}", pre, self.field[col][row], post)?
-            }
-            write!(f, "\n")?;
-        }
+        write!(f, "{}", self.frame())
+    }
+}
 
-        Ok(())
+fn parse(input: &str) -> AocResult<Vec<Segment>> {
+    let mut lines: Vec<Segment> = Vec::<Segment>::new();
+    for (ind, line) in input.split_terminator('\n').enumerate() {
+        lines.push(parse_line(line).map_err(|e| e.in_line(ind))?);
     }
+
+    Ok(lines)
 }
 
-fn part_one<const ROWS: usize, const COLS: usize>(input: &str) -> isize {
-    let mut field = ThermalVentsField::<ROWS, COLS>::new(false);
-    let mut lines: Vec<Line> = Vec::<Line>::new();
-    for line in input.split_terminator("\n") {
-        if let Some(line) = Line::from_string(line) {
-            lines.push(line);
-        }
+/// A drawing of every vent line, the diagonals in a color of their own
+pub fn vent_map(lines: &[Segment]) -> Svg {
+    let mut svg = Svg::new();
+    for line in lines {
+        let color = if line.is_axis_aligned() {
+            Rgb::new(0xff, 0xd7, 0x00)
+        } else {
+            Rgb::new(0x00, 0xbc, 0xd4)
+        };
+        svg.segment(line, color);
     }
-    for line in lines.into_iter() {
+
+    svg
+}
+
+fn part_one(lines: &[Segment]) -> AocResult<Answer> {
+    let mut field = ThermalVentsField::new(false);
+    for line in lines {
         field.input_line(line);
     }
-    println!("{}", field);
+    log::trace!("{}", field);
 
-    field.check_intersections(2)
+    Ok(field.check_intersections(2).into())
 }
 
-fn part_two<const ROWS: usize, const COLS: usize>(input: &str) -> isize {
-    let mut field = ThermalVentsField::<ROWS, COLS>::new(true);
-    let mut lines: Vec<Line> = Vec::<Line>::new();
-    for line in input.split_terminator("\n") {
-        if let Some(line) = Line::from_string(line) {
-            lines.push(line);
-        }
-    }
-    for line in lines.into_iter() {
+fn part_two(lines: &[Segment]) -> AocResult<Answer> {
+    let mut field = ThermalVentsField::new(true);
+    for line in lines {
         field.input_line(line);
     }
-    println!("{}", field);
+    log::trace!("{}", field);
 
-    field.check_intersections(2)
+    Ok(field.check_intersections(2).into())
 }
 
 #[test]
 fn example_part_one() {
-    let result = part_one::<10, 10>(EXAMPLE_INPUT);
+    let result = part_one(&parse(EXAMPLE_INPUT).unwrap()).unwrap();
     println!("example result: {}", result);
     assert_eq!(result, 5);
 }
 
 #[test]
 fn example_part_two() {
-    let result = part_two::<10, 10>(EXAMPLE_INPUT);
+    let result = part_two(&parse(EXAMPLE_INPUT).unwrap()).unwrap();
     println!("example result: {}", result);
     assert_eq!(result, 12);
 }
 
 #[test]
 fn test_part_one() {
-    let result = part_one::<1_000, 1_000>(OUR_INPUT.unwrap());
+    let input = personal_input!(crate::YEAR, 5);
+    let result = part_one(&parse(&input).unwrap()).unwrap();
     println!("part one: {}", result);
     assert_eq!(5169, result);
 }
 
 #[test]
 fn test_part_two() {
-    let result = part_two::<1_000, 1_000>(OUR_INPUT.unwrap());
+    let input = personal_input!(crate::YEAR, 5);
+    let result = part_two(&parse(&input).unwrap()).unwrap();
     println!("part two: {}", result);
     assert_eq!(22_083, result);
 }
@@ -263,74 +183,99 @@ fn test_part_two() {
 mod utests {
     use super::*;
 
+    fn points(line: &str) -> Vec<(isize, isize)> {
+        parse_line(line).unwrap().rasterize().map(Into::into).collect()
+    }
+
     #[test]
     fn line_from_str() {
-        let line = Line::from_string("0,9 -> 5,9").unwrap();
-        assert_eq!(line.start, (0, 9));
-        assert_eq!(line.end, (5, 9));
+        let line = parse_line("0,9 -> 5,9").unwrap();
+        assert_eq!(line.start, Point2::new(0, 9));
+        assert_eq!(line.end, Point2::new(5, 9));
+    }
+
+    #[test]
+    fn malformed() {
+        let err = parse("0,9 -> 5,9\n8,0 -> 0,x8\n").unwrap_err();
+        assert_eq!((Some(2), Some(10)), (err.line, err.column));
+        let err = parse("0,9 -> 5,9\n8,0 => 0,8\n").unwrap_err();
+        assert_eq!((Some(2), None), (err.line, err.column));
+        let err = parse("8,0 -> 0,8\n0,9 -> 5,3,1\n").unwrap_err();
+        assert_eq!((Some(2), Some(8)), (err.line, err.column));
+    }
+
+    #[test]
+    fn drawn() {
+        let lines = parse("0,9 -> 5,9\n8,0 -> 0,8\n9,4 -> 3,4\n").unwrap();
+        let svg = vent_map(&lines).to_string();
+        assert!(svg.contains(r#"viewBox="-1 -1 12 12""#));
+        assert_eq!(3, svg.matches("<line ").count());
+        assert_eq!(1, svg.matches("#00bcd4").count());
+    }
+
+    #[test]
+    fn unbounded_field() {
+        let lines = parse("-3,9 -> 12,9\n3,-4 -> 3,20\n").unwrap();
+        let mut field = ThermalVentsField::new(false);
+        for line in &lines {
+            field.input_line(line);
+        }
+        let bounds = field.field.bounds().unwrap();
+        assert_eq!(((-3, -4), (12, 20)), (bounds.min, bounds.max));
+        assert_eq!(1, part_one(&lines).unwrap());
+    }
+
+    #[test]
+    fn many_overlapping_vents() {
+        let lines = parse(&"0,0 -> 0,2\n".repeat(300)).unwrap();
+        let mut field = ThermalVentsField::new(false);
+        for line in &lines {
+            field.input_line(line);
+        }
+        assert_eq!(Some(&300), field.field.get((0, 1)));
+        assert_eq!(3, part_one(&lines).unwrap());
+    }
+
+    #[test]
+    fn any_slope() {
+        // drawn out, the sloped line covers (1, 1) and (2, 1) of the horizontal one and (4, 2) of
+        // the vertical one, which cross each other at (4, 1)
+        let lines = parse("0,0 -> 4,2\n0,1 -> 5,1\n4,-2 -> 4,5\n").unwrap();
+        assert_eq!(1, part_one(&lines).unwrap());
+        assert_eq!(4, part_two(&lines).unwrap());
     }
 
     #[test]
     fn line_iter_x() {
-        let line = Line::from_string("0,9 -> 5,9").unwrap();
-        let mut line_iter = line.iter(false);
-        assert_eq!(Some((0, 9)), line_iter.next());
-        assert_eq!(Some((1, 9)), line_iter.next());
-        assert_eq!(Some((2, 9)), line_iter.next());
-        assert_eq!(Some((3, 9)), line_iter.next());
-        assert_eq!(Some((4, 9)), line_iter.next());
-        assert_eq!(Some((5, 9)), line_iter.next());
-        assert_eq!(None, line_iter.next());
+        assert_eq!(
+            vec![(0, 9), (1, 9), (2, 9), (3, 9), (4, 9), (5, 9)],
+            points("0,9 -> 5,9")
+        );
     }
 
     #[test]
     fn line_iter_y() {
-        let line = Line::from_string("9,0 -> 9,5").unwrap();
-        let mut line_iter = line.iter(false);
-        assert_eq!(Some((9, 0)), line_iter.next());
-        assert_eq!(Some((9, 1)), line_iter.next());
-        assert_eq!(Some((9, 2)), line_iter.next());
-        assert_eq!(Some((9, 3)), line_iter.next());
-        assert_eq!(Some((9, 4)), line_iter.next());
-        assert_eq!(Some((9, 5)), line_iter.next());
-        assert_eq!(None, line_iter.next());
+        assert_eq!(
+            vec![(9, 0), (9, 1), (9, 2), (9, 3), (9, 4), (9, 5)],
+            points("9,0 -> 9,5")
+        );
     }
 
     #[test]
     fn line_iter_x_rev() {
-        let line = Line::from_string("5,9 -> 0,9").unwrap();
-        println!("line: {:?}", &line);
-        let mut line_iter = line.iter(false);
-        assert_eq!(Some((0, 9)), line_iter.next());
-        assert_eq!(Some((1, 9)), line_iter.next());
-        assert_eq!(Some((2, 9)), line_iter.next());
-        assert_eq!(Some((3, 9)), line_iter.next());
-        assert_eq!(Some((4, 9)), line_iter.next());
-        assert_eq!(Some((5, 9)), line_iter.next());
-        assert_eq!(None, line_iter.next());
+        assert_eq!(
+            vec![(5, 9), (4, 9), (3, 9), (2, 9), (1, 9), (0, 9)],
+            points("5,9 -> 0,9")
+        );
     }
 
     #[test]
     fn line_iter_diag() {
-        let line = Line::from_string("4,4 -> 7,7").unwrap();
-        println!("line: {:?}", &line);
-        let mut line_iter = line.iter(true);
-        assert_eq!(Some((4, 4)), line_iter.next());
-        assert_eq!(Some((5, 5)), line_iter.next());
-        assert_eq!(Some((6, 6)), line_iter.next());
-        assert_eq!(Some((7, 7)), line_iter.next());
-        assert_eq!(None, line_iter.next());
+        assert_eq!(vec![(4, 4), (5, 5), (6, 6), (7, 7)], points("4,4 -> 7,7"));
     }
 
     #[test]
     fn line_iter_diag_rev() {
-        let line = Line::from_string("7,7 -> 4,4").unwrap();
-        println!("line: {:?}", &line);
-        let mut line_iter = line.iter(true);
-        assert_eq!(Some((7, 7)), line_iter.next());
-        assert_eq!(Some((6, 6)), line_iter.next());
-        assert_eq!(Some((5, 5)), line_iter.next());
-        assert_eq!(Some((4, 4)), line_iter.next());
-        assert_eq!(None, line_iter.next());
+        assert_eq!(vec![(7, 7), (6, 6), (5, 5), (4, 4)], points("7,7 -> 4,4"));
     }
 }
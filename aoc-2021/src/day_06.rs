@@ -1,93 +1,128 @@
 //! AOC Day xx
 // use aoc_2021;
 
-const EXAMPLE_INPUT: &str = "3,4,3,1,2";
-const OUR_INPUT: Result<&str, std::str::Utf8Error> =
-    std::str::from_utf8(include_bytes!("../assets/day_06.txt"));
+use aoc_common::error::parse_at;
+use aoc_common::simulation::Simulation;
+use aoc_common::{Answer, AocError, AocResult, Solution};
+#[cfg(test)]
+use aoc_common::personal_input;
 
-#[derive(Debug)]
-struct Fish {
-    pub(crate) num: usize,
-    pub(crate) cycle: u8,
-}
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = Vec<u8>;
 
-impl Fish {
-    pub const fn new(num: usize, cycle: u8) -> Self {
-        Self { num, cycle }
+    fn parse(&self, input: &str) -> AocResult<Self::Input> {
+        parse(input)
     }
-}
 
-fn simulate(input: &str, num_days: usize, verbose: bool) -> isize {
-    let mut state: Vec<Fish> = input
-        .split(",")
-        .map(|v| Fish::new(1, v.parse().expect("invalid state")))
-        .collect();
-    // let mut new_fish: Vec<u8> = Vec::new();
-    for day in 0..num_days {
-        let mut new_fish_count = 0;
-        for fish in state.iter_mut() {
-            if fish.cycle == 0 {
-                new_fish_count += fish.num;
-                fish.cycle = 6;
-            } else {
-                fish.cycle -= 1;
-            }
-        }
+    fn part_one(&self, input: &Self::Input) -> AocResult<Answer> {
+        Ok(part_one(input))
+    }
+
+    fn part_two(&self, input: &Self::Input) -> AocResult<Answer> {
+        Ok(part_two(input))
+    }
+}
 
-        // new_fish.resize(new_fish_count, 8);
-        state.push(Fish::new(new_fish_count, 8));
+#[cfg(test)]
+const EXAMPLE_INPUT: &str = "3,4,3,1,2";
+/// How many fish there are at each point in their spawning cycle
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct School {
+    timers: [usize; 9],
+}
 
-        if verbose {
-            println!("{:2}: {:?}", day, state);
-        } else if day % 16 == 0 {
-            println!("day {:2}", day);
+impl School {
+    fn new(input: &[u8]) -> Self {
+        let mut timers = [0; 9];
+        for cycle in input {
+            timers[*cycle as usize] += 1;
         }
+        Self { timers }
     }
 
-    let mut num_fish: isize = 0;
-    for fish in state {
-        num_fish += fish.num as isize;
+    fn len(&self) -> usize {
+        self.timers.iter().sum()
     }
+}
+
+impl Simulation for School {
+    /// Every fish counts down a day. Those at zero spawn a new fish at 8 and start over at 6.
+    fn step(&mut self) {
+        let spawning = self.timers[0];
+        self.timers.rotate_left(1);
+        self.timers[6] += spawning;
+    }
+}
 
-    num_fish
+fn parse(input: &str) -> AocResult<Vec<u8>> {
+    input
+        .trim()
+        .split(',')
+        .map(|v| {
+            let timer: u8 = parse_at(input, v, "a timer")?;
+            if timer > 8 {
+                return Err(AocError::at(input, v, format!("timers run from 0 to 8, found {}", timer)));
+            }
+            Ok(timer)
+        })
+        .collect()
 }
 
-fn part_one(input: &str) -> isize {
+fn simulate(input: &[u8], num_days: usize) -> isize {
+    let mut school = School::new(input);
+    school.run(num_days);
+    log::trace!("after {} days: {:?}", num_days, school);
+
+    school.len() as isize
+}
+
+fn part_one(input: &[u8]) -> Answer {
     const NUM_DAYS: usize = 80;
 
-    simulate(input, NUM_DAYS, false)
+    simulate(input, NUM_DAYS).into()
 }
 
-fn part_two(input: &str) -> isize {
+fn part_two(input: &[u8]) -> Answer {
     const NUM_DAYS: usize = 256;
 
-    simulate(input, NUM_DAYS, false)
+    simulate(input, NUM_DAYS).into()
 }
 
 #[test]
 fn example_part_one() {
-    let result = part_one(EXAMPLE_INPUT);
+    let result = part_one(&parse(EXAMPLE_INPUT).unwrap());
     println!("example result: {}", result);
     assert_eq!(result, 5934);
 }
 
 #[test]
 fn example_part_two() {
-    let result = part_two(EXAMPLE_INPUT);
+    let result = part_two(&parse(EXAMPLE_INPUT).unwrap());
     println!("example result: {}", result);
-    assert_eq!(result, 26_984_457_539);
+    assert_eq!(result, 26_984_457_539_u64);
+}
+
+#[test]
+fn malformed() {
+    let err = parse("3,4,9").unwrap_err();
+    assert_eq!((Some(1), Some(5)), (err.line, err.column));
+    assert_eq!(Ok(vec![8, 0]), parse("8,0\n"));
 }
 
 #[test]
 fn test_part_one() {
-    let result = part_one(OUR_INPUT.unwrap());
+    let input = personal_input!(crate::YEAR, 6);
+    let result = part_one(&parse(&input).unwrap());
     println!("part one: {}", result);
     assert_eq!(391_671, result);
 }
 
 #[test]
 fn test_part_two() {
-    let result = part_two(OUR_INPUT.unwrap());
+    let input = personal_input!(crate::YEAR, 6);
+    let result = part_two(&parse(&input).unwrap());
     println!("part two: {}", result);
-    assert_eq!(1_754_000_560_399, result);
+    assert_eq!(1_754_000_560_399_u64, result);
 }
@@ -1,85 +1,116 @@
 //! AOC Day xx
 // use aoc_2021;
 
+use aoc_common::error::parse_at;
+use aoc_common::{Answer, AocResult, Solution};
+#[cfg(test)]
+use aoc_common::personal_input;
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = Vec<usize>;
+
+    fn parse(&self, input: &str) -> AocResult<Self::Input> {
+        parse(input)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> AocResult<Answer> {
+        Ok(part_one(input))
+    }
+
+    fn part_two(&self, input: &Self::Input) -> AocResult<Answer> {
+        Ok(part_two(input))
+    }
+}
+
+#[cfg(test)]
 const EXAMPLE_INPUT: &str = "16,1,2,0,4,2,7,1,2,14";
-const OUR_INPUT: Result<&str, std::str::Utf8Error> =
-    std::str::from_utf8(include_bytes!("../assets/day_07.txt"));
-
-fn part_one(input: &str) -> isize {
-    let mut crab_position: Vec<usize> = input
-        .split(",")
-        .map(|v| v.parse().expect("invalid position"))
-        .collect();
-    crab_position.sort();
+fn parse(input: &str) -> AocResult<Vec<usize>> {
+    input
+        .trim()
+        .split(',')
+        .map(|v| parse_at(input, v, "a position"))
+        .collect()
+}
+
+fn part_one(input: &[usize]) -> Answer {
+    let mut crab_position = input.to_vec();
+    crab_position.sort_unstable();
     let median = crab_position[crab_position.len() / 2];
     let mut fuel_cost = isize::MAX;
-    for med in (median - 2)..(median + 2) {
-        println!("moving crabs to {}", median);
+    for med in median.saturating_sub(2)..(median + 2) {
+        log::trace!("moving crabs to {}", med);
         let fuel: isize = crab_position
             .iter()
             .map(|pos| (*pos as isize - med as isize).abs())
             .sum();
-        println!("fuel: {}", fuel);
+        log::trace!("fuel: {}", fuel);
         if fuel < fuel_cost {
             fuel_cost = fuel;
         }
     }
 
-    fuel_cost
+    fuel_cost.into()
 }
 
-fn part_two(input: &str) -> isize {
-    let crab_position: Vec<usize> = input
-        .split(",")
-        .map(|v| v.parse().expect("invalid position"))
-        .collect();
-
+fn part_two(crab_position: &[usize]) -> Answer {
     let crab_pos_sum: usize = crab_position.iter().sum();
     let average = (crab_pos_sum as f32 / crab_position.len() as f32).round() as isize;
     let mut fuel_cost = isize::MAX;
 
     for ave in (average - 2)..(average + 2) {
-        println!("moving crabs to {}", average);
+        log::trace!("moving crabs to {}", ave);
         let fuel: isize = crab_position
             .iter()
             .map(|pos| {
-                let delta = (*pos as isize - ave as isize).abs();
+                let delta = (*pos as isize - ave).abs();
                 (delta * delta + delta) / 2 // see https://en.wikipedia.org/wiki/Binomial_coefficient
             })
             .sum();
-        println!("fuel: {}", fuel);
+        log::trace!("fuel: {}", fuel);
         if fuel < fuel_cost {
             fuel_cost = fuel;
         }
     }
 
-    fuel_cost
+    fuel_cost.into()
 }
 
 #[test]
 fn example_part_one() {
-    let result = part_one(EXAMPLE_INPUT);
+    let result = part_one(&parse(EXAMPLE_INPUT).unwrap());
     println!("example result: {}", result);
     assert_eq!(result, 37);
 }
 
 #[test]
 fn example_part_two() {
-    let result = part_two(EXAMPLE_INPUT);
+    let result = part_two(&parse(EXAMPLE_INPUT).unwrap());
     println!("example result: {}", result);
     assert_eq!(result, 168);
 }
 
+#[test]
+fn malformed() {
+    let err = parse("16,1,2,-0,4\n").unwrap_err();
+    assert_eq!((Some(1), Some(8)), (err.line, err.column));
+    assert_eq!(Ok(vec![0]), parse("0"));
+    assert_eq!(0, part_one(&[0]));
+}
+
 #[test]
 fn test_part_one() {
-    let result = part_one(OUR_INPUT.unwrap());
+    let input = personal_input!(crate::YEAR, 7);
+    let result = part_one(&parse(&input).unwrap());
     println!("part one: {}", result);
     assert_eq!(341558, result);
 }
 
 #[test]
 fn test_part_two() {
-    let result = part_two(OUR_INPUT.unwrap());
+    let input = personal_input!(crate::YEAR, 7);
+    let result = part_two(&parse(&input).unwrap());
     println!("part two: {}", result);
     assert_eq!(93_214_037, result);
 }
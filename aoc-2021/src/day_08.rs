@@ -1,11 +1,34 @@
 //! AOC Day xx
 // use aoc_2021;
 
+use aoc_common::{Answer, AocError, AocResult, Solution};
+#[cfg(test)]
+use aoc_common::personal_input;
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = Vec<NoteEntry>;
+
+    fn parse(&self, input: &str) -> AocResult<Self::Input> {
+        parse(input)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> AocResult<Answer> {
+        Ok(part_one(input))
+    }
+
+    fn part_two(&self, input: &Self::Input) -> AocResult<Answer> {
+        part_two(input)
+    }
+}
+
 const ONE_CHARS: usize = 2;
 const FOUR_CHARS: usize = 4;
 const SEVEN_CHARS: usize = 3;
 const EIGHT_CHARS: usize = 7;
 
+#[cfg(test)]
 const EXAMPLE_INPUT: &str =
     "be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb | fdgacbe cefdb cefbgd gcbe
 edbfga begcd cbg gc gcadebf fbgde acbgfd abcde gfcbed gfec | fcgedb cgb dgebacf gc
@@ -17,9 +40,6 @@ dbcfg fgd bdegcaf fgec aegbdf ecdfab fbedc dacgb gdcebf gf | cefg dcbef fcge gbc
 bdfegc cbegaf gecbf dfcage bdacg ed bedf ced adcbefg gebcd | ed bcgafe cdgba cbgef
 egadfb cdbfeg cegd fecab cgb gbdefca cg fgcdab egfdb bfceg | gbdfcae bgc cg cgb
 gcafb gcf dcaebfg ecagb gf abcdeg gaef cafbge fdbac fegbdc | fgae cfgab fg bagce";
-const OUR_INPUT: Result<&str, std::str::Utf8Error> =
-    std::str::from_utf8(include_bytes!("../assets/day_08.txt"));
-
 struct PossibleValues {
     a: Vec<char>,
     b: Vec<char>,
@@ -92,13 +112,13 @@ impl PossibleValues {
 
 impl core::fmt::Display for PossibleValues {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        writeln!(f, "{}: {:?}", 'a', self.a)?;
-        writeln!(f, "{}: {:?}", 'b', self.b)?;
-        writeln!(f, "{}: {:?}", 'c', self.c)?;
-        writeln!(f, "{}: {:?}", 'd', self.d)?;
-        writeln!(f, "{}: {:?}", 'e', self.e)?;
-        writeln!(f, "{}: {:?}", 'f', self.f)?;
-        writeln!(f, "{}: {:?}", 'g', self.g)?;
+        writeln!(f, "a: {:?}", self.a)?;
+        writeln!(f, "b: {:?}", self.b)?;
+        writeln!(f, "c: {:?}", self.c)?;
+        writeln!(f, "d: {:?}", self.d)?;
+        writeln!(f, "e: {:?}", self.e)?;
+        writeln!(f, "f: {:?}", self.f)?;
+        writeln!(f, "g: {:?}", self.g)?;
         Ok(())
     }
 }
@@ -266,30 +286,64 @@ impl SevenSegmentDisplay {
     }
 }
 
-fn parse_out_line(line: &str) -> (Vec<&str>, Vec<&str>) {
-    let mut split_or = line.split(" | ");
-    let ten_signals: Vec<&str> = split_or
-        .next()
-        .expect("invalid 10 unique signal input")
-        .split(" ")
-        .map(|v| v.trim())
-        .collect();
-    let numbers: Vec<&str> = split_or
-        .next()
-        .expect("invalid final output")
-        .split(" ")
-        .map(|v| v.trim())
-        .collect();
-
-    (ten_signals, numbers)
+/// Splits a line into its ten signal patterns and four output digits. Errors are located within
+/// `line`.
+fn parse_out_line(line: &str) -> AocResult<(Vec<&str>, Vec<&str>)> {
+    let (signals, outputs) = line
+        .split_once(" | ")
+        .ok_or_else(|| AocError::line(0, line, "expected `<ten patterns> | <four digits>`"))?;
+    let ten_signals: Vec<&str> = signals.split(' ').map(|v| v.trim()).collect();
+    let numbers: Vec<&str> = outputs.split(' ').map(|v| v.trim()).collect();
+
+    if ten_signals.len() != 10 || numbers.len() != 4 {
+        return Err(AocError::line(
+            0,
+            line,
+            format!(
+                "expected 10 patterns and 4 digits, found {} and {}",
+                ten_signals.len(),
+                numbers.len()
+            ),
+        ));
+    }
+    for pattern in ten_signals.iter().chain(numbers.iter()) {
+        if pattern.is_empty() || !pattern.chars().all(|c| ('a'..='g').contains(&c)) {
+            return Err(AocError::at(
+                line,
+                pattern,
+                format!("expected segments `a` to `g`, found `{}`", pattern),
+            ));
+        }
+    }
+
+    Ok((ten_signals, numbers))
 }
 
-fn part_one(input: &str) -> isize {
-    let mut num_interesting_chars = 0;
-    for line in input.split_terminator("\n") {
-        let (_ten_signals, numbers) = parse_out_line(line);
+/// One line of notes: the ten unique signal patterns, and the four digit output value.
+#[derive(Debug, Clone)]
+pub struct NoteEntry {
+    signals: Vec<String>,
+    outputs: Vec<String>,
+}
 
-        for num in numbers {
+fn parse(input: &str) -> AocResult<Vec<NoteEntry>> {
+    input
+        .split_terminator('\n')
+        .enumerate()
+        .map(|(ind, line)| {
+            let (ten_signals, numbers) = parse_out_line(line).map_err(|e| e.in_line(ind))?;
+            Ok(NoteEntry {
+                signals: ten_signals.into_iter().map(str::to_string).collect(),
+                outputs: numbers.into_iter().map(str::to_string).collect(),
+            })
+        })
+        .collect()
+}
+
+fn part_one(input: &[NoteEntry]) -> Answer {
+    let mut num_interesting_chars = 0;
+    for entry in input {
+        for num in &entry.outputs {
             match num.len() {
                 ONE_CHARS | FOUR_CHARS | SEVEN_CHARS | EIGHT_CHARS => num_interesting_chars += 1,
                 _ => (),
@@ -297,16 +351,16 @@ fn part_one(input: &str) -> isize {
         }
     }
 
-    num_interesting_chars
+    num_interesting_chars.into()
 }
 
-fn part_two(input: &str) -> isize {
-    let mut four_char_value_sum = 0;
-    for line in input.split_terminator("\n") {
+fn part_two(input: &[NoteEntry]) -> AocResult<Answer> {
+    let mut four_char_value_sum: isize = 0;
+    for entry in input {
         let mut true_match_found = false;
         let mut decoder = PossibleValues::new();
-        println!("Parsing {:?}", line);
-        let (ten_signals, numbers) = parse_out_line(line);
+        log::trace!("parsing {:?}", entry);
+        let (ten_signals, numbers) = (&entry.signals, &entry.outputs);
         for num in ten_signals.iter() {
             let mixed_segments: Vec<char> = num.chars().collect();
             match num.len() {
@@ -348,7 +402,7 @@ fn part_two(input: &str) -> isize {
                                     let mut is_match: bool = true;
 
                                     for input in ten_signals.iter() {
-                                        if scrambled.to_display(*input).as_u8().is_none() {
+                                        if scrambled.to_display(input).as_u8().is_none() {
                                             is_match = false;
                                             break;
                                         }
@@ -357,10 +411,9 @@ fn part_two(input: &str) -> isize {
                                     if is_match {
                                         let mut our_sum: isize = 0;
                                         for (ind, num) in numbers.iter().enumerate() {
-                                            print!("{}=", *num);
-                                            let disp = scrambled.to_display(*num);
+                                            let disp = scrambled.to_display(num);
                                             if let Some(digit) = disp.as_u8() {
-                                                println!("{}", digit);
+                                                log::trace!("{}={}", *num, digit);
                                                 our_sum += isize::from(digit)
                                                     * (10_isize.pow((3 - ind) as u32));
                                             } else {
@@ -371,7 +424,7 @@ fn part_two(input: &str) -> isize {
                                         }
 
                                         if is_match {
-                                            println!("true match: {}", our_sum);
+                                            log::trace!("true match: {}", our_sum);
                                             true_match_found = true;
                                             // if it's still a match, excellent!
                                             four_char_value_sum += our_sum;
@@ -387,37 +440,50 @@ fn part_two(input: &str) -> isize {
         }
 
         if !true_match_found {
-            panic!("Failed to find a true decoding");
+            return Err(AocError::new(format!(
+                "no wiring decodes `{}`",
+                entry.signals.join(" ")
+            )));
         }
     }
 
-    four_char_value_sum
+    Ok(four_char_value_sum.into())
 }
 
 #[test]
 fn example_part_one() {
-    let result = part_one(EXAMPLE_INPUT);
+    let result = part_one(&parse(EXAMPLE_INPUT).unwrap());
     println!("example result: {}", result);
     assert_eq!(result, 26);
 }
 
 #[test]
 fn example_part_two() {
-    let result = part_two(EXAMPLE_INPUT);
+    let result = part_two(&parse(EXAMPLE_INPUT).unwrap()).unwrap();
     println!("example result: {}", result);
     assert_eq!(result, 61_229);
 }
 
+#[test]
+fn malformed() {
+    let err = parse("be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb | fdgacbe cefdb cefbgd gcbe\nbe cfbegad | fdgacbe\n").unwrap_err();
+    assert_eq!((Some(2), None), (err.line, err.column));
+    let err = parse("be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb | fdgacbe cefdx cefbgd gcbe\n").unwrap_err();
+    assert_eq!((Some(1), Some(70)), (err.line, err.column));
+}
+
 #[test]
 fn test_part_one() {
-    let result = part_one(OUR_INPUT.unwrap());
+    let input = personal_input!(crate::YEAR, 8);
+    let result = part_one(&parse(&input).unwrap());
     println!("part one: {}", result);
     assert_eq!(352, result);
 }
 
 #[test]
 fn test_part_two() {
-    let result = part_two(OUR_INPUT.unwrap());
+    let input = personal_input!(crate::YEAR, 8);
+    let result = part_two(&parse(&input).unwrap()).unwrap();
     println!("part two: {}", result);
     assert_eq!(936_117, result);
 }
@@ -1,151 +1,149 @@
 //! AOC Day xx
 // use aoc_2021;
 
+use aoc_common::image::{Image, Palette, Rgb};
+use aoc_common::neighbors::{Adjacency, Edge};
+use aoc_common::parse;
+use aoc_common::{Answer, AocResult, Grid, Solution};
+#[cfg(test)]
+use aoc_common::personal_input;
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = HeightMap;
+
+    fn parse(&self, input: &str) -> AocResult<Self::Input> {
+        HeightMap::from_input(input)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> AocResult<Answer> {
+        Ok(part_one(input))
+    }
+
+    fn part_two(&self, input: &Self::Input) -> AocResult<Answer> {
+        Ok(part_two(input))
+    }
+}
+
+#[cfg(test)]
 const EXAMPLE_INPUT: &str = "2199943210
 3987894921
 9856789892
 8767896789
 9899965678";
-const OUR_INPUT: Result<&str, std::str::Utf8Error> =
-    std::str::from_utf8(include_bytes!("../assets/day_09.txt"));
-
-struct HeightMap {
-    /// a height map padded on all sides with 0xF
-    map: Vec<Vec<u8>>,
-    /// A mapping to what basin this coordinate belongs to
-    basin_map: Vec<Vec<u8>>,
+
+/// Anything off the map is higher than anywhere on it
+const OFF_THE_MAP: Edge<u8> = Edge::Outside(u8::MAX);
+
+#[derive(Debug, Clone)]
+pub struct HeightMap {
+    /// a height map
+    map: Grid<u8>,
+    /// A mapping to what basin this coordinate belongs to, 0 for none
+    basin_map: Grid<u8>,
 }
 
 impl HeightMap {
-    pub fn from_str(string: &str) -> HeightMap {
-        let mut map: Vec<Vec<u8>> = Vec::new();
-        let mut basin_map: Vec<Vec<u8>> = Vec::new();
-        let first_line = &string[0..string.find("\n").expect("invalid input")];
-        let width = first_line.len() + 2; // include the padding on each side
-
-        let mut padding_line = Vec::with_capacity(width);
-        padding_line.resize(width, 0xF);
-
-        map.push(padding_line.clone());
-        basin_map.push(padding_line.clone());
-        for line in string.split_terminator("\n") {
-            let mut map_line = Vec::with_capacity(width);
-            let mut basin_line = Vec::with_capacity(width);
-            map_line.push(0xF);
-            basin_line.push(0xF);
-            for character in line.chars() {
-                map_line.push(character.to_digit(10).expect("invalid char in height map") as u8);
-                basin_line.push(0);
-            }
-            map_line.push(0xF);
-            basin_line.push(0xF);
-            map.push(map_line);
-            basin_map.push(basin_line);
-        }
-        basin_map.push(padding_line.clone());
-        map.push(padding_line);
+    pub fn from_input(string: &str) -> AocResult<HeightMap> {
+        let map = parse::digit_grid(string, "a height")?;
+        let basin_map = Grid::new(map.width(), map.height(), 0);
 
-        Self { map, basin_map }
+        Ok(Self { map, basin_map })
     }
 
-    pub fn find_minima(&self) -> Vec<u8> {
-        let width = self.map[0].len() - 2;
-        let height = self.map.len() - 2;
-        let mut minima = vec![];
-
-        for y in 1..height + 1 {
-            for x in 1..width + 1 {
-                let target = &self.map[y][x];
-                if target < &self.map[y][x + 1]
-                    && target < &self.map[y][x - 1]
-                    && target < &self.map[y + 1][x]
-                    && target < &self.map[y - 1][x]
-                {
-                    minima.push(*target);
-                }
-            }
-        }
+    /// Whether `position` is lower than everywhere next to it
+    fn is_minimum(&self, position: (usize, usize)) -> bool {
+        let target = self.map[position];
+        self.map
+            .neighbors(position, Adjacency::Orthogonal, &OFF_THE_MAP)
+            .all(|(_, height)| target < *height)
+    }
 
-        minima
+    /// A picture of the map, each location a `scale` pixel square running from dark blue in the
+    /// depths to white on the peaks
+    pub fn image(&self, scale: usize) -> Image {
+        let heights = Palette::gradient(Rgb::new(0x08, 0x30, 0x6b), Rgb::WHITE, 10);
+        Image::from_grid(&self.map, scale, |height| heights.color(*height as usize))
     }
 
-    pub fn mark_basins(&mut self) -> Vec<Vec<u8>> {
-        let width = self.map[0].len() - 2;
-        let height = self.map.len() - 2;
-        let mut minima = vec![];
-
-        for y in 1..height + 1 {
-            for x in 1..width + 1 {
-                let target = &self.map[y][x];
-                if target < &self.map[y][x + 1]
-                    && target < &self.map[y][x - 1]
-                    && target < &self.map[y + 1][x]
-                    && target < &self.map[y - 1][x]
-                {
-                    // this is a minima. We need to now walk from here as long as we walk up "out" of the basin
-                    minima.push(*target);
-                    let our_name = minima.len() as u8;
-
-                    self.mark_basins_inner(our_name, (x, y));
-                }
-            }
+    pub fn find_minima(&self) -> Vec<u8> {
+        self.map
+            .indexed()
+            .filter(|(position, _)| self.is_minimum(*position))
+            .map(|(_, height)| *height)
+            .collect()
+    }
+
+    pub fn mark_basins(&mut self) -> Grid<u8> {
+        let minima: Vec<(usize, usize)> = self
+            .map
+            .positions()
+            .filter(|position| self.is_minimum(*position))
+            .collect();
+
+        // each minima is a basin. We need to now walk from there as long as we walk up "out" of the basin
+        for (ind, position) in minima.into_iter().enumerate() {
+            let our_name = (ind + 1) as u8;
+            self.mark_basins_inner(our_name, position);
         }
 
         self.basin_map.clone()
     }
 
     fn mark_basins_inner(&mut self, marker: u8, coords: (usize, usize)) {
-        if self.map[coords.1][coords.0] >= 9 || self.basin_map[coords.1][coords.0] != 0 {
+        if self.map[coords] >= 9 || self.basin_map[coords] != 0 {
             return;
         }
-        self.basin_map[coords.1][coords.0] = marker;
-
-        self.mark_basins_inner(marker, (coords.0 + 1, coords.1));
-        self.mark_basins_inner(marker, (coords.0 - 1, coords.1));
-        self.mark_basins_inner(marker, (coords.0, coords.1 + 1));
-        self.mark_basins_inner(marker, (coords.0, coords.1 - 1));
+        self.basin_map[coords] = marker;
+
+        let neighbors: Vec<_> = self
+            .map
+            .neighbors(coords, Adjacency::Orthogonal, &Edge::Clip)
+            .filter_map(|(position, _)| position)
+            .collect();
+        for neighbor in neighbors {
+            self.mark_basins_inner(marker, neighbor);
+        }
     }
 }
 
 impl core::fmt::Display for HeightMap {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        for line in &self.map {
+        for line in self.map.rows() {
             for c in line {
                 write!(f, "{:x}", c)?;
             }
-            writeln!(f, "")?;
+            writeln!(f)?;
         }
 
         Ok(())
     }
 }
 
-fn part_one(input: &str) -> isize {
-    let map = HeightMap::from_str(input);
-    println!("{}", map);
+fn part_one(map: &HeightMap) -> Answer {
+    log::trace!("{}", map);
     let minima = map.find_minima();
-    let risk = minima.iter().map(|v| (v + 1) as isize).sum();
+    let risk: isize = minima.iter().map(|v| (v + 1) as isize).sum();
 
-    risk
+    risk.into()
 }
 
-fn part_two(input: &str) -> isize {
-    let mut map = HeightMap::from_str(input);
-    println!("{}", map);
+fn part_two(map: &HeightMap) -> Answer {
+    let mut map = map.clone();
+    log::trace!("{}", map);
     let basins = map.mark_basins();
-    println!("basins: {:?}", basins);
+    log::trace!("basins: {:?}", basins);
     let mut basin_sums = [0_usize; 256];
-    for row in basins {
-        for b in row {
-            if b != 0xF && b != 0 {
-                basin_sums[b as usize] += 1;
-            }
+    for b in basins.iter() {
+        if *b != 0 {
+            basin_sums[*b as usize] += 1;
         }
     }
-    println!("basin sums: {:?}", basin_sums);
+    log::trace!("basin sums: {:?}", basin_sums);
     basin_sums.sort();
     let basin_len = basin_sums.len();
-    println!(
+    log::info!(
         "three largest basins: {} {} {}",
         basin_sums[basin_len - 1],
         basin_sums[basin_len - 2],
@@ -154,33 +152,51 @@ fn part_two(input: &str) -> isize {
     let three_largest_basins =
         basin_sums[basin_len - 1] * basin_sums[basin_len - 2] * basin_sums[basin_len - 3];
 
-    three_largest_basins as isize
+    three_largest_basins.into()
 }
 
 #[test]
 fn example_part_one() {
-    let result = part_one(EXAMPLE_INPUT);
+    let result = part_one(&HeightMap::from_input(EXAMPLE_INPUT).unwrap());
     println!("example result: {}", result);
     assert_eq!(result, 15);
 }
 
 #[test]
 fn example_part_two() {
-    let result = part_two(EXAMPLE_INPUT);
+    let result = part_two(&HeightMap::from_input(EXAMPLE_INPUT).unwrap());
     println!("example result: {}", result);
     assert_eq!(result, 1134);
 }
 
+#[test]
+fn pictured() {
+    let image = HeightMap::from_input(EXAMPLE_INPUT).unwrap().image(3);
+    assert_eq!((30, 15), (image.width(), image.height()));
+    assert_eq!(Some(Rgb::WHITE), image.get((8, 2)));
+    assert_eq!(Some(Rgb::new(0x08, 0x30, 0x6b)), image.get((29, 0)));
+}
+
+#[test]
+fn malformed() {
+    let err = HeightMap::from_input("2199\n39a7\n").unwrap_err();
+    assert_eq!((Some(2), Some(3)), (err.line, err.column));
+    assert_eq!(Some(2), HeightMap::from_input("2199\n397\n").unwrap_err().line);
+    assert!(HeightMap::from_input("").is_err());
+}
+
 #[test]
 fn test_part_one() {
-    let result = part_one(OUR_INPUT.unwrap());
+    let input = personal_input!(crate::YEAR, 9);
+    let result = part_one(&HeightMap::from_input(&input).unwrap());
     println!("part one: {}", result);
     assert_eq!(600, result);
 }
 
 #[test]
 fn test_part_two() {
-    let result = part_two(OUR_INPUT.unwrap());
+    let input = personal_input!(crate::YEAR, 9);
+    let result = part_two(&HeightMap::from_input(&input).unwrap());
     println!("part two: {}", result);
     assert_eq!(987840, result);
 }
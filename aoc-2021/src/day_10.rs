@@ -3,6 +3,29 @@
 
 use std::collections::VecDeque;
 
+use aoc_common::{Answer, AocError, AocResult, Solution};
+#[cfg(test)]
+use aoc_common::personal_input;
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Vec<String>;
+
+    fn parse(&self, input: &str) -> AocResult<Self::Input> {
+        parse(input)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> AocResult<Answer> {
+        Ok(part_one(input))
+    }
+
+    fn part_two(&self, input: &Self::Input) -> AocResult<Answer> {
+        part_two(input)
+    }
+}
+
+#[cfg(test)]
 const EXAMPLE_INPUT: &str = "[({(<(())[]>[[{[]{<()<>>
 [(()[<>])]({[<{<<[]>>(
 {([(<{}[<>[]}>{[]{[(<()>
@@ -13,9 +36,6 @@ const EXAMPLE_INPUT: &str = "[({(<(())[]>[[{[]{<()<>>
 [<(<(<(<{}))><([]([]()
 <{([([[(<>()){}]>(<<{{
 <{([{{}}[<[[[<>{}]]]>[]]";
-const OUR_INPUT: Result<&str, std::str::Utf8Error> =
-    std::str::from_utf8(include_bytes!("../assets/day_10.txt"));
-
 fn unexpected_deliminator_to_score(delim: char) -> usize {
     match delim {
         ')' => 3,     // | '('
@@ -55,10 +75,11 @@ fn parse_line_for_syntax(line: &str) -> usize {
     for character in line.chars() {
         match character {
             ')' | ']' | '}' | '>' => {
-                let found = chunks.pop_back().expect("unbalanced chunk");
+                // a closer with nothing open is as corrupt as a mismatched one
+                let found = chunks.pop_back();
                 let expected = complementary_deliminator(character);
-                if found != expected {
-                    println!("Expected {:?}, found {:?}", expected, character);
+                if found != Some(expected) {
+                    log::trace!("expected {:?}, found {:?}", expected, character);
                     return unexpected_deliminator_to_score(character);
                 }
             }
@@ -67,8 +88,8 @@ fn parse_line_for_syntax(line: &str) -> usize {
         }
     }
 
-    if chunks.len() != 0 {
-        println!("incomplete line");
+    if !chunks.is_empty() {
+        log::trace!("incomplete line");
     }
 
     0
@@ -79,10 +100,11 @@ fn parse_line_for_autocomplete(line: &str) -> usize {
     for character in line.chars() {
         match character {
             ')' | ']' | '}' | '>' => {
-                let found = chunks.pop_back().expect("unbalanced chunk");
+                // a closer with nothing open is as corrupt as a mismatched one
+                let found = chunks.pop_back();
                 let expected = complementary_deliminator(character);
-                if found != expected {
-                    println!("Expected {:?}, found {:?}", expected, character);
+                if found != Some(expected) {
+                    log::trace!("expected {:?}, found {:?}", expected, character);
                     return 0;
                 }
             }
@@ -92,13 +114,13 @@ fn parse_line_for_autocomplete(line: &str) -> usize {
     }
 
     let mut score = 0;
-    if chunks.len() != 0 {
-        println!("incomplete line");
+    if !chunks.is_empty() {
+        log::trace!("incomplete line");
         while let Some(found) = chunks.pop_back() {
             match found {
                 '(' | '[' | '{' | '<' => {
                     let closer = complementary_deliminator(found);
-                    println!("adding {:?}", closer);
+                    log::trace!("adding {:?}", closer);
                     score *= 5;
                     score += autocomplete_deliminator_to_score(closer);
                 }
@@ -110,19 +132,36 @@ fn parse_line_for_autocomplete(line: &str) -> usize {
     score
 }
 
-fn part_one(input: &str) -> usize {
-    let mut result = 0;
+fn parse(input: &str) -> AocResult<Vec<String>> {
+    let mut lines = vec![];
     for line in input.split_terminator('\n') {
+        if let Some(col) = line.find(|c| !"()[]{}<>".contains(c)) {
+            let span = &line[col..col + line[col..].chars().next().unwrap().len_utf8()];
+            return Err(AocError::at(
+                input,
+                span,
+                format!("expected a bracket, found `{}`", span),
+            ));
+        }
+        lines.push(line.to_string());
+    }
+
+    Ok(lines)
+}
+
+fn part_one(input: &[String]) -> Answer {
+    let mut result = 0;
+    for line in input {
         let cost = parse_line_for_syntax(line);
         result += cost;
     }
 
-    result
+    result.into()
 }
 
-fn part_two(input: &str) -> usize {
+fn part_two(input: &[String]) -> AocResult<Answer> {
     let mut costs = vec![];
-    for line in input.split_terminator('\n') {
+    for line in input {
         let cost = parse_line_for_autocomplete(line);
         if cost != 0 {
             costs.push(cost);
@@ -130,33 +169,47 @@ fn part_two(input: &str) -> usize {
     }
     costs.sort();
 
-    costs[costs.len() / 2]
+    costs
+        .get(costs.len() / 2)
+        .map(|cost| Answer::from(*cost))
+        .ok_or_else(|| AocError::new("no incomplete lines"))
 }
 
 #[test]
 fn example_part_one() {
-    let result = part_one(EXAMPLE_INPUT);
+    let result = part_one(&parse(EXAMPLE_INPUT).unwrap());
     println!("example result: {}", result);
     assert_eq!(result, 26397);
 }
 
 #[test]
 fn example_part_two() {
-    let result = part_two(EXAMPLE_INPUT);
+    let result = part_two(&parse(EXAMPLE_INPUT).unwrap()).unwrap();
     println!("example result: {}", result);
     assert_eq!(result, 288_957);
 }
 
+#[test]
+fn malformed() {
+    let err = parse("[({(<(())[]>[[{[]{<()<>>\n[(()[<>])]x({[<{<<[]>>(\n").unwrap_err();
+    assert_eq!((Some(2), Some(11)), (err.line, err.column));
+    // closing more than was opened is corrupt, not a crash
+    assert_eq!(3, parse_line_for_syntax("())"));
+    assert!(part_two(&parse("()\n").unwrap()).is_err());
+}
+
 #[test]
 fn test_part_one() {
-    let result = part_one(OUR_INPUT.unwrap());
+    let input = personal_input!(crate::YEAR, 10);
+    let result = part_one(&parse(&input).unwrap());
     println!("part one: {}", result);
     assert_eq!(319_233, result);
 }
 
 #[test]
 fn test_part_two() {
-    let result = part_two(OUR_INPUT.unwrap());
+    let input = personal_input!(crate::YEAR, 10);
+    let result = part_two(&parse(&input).unwrap()).unwrap();
     println!("part two: {}", result);
     assert_eq!(1_118_976_874, result);
 }
@@ -1,6 +1,38 @@
 //! AOC Day xx
 // use aoc_2021;
 
+use aoc_common::automaton::{Automaton, Cascade};
+use aoc_common::neighbors::Adjacency;
+use aoc_common::parse;
+use aoc_common::render::{Frame, Render, Tone};
+use aoc_common::simulation::{Runner, Simulation};
+use aoc_common::{Answer, AocResult, Solution};
+#[cfg(test)]
+use aoc_common::personal_input;
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = DumboOctopus;
+
+    fn parse(&self, input: &str) -> AocResult<Self::Input> {
+        DumboOctopus::from_input(input)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> AocResult<Answer> {
+        Ok(part_one(input))
+    }
+
+    fn part_two(&self, input: &Self::Input) -> AocResult<Answer> {
+        Ok(part_two(input))
+    }
+
+    fn animate(&self, input: &Self::Input) -> AocResult<Vec<Frame>> {
+        Ok(animate(input))
+    }
+}
+
+#[cfg(test)]
 const EXAMPLE_INPUT: &str = "5483143223
 2745854711
 5264556173
@@ -12,201 +44,173 @@ const EXAMPLE_INPUT: &str = "5483143223
 4846848554
 5283751526";
 
-const OUR_INPUT: Result<&str, std::str::Utf8Error> =
-    std::str::from_utf8(include_bytes!("../assets/day_11.txt"));
-
-#[derive(Debug)]
-struct DumboOctopus<const ROWS: usize, const COLS: usize> {
-    map: [[u8; ROWS]; COLS],
-}
-
-impl<const ROWS: usize, const COLS: usize> DumboOctopus<ROWS, COLS> {
-    pub fn from_str(input: &str) -> Self {
-        let mut map = Self {
-            map: [[0xF_u8; ROWS]; COLS],
-        };
-        for (row, line) in input.split_terminator("\n").enumerate() {
-            assert_eq!(COLS - 2, line.len());
-            for (col, char) in line.chars().enumerate() {
-                map.map[col + 1][row + 1] = char.to_digit(10).expect("invalid character") as u8;
-            }
-        }
-
-        map
-    }
-
-    pub fn iter_indices(&self) -> DumboOctopusIndices<ROWS, COLS> {
-        DumboOctopusIndices::<ROWS, COLS> { index: (0, 1) } // initialize x to zero for the first += 1
-    }
-
-    /// run a step of the simulation, returning the number of DumboOctopi that flash
-    pub fn step(&mut self) -> usize {
-        // first step, increment everyone by one
-        for (col, row) in self.iter_indices() {
-            self.map[col][row] += 1;
-        }
-
-        // second step, do {
-        //    - look for flashers (== 9)
-        //    - if flashed, set energy to 0
-        //    - All adjacent Octopi to flashers increment by one if they're non-zero
-        // } while (someone flashed)
-        let mut total_flashed = 0;
-        loop {
-            let flashed = self.second_step();
-            if flashed == 0 {
-                break;
-            } else {
-                total_flashed += flashed;
-            }
-        }
-
-        total_flashed
-    }
-
-    fn second_step(&mut self) -> usize {
-        // first step, increment everyone by one
-        let mut flashed = 0;
-        for (col, row) in self.iter_indices() {
-            if self.map[col][row] == 10 {
-                // flash!
-                self.map[col][row] = 0;
-                flashed += 1;
-
-                // increment surrounding octopi
-                if (1..=9).contains(&self.map[col - 1][row - 1]) {
-                    self.map[col - 1][row - 1] += 1;
-                }
-                if (1..=9).contains(&self.map[col - 1][row]) {
-                    self.map[col - 1][row] += 1;
-                }
-                if (1..=9).contains(&self.map[col - 1][row + 1]) {
-                    self.map[col - 1][row + 1] += 1;
-                }
-                if (1..=9).contains(&self.map[col][row - 1]) {
-                    self.map[col][row - 1] += 1;
-                }
-                if (1..=9).contains(&self.map[col][row + 1]) {
-                    self.map[col][row + 1] += 1;
-                }
-                if (1..=9).contains(&self.map[col + 1][row - 1]) {
-                    self.map[col + 1][row - 1] += 1;
-                }
-                if (1..=9).contains(&self.map[col + 1][row]) {
-                    self.map[col + 1][row] += 1;
-                }
-                if (1..=9).contains(&self.map[col + 1][row + 1]) {
-                    self.map[col + 1][row + 1] += 1;
-                }
-            }
-        }
-
-        flashed
-    }
-}
-
-struct DumboOctopusIndices<const ROWS: usize, const COLS: usize> {
-    index: (usize, usize),
-}
-
-impl<const ROWS: usize, const COLS: usize> Iterator for DumboOctopusIndices<ROWS, COLS> {
-    type Item = (usize, usize);
-
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.index.0 < COLS - 2 {
-            self.index.0 += 1;
-        } else {
-            self.index.0 = 1;
-            if self.index.1 < ROWS - 2 {
-                self.index.1 += 1;
-            } else {
-                return None;
-            }
-        }
-
-        Some(self.index)
-    }
-}
-
-impl<const ROWS: usize, const COLS: usize> core::fmt::Display for DumboOctopus<ROWS, COLS> {
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        for (col, row) in self.iter_indices() {
-            let (pre, post) = if self.map[col][row] == 0 {
-                ("\x1b[1;4m", "\x1b[0m")
-            } else {
-                ("", "")
-            };
-            write!(f, "{}{}{}", pre, self.map[col][row], post)?;
-            if col == COLS - 2 {
-                write!(f, "\n")?;
-            }
-        }
+#[derive(Debug, Clone)]
+pub struct DumboOctopus {
+    octopi: Automaton<u8>,
+    /// how many flashed in the last step
+    flashed: usize,
+}
+
+/// Every octopus gains a unit of energy each step, and flashes once it has more than 9, giving a
+/// unit to each octopus around it. Those that flashed end the step drained.
+struct Flash;
 
-        Ok(())
+impl Cascade<u8> for Flash {
+    fn charge(&self, energy: &mut u8) {
+        *energy += 1;
+    }
+
+    fn fires(&self, energy: &u8) -> bool {
+        *energy > 9
+    }
+
+    fn spread(&self, energy: &mut u8) {
+        *energy = energy.saturating_add(1);
+    }
+
+    fn settle(&self, energy: &mut u8) {
+        *energy = 0;
     }
 }
 
-fn part_one(input: &str) -> usize {
+impl DumboOctopus {
+    pub fn from_input(input: &str) -> AocResult<Self> {
+        let energy = parse::digit_grid(input, "an energy level")?;
+        Ok(Self {
+            octopi: Automaton::new(energy, Adjacency::Surrounding),
+            flashed: 0,
+        })
+    }
+
+    /// How many octopi there are
+    pub fn num_octopi(&self) -> usize {
+        self.octopi.cells().len()
+    }
+
+    /// How many DumboOctopi flashed in the last step
+    pub fn flashed(&self) -> usize {
+        self.flashed
+    }
+}
+
+impl Simulation for DumboOctopus {
+    fn step(&mut self) {
+        self.flashed = self.octopi.cascade(&Flash);
+    }
+}
+
+impl Render for DumboOctopus {
+    /// The octopi that just flashed stand out
+    fn frame(&self) -> Frame {
+        Frame::from_grid(self.octopi.cells(), |_, energy| {
+            let tone = if *energy == 0 { Tone::Highlight } else { Tone::Normal };
+            (*energy, tone)
+        })
+    }
+}
+
+impl core::fmt::Display for DumboOctopus {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.frame())
+    }
+}
+
+fn part_one(input: &DumboOctopus) -> Answer {
     const NUM_STEPS: usize = 100;
-    // it's actually 10 by 10, but we have a padding row/col on all sides
-    let mut map = DumboOctopus::<12, 12>::from_str(input);
+    let mut map = input.clone();
     let mut total_flashed = 0;
-    println!("initial:\n{}", map);
+    log::trace!("initial:\n{}", map);
     for step in 0..NUM_STEPS {
-        total_flashed += map.step();
-        {
-            println!("After step {}:", step);
-            println!("{}", map)
-        }
+        map.step();
+        total_flashed += map.flashed();
+        log::trace!("after step {}:\n{}", step, map);
     }
 
-    total_flashed
+    total_flashed.into()
 }
 
-fn part_two(input: &str) -> isize {
-    // it's actually 10 by 10, but we have a padding row/col on all sides
-    let mut map = DumboOctopus::<12, 12>::from_str(input);
+fn part_two(input: &DumboOctopus) -> Answer {
+    let mut map = Runner::new(input.clone());
 
-    println!("initial:\n{}", map);
-    let mut step = 0;
-    let synchronized_step = loop {
-        let flashed = map.step();
-        step += 1;
+    log::trace!("initial:\n{}", map.state());
+    let synchronized_step = map.run_until(|map| {
+        log::trace!("{} flashed", map.flashed());
+        map.flashed() == map.num_octopi()
+    });
+    log::info!("synchronized at step {}", synchronized_step);
 
-        println!("step {}: {} flashed", step, flashed);
-        if flashed == 10 * 10 {
-            println!("\tsynchronized!");
-            break step;
-        }
-    };
+    synchronized_step.into()
+}
 
-    synchronized_step
+/// Every step up to the first one where all the octopi flash together
+fn animate(input: &DumboOctopus) -> Vec<Frame> {
+    let mut map = input.clone();
+    let mut frames = vec![map.frame()];
+    while map.flashed() != map.num_octopi() {
+        map.step();
+        let mut frame = map.frame();
+        frame.push_line(format!("{} flashed", map.flashed()), Tone::Dim);
+        frames.push(frame);
+    }
+
+    frames
 }
 
 #[test]
 fn example_part_one() {
-    let result = part_one(EXAMPLE_INPUT);
+    let result = part_one(&DumboOctopus::from_input(EXAMPLE_INPUT).unwrap());
     println!("example result: {}", result);
     assert_eq!(result, 1656);
 }
 
 #[test]
 fn example_part_two() {
-    let result = part_two(EXAMPLE_INPUT);
+    let result = part_two(&DumboOctopus::from_input(EXAMPLE_INPUT).unwrap());
     println!("example result: {}", result);
     assert_eq!(result, 195);
 }
 
+#[test]
+fn malformed() {
+    let input = EXAMPLE_INPUT.replacen("6141336146", "614133614", 1);
+    assert_eq!(Some(4), DumboOctopus::from_input(&input).unwrap_err().line);
+    let input = EXAMPLE_INPUT.replacen("6141336146", "61413x6146", 1);
+    let err = DumboOctopus::from_input(&input).unwrap_err();
+    assert_eq!((Some(4), Some(6)), (err.line, err.column));
+    assert!(DumboOctopus::from_input("").is_err());
+}
+
+#[test]
+fn other_sizes() {
+    let mut map = DumboOctopus::from_input("11111\n19991\n19191\n19991\n11111\n").unwrap();
+    map.step();
+    assert_eq!(9, map.flashed());
+    map.step();
+    assert_eq!(0, map.flashed());
+    assert_eq!(25, map.num_octopi());
+}
+
+#[test]
+fn animation() {
+    let frames = animate(&DumboOctopus::from_input(EXAMPLE_INPUT).unwrap());
+    assert_eq!(196, frames.len());
+    assert!(frames[0].text().starts_with("5483143223\n"));
+    assert!(frames[195].text().starts_with("0000000000\n"));
+    assert!(frames[195].text().ends_with("100 flashed\n"));
+}
+
 #[test]
 fn test_part_one() {
-    let result = part_one(OUR_INPUT.unwrap());
+    let input = personal_input!(crate::YEAR, 11);
+    let result = part_one(&DumboOctopus::from_input(&input).unwrap());
     println!("part one: {}", result);
     assert_eq!(1644, result);
 }
 
 #[test]
 fn test_part_two() {
-    let result = part_two(OUR_INPUT.unwrap());
+    let input = personal_input!(crate::YEAR, 11);
+    let result = part_two(&DumboOctopus::from_input(&input).unwrap());
     println!("part two: {}", result);
     assert_eq!(229, result);
 }
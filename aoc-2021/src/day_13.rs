@@ -1,11 +1,38 @@
 //! AOC Day xx
 // use aoc_2021;
-use log;
-use regex::Regex;
-use simple_logger::SimpleLogger;
+use aoc_common::ocr;
+use aoc_common::parse;
+use aoc_common::render::{Frame, Render, Tone};
+use aoc_common::transform::Axis;
+use aoc_common::{Answer, AocResult, FromLine, Solution, SparseGrid};
+#[cfg(test)]
+use aoc_common::personal_input;
 
 use std::collections::VecDeque;
 
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Instructions;
+
+    fn parse(&self, input: &str) -> AocResult<Self::Input> {
+        Instructions::new(input)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> AocResult<Answer> {
+        Ok(part_one(input))
+    }
+
+    fn part_two(&self, input: &Self::Input) -> AocResult<Answer> {
+        part_two(input)
+    }
+
+    fn animate(&self, input: &Self::Input) -> AocResult<Vec<Frame>> {
+        Ok(animate(input))
+    }
+}
+
+#[cfg(test)]
 const EXAMPLE_INPUT: &str = "6,10
 0,14
 9,10
@@ -28,131 +55,106 @@ const EXAMPLE_INPUT: &str = "6,10
 fold along y=7
 fold along x=5";
 
-const OUR_INPUT: Result<&str, std::str::Utf8Error> =
-    std::str::from_utf8(include_bytes!("../assets/day_13.txt"));
-
-struct Instructions {
+#[derive(Debug, Clone)]
+pub struct Instructions {
     /// The points
-    points: Vec<(usize, usize)>,
+    points: SparseGrid<()>,
 
     /// the axis and value of the fold
-    folds: VecDeque<(char, usize)>,
+    folds: VecDeque<(Axis, isize)>,
 }
 
-impl Instructions {
-    pub fn new(input: &str) -> Instructions {
-        let points_re: Regex = Regex::new(r"(\d+),(\d+)").unwrap();
-        let folds_re: Regex = Regex::new(r"fold along ([xy])=(\d+)").unwrap();
+/// A line of the instructions: a point, or one of the folds after them.
+#[derive(Debug, FromLine)]
+enum Line {
+    #[line(r"^(\d+),(\d+)$", "`x,y`")]
+    Point(isize, isize),
+    #[line(r"^fold along ([xy])=(\d+)$", "`fold along <x|y>=<n>`")]
+    Fold(Axis, isize),
+}
 
-        let mut points = vec![];
+impl Instructions {
+    pub fn new(input: &str) -> AocResult<Instructions> {
+        let mut points = SparseGrid::new();
         let mut folds = VecDeque::new();
-        for line in input.split_terminator("\n") {
-            if let Some(captures) = points_re.captures(line) {
-                let cap_x = captures.get(1).expect("invalid regex somehow");
-                let cap_y = captures.get(2).expect("invalid regex somehow");
-                let x = cap_x.as_str().parse::<usize>().expect("invalid digit");
-                let y = cap_y.as_str().parse::<usize>().expect("invalid digit");
-                log::debug!("point {},{}", x, y);
-                points.push((x, y));
-            }
-
-            if let Some(captures) = folds_re.captures(line) {
-                let cap_axis = captures.get(1).expect("invalid regex somehow");
-                let cap_mag = captures.get(2).expect("invalid regex somehow");
-                let axis = cap_axis.as_str().chars().next().expect("invalid fold char");
-                let mag = cap_mag.as_str().parse::<usize>().expect("invalid digit");
-                log::debug!("fold along {}={}", axis, mag);
-                folds.push_back((axis, mag));
-            }
-        }
-
-        Instructions { points, folds }
-    }
-
-    pub fn bounding_box(&self) -> (usize, usize) {
-        let mut max_x = 0;
-        let mut max_y = 0;
-        for (x, y) in self.points.iter() {
-            if *x > max_x {
-                max_x = *x;
-            }
-            if *y > max_y {
-                max_y = *y;
+        for line in parse::lines(input)? {
+            match line {
+                Line::Point(x, y) => {
+                    log::trace!("point {},{}", x, y);
+                    points.insert((x, y), ());
+                }
+                Line::Fold(axis, mag) => {
+                    log::info!("fold along {:?}={}", axis, mag);
+                    folds.push_back((axis, mag));
+                }
             }
         }
 
-        max_x += 1;
-        max_y += 1;
-        log::debug!("bounding box=({},{})", max_x, max_y);
-        (max_x, max_y)
+        Ok(Instructions { points, folds })
     }
 
     /// Fold along the first available fold, if there is a fold available.
     /// If folded, returns `true`, else `false`.
     pub fn fold(&mut self) -> bool {
         if let Some((axis, mag)) = self.folds.pop_front() {
-            for (x, y) in self.points.iter_mut() {
-                match axis {
-                    'x' => {
-                        if *x > mag {
-                            let delta = *x - mag;
-                            *x = *x - 2 * delta;
-                        }
-                    }
-                    'y' => {
-                        if *y > mag {
-                            let delta = *y - mag;
-                            *y = *y - 2 * delta;
-                        }
-                    }
-
-                    _ => (),
-                }
-            }
-            self.points.sort();
-            self.points.dedup();
-            return true;
+            self.points = self.points.fold(axis, mag, |_, _| ());
+            true
         } else {
-            return false;
+            false
         }
     }
 
     pub fn count(&self) -> usize {
         self.points.len()
     }
+
+    /// The letters the points spell out.
+    pub fn read(&self) -> AocResult<String> {
+        ocr::read_sparse(&self.points)
+    }
 }
 
-impl std::fmt::Display for Instructions {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let (bound_x, bound_y) = self.bounding_box();
-        let mut field = vec!['.'; bound_x * bound_y];
-
-        // fill in the fold lines
-        for (axis, value) in self.folds.iter() {
-            if *axis == 'y' {
-                for x in 0..bound_x {
-                    field[bound_x * *value + x] = '-';
-                }
-            }
-            if *axis == 'x' {
-                for y in 0..bound_y {
-                    field[bound_x * y + *value] = '|';
-                }
+impl Instructions {
+    /// the points, with the fold lines still to come drawn in between
+    fn field(&self) -> String {
+        self.points.render(|(x, y), point| {
+            if point.is_some() {
+                'X'
+            } else if self.folds.contains(&(Axis::Y, y)) {
+                '-'
+            } else if self.folds.contains(&(Axis::X, x)) {
+                '|'
+            } else {
+                '.'
             }
-        }
+        })
+    }
+}
 
-        // fill in the points
-        for (x, y) in self.points.iter() {
-            field[*y * bound_x + *x] = 'X';
+impl Render for Instructions {
+    fn frame(&self) -> Frame {
+        let mut frame = Frame::new();
+        for line in self.field().lines() {
+            for c in line.chars() {
+                let tone = match c {
+                    'X' => Tone::Highlight,
+                    '.' => Tone::Dim,
+                    _ => Tone::Accent,
+                };
+                frame.push(c, tone);
+            }
+            frame.newline();
         }
+        frame.push_line(format!("count={}", self.count()), Tone::Normal);
+        frame
+    }
+}
 
+impl std::fmt::Display for Instructions {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         writeln!(f, "Instructions {{")?;
-        for y in 0..bound_y {
-            write!(f, "\t")?;
-            for x in 0..bound_x {
-                write!(f, "{}", field[y * bound_x + x])?;
-            }
-            write!(f, "\n")?;
+        for line in self.field().lines() {
+            writeln!(f, "\t{}", line)?;
         }
         writeln!(f, "\tcount={}", self.count())?;
         writeln!(f, "}}")?;
@@ -161,53 +163,88 @@ impl std::fmt::Display for Instructions {
     }
 }
 
-fn part_one(input: &str) -> isize {
-    let mut instructions = Instructions::new(input);
-    log::debug!("{}", instructions);
+fn part_one(input: &Instructions) -> Answer {
+    let mut instructions = input.clone();
+    log::trace!("{}", instructions);
     instructions.fold();
-    log::debug!("{}", instructions);
+    log::trace!("{}", instructions);
     let count = instructions.count();
     // instructions.fold();
     // println!("{}", instructions);
 
-    count as isize
+    count.into()
 }
 
-fn part_two(input: &str) -> isize {
-    let mut instructions = Instructions::new(input);
+fn part_two(input: &Instructions) -> AocResult<Answer> {
+    let mut instructions = input.clone();
     let mut folded = true;
     while folded {
-        log::debug!("{}", instructions);
+        log::trace!("{}", instructions);
         folded = instructions.fold();
     }
-    log::debug!("{}", instructions);
+    log::trace!("{}", instructions);
+
+    instructions.read().map(Answer::from)
+}
+
+/// The paper before and after each fold
+fn animate(input: &Instructions) -> Vec<Frame> {
+    let mut instructions = input.clone();
+    let mut frames = vec![instructions.frame()];
+    while instructions.fold() {
+        frames.push(instructions.frame());
+    }
 
-    instructions.count() as isize
+    frames
 }
 
 #[test]
 fn example_part_one() {
-    SimpleLogger::new().init().ok();
-    let result = part_one(EXAMPLE_INPUT);
+    let result = part_one(&Instructions::new(EXAMPLE_INPUT).unwrap());
     println!("example result: {}", result);
     assert_eq!(result, 17);
 }
 
 #[test]
 fn example_part_two() {
-    SimpleLogger::new().init().ok();
-    part_two(EXAMPLE_INPUT);
+    let mut instructions = Instructions::new(EXAMPLE_INPUT).unwrap();
+    while instructions.fold() {}
+    assert_eq!(
+        "Instructions {\n\tXXXXX\n\tX...X\n\tX...X\n\tX...X\n\tXXXXX\n\tcount=16\n}\n",
+        instructions.to_string()
+    );
+    // a square isn't a letter
+    assert!(part_two(&Instructions::new(EXAMPLE_INPUT).unwrap()).is_err());
+}
+
+#[test]
+fn animation() {
+    let frames = animate(&Instructions::new(EXAMPLE_INPUT).unwrap());
+    assert_eq!(3, frames.len());
+    assert!(frames[0].text().contains("\n-----------\n"));
+    assert!(frames[1].text().ends_with("count=17\n"));
+    assert_eq!("XXXXX\nX...X\nX...X\nX...X\nXXXXX\ncount=16\n", frames[2].text());
+}
+
+#[test]
+fn malformed() {
+    assert_eq!(Some(2), Instructions::new("6,10\n0;14\n").unwrap_err().line);
+    let err = Instructions::new("6,10\n\nfold along z=7\n").unwrap_err();
+    assert_eq!(Some(3), err.line);
 }
 
 #[test]
 fn test_part_one() {
-    let result = part_one(OUR_INPUT.unwrap());
+    let input = personal_input!(crate::YEAR, 13);
+    let result = part_one(&Instructions::new(&input).unwrap());
     println!("part one: {}", result);
     assert_eq!(706, result);
 }
 
 #[test]
 fn test_part_two() {
-    SimpleLogger::new().init().ok();
-    part_two(OUR_INPUT.unwrap());
+    let input = personal_input!(crate::YEAR, 13);
+    let result = part_two(&Instructions::new(&input).unwrap()).unwrap();
+    println!("part two: {}", result);
+    assert_eq!("LRFJBJEH", result);
 }
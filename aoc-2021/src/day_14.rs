@@ -3,6 +3,31 @@
 
 use std::collections::HashMap;
 
+use aoc_common::parse;
+use aoc_common::simulation::Simulation;
+use aoc_common::{Answer, AocError, AocResult, Solution};
+#[cfg(test)]
+use aoc_common::personal_input;
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Polymerization;
+
+    fn parse(&self, input: &str) -> AocResult<Self::Input> {
+        Polymerization::new(input)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> AocResult<Answer> {
+        Ok(part_one(input))
+    }
+
+    fn part_two(&self, input: &Self::Input) -> AocResult<Answer> {
+        Ok(part_two(input))
+    }
+}
+
+#[cfg(test)]
 const EXAMPLE_INPUT: &str = "NNCB
 
 CH -> B
@@ -23,10 +48,7 @@ CC -> N
 CN -> C
 ";
 
-const OUR_INPUT: Result<&str, std::str::Utf8Error> =
-    std::str::from_utf8(include_bytes!("../assets/day_14.txt"));
-
-#[derive(Copy, Clone)]
+#[derive(Debug, Copy, Clone)]
 struct PolyPair {
     /// The polymer pair
     pub pair: [char; 2],
@@ -66,7 +88,8 @@ impl std::fmt::Display for PolyPair {
     }
 }
 
-struct Polymerization {
+#[derive(Debug, Clone)]
+pub struct Polymerization {
     /// Our current state
     state: Vec<PolyPair>,
     /// Our pending
@@ -76,58 +99,45 @@ struct Polymerization {
 }
 
 impl Polymerization {
-    pub fn new(input: &str) -> Polymerization {
-        let mut rules = vec![];
-        let mut line_iter = input.split_terminator("\n");
+    pub fn new(input: &str) -> AocResult<Polymerization> {
+        let mut blocks = parse::blocks(input);
         let mut previous = ' ';
         let mut state: Vec<PolyPair> = vec![];
-        for char in line_iter.next().unwrap().chars() {
+        let template = blocks
+            .next()
+            .ok_or_else(|| AocError::new("expected a polymer template"))?;
+        if let Some(line) = template.lines().nth(1) {
+            return Err(AocError::at(input, line, "expected a blank line after the template"));
+        }
+        for char in template.chars() {
             state.push(PolyPair::new([previous, char], 1));
             previous = char;
         }
         state.push(PolyPair::new([previous, ' '], 1));
 
-        line_iter.next().unwrap(); // empty newline
-
-        for line in line_iter {
-            let mut split = line.split(" -> ");
-            let left = split.next().expect("invalid rule");
-            let right = split.next().expect("invalid rule");
-
+        let mut rules = vec![];
+        for line in blocks.flat_map(str::lines) {
+            let (left, right) = parse::rule(input, line)?;
             let mut left_chars = left.chars();
-            rules.push((
-                left_chars.next().unwrap(),
-                left_chars.next().unwrap(),
-                right.chars().next().unwrap(),
-            ));
+            let mut right_chars = right.chars();
+            let rule = match (
+                left_chars.next(),
+                left_chars.next(),
+                left_chars.next(),
+                right_chars.next(),
+                right_chars.next(),
+            ) {
+                (Some(l1), Some(l2), None, Some(r), None) => (l1, l2, r),
+                _ => return Err(AocError::at(input, line, "expected `AB -> C`")),
+            };
+            rules.push(rule);
         }
 
-        Polymerization {
+        Ok(Polymerization {
             state,
             pending: vec![],
             rules,
-        }
-    }
-
-    pub fn step(&mut self) {
-        for pair in self.state.drain(0..) {
-            let mut match_found = false;
-            for (l1, l2, r) in self.rules.iter() {
-                if pair.pair[0] == *l1 && pair.pair[1] == *l2 {
-                    let (p1, p2) = pair.insert(*r);
-                    Self::insert_to_pending(&mut self.pending, p1);
-                    Self::insert_to_pending(&mut self.pending, p2);
-                    match_found = true;
-                    break;
-                }
-            }
-
-            if !match_found {
-                Self::insert_to_pending(&mut self.pending, pair);
-            }
-        }
-
-        std::mem::swap(&mut self.state, &mut self.pending);
+        })
     }
 
     fn insert_to_pending(pending: &mut Vec<PolyPair>, pair: PolyPair) {
@@ -148,7 +158,7 @@ impl Polymerization {
 
     #[allow(dead_code)]
     pub fn print_state(&self) {
-        print!("\n");
+        log::trace!("{}", self);
     }
 
     pub fn compute(&self) -> usize {
@@ -182,13 +192,36 @@ impl Polymerization {
     }
 }
 
+impl Simulation for Polymerization {
+    fn step(&mut self) {
+        for pair in self.state.drain(0..) {
+            let mut match_found = false;
+            for (l1, l2, r) in self.rules.iter() {
+                if pair.pair[0] == *l1 && pair.pair[1] == *l2 {
+                    let (p1, p2) = pair.insert(*r);
+                    Self::insert_to_pending(&mut self.pending, p1);
+                    Self::insert_to_pending(&mut self.pending, p2);
+                    match_found = true;
+                    break;
+                }
+            }
+
+            if !match_found {
+                Self::insert_to_pending(&mut self.pending, pair);
+            }
+        }
+
+        std::mem::swap(&mut self.state, &mut self.pending);
+    }
+}
+
 impl std::fmt::Display for Polymerization {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         writeln!(f, "Poly {{")?;
         for c in self.state.iter() {
             writeln!(f, "\t{}", c)?;
         }
-        write!(f, "\n")?;
+        writeln!(f)?;
 
         for (l1, l2, r) in self.rules.iter() {
             writeln!(f, "\t{}{} -> {}", l1, l2, r)?;
@@ -199,53 +232,61 @@ impl std::fmt::Display for Polymerization {
     }
 }
 
-fn part_one(input: &str) -> isize {
-    let mut poly = Polymerization::new(input);
-    println!("{}", poly);
+fn part_one(input: &Polymerization) -> Answer {
+    let mut poly = input.clone();
+    log::trace!("{}", poly);
 
-    for _i in 0..10 {
-        poly.step();
-    }
+    poly.run(10);
 
-    poly.compute() as isize
+    poly.compute().into()
 }
 
-fn part_two(input: &str) -> isize {
-    let mut poly = Polymerization::new(input);
-    println!("{}", poly);
+fn part_two(input: &Polymerization) -> Answer {
+    let mut poly = input.clone();
+    log::trace!("{}", poly);
 
     for i in 0..40 {
-        println!("step {}", i);
+        log::trace!("step {}", i);
         poly.step();
     }
 
-    poly.compute() as isize
+    poly.compute().into()
 }
 
 #[test]
 fn example_part_one() {
-    let result = part_one(EXAMPLE_INPUT);
+    let result = part_one(&Polymerization::new(EXAMPLE_INPUT).unwrap());
     println!("example result: {}", result);
     assert_eq!(result, 1588);
 }
 
 #[test]
 fn example_part_two() {
-    let result = part_two(EXAMPLE_INPUT);
+    let result = part_two(&Polymerization::new(EXAMPLE_INPUT).unwrap());
     println!("example result: {}", result);
-    assert_eq!(result, 2188189693529);
+    assert_eq!(result, 2188189693529_u64);
+}
+
+#[test]
+fn malformed() {
+    let err = Polymerization::new("NNCB\n\nCH -> B\nHHN -> N\n").unwrap_err();
+    assert_eq!(Some(4), err.line);
+    assert_eq!(Some(2), Polymerization::new("NNCB\nCH -> B\n").unwrap_err().line);
+    assert!(Polymerization::new("").is_err());
 }
 
 #[test]
 fn test_part_one() {
-    let result = part_one(OUR_INPUT.unwrap());
+    let input = personal_input!(crate::YEAR, 14);
+    let result = part_one(&Polymerization::new(&input).unwrap());
     println!("part one: {}", result);
     assert_eq!(3118, result);
 }
 
 #[test]
 fn test_part_two() {
-    let result = part_two(OUR_INPUT.unwrap());
+    let input = personal_input!(crate::YEAR, 14);
+    let result = part_two(&Polymerization::new(&input).unwrap());
     println!("part two: {}", result);
-    assert_eq!(4332887448171, result);
+    assert_eq!(4332887448171_u64, result);
 }
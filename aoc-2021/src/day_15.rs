@@ -1,7 +1,37 @@
 //! AOC Day xx
 // use aoc_2021;
-use ansi_term;
+use aoc_common::graph;
+use aoc_common::neighbors::{Adjacency, Edge};
+use aoc_common::render::{Frame, Render, Tone};
+use aoc_common::{Answer, AocResult, Grid, Solution};
+#[cfg(test)]
+use aoc_common::personal_input;
 
+use std::collections::HashSet;
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Grid<u8>;
+
+    fn parse(&self, input: &str) -> AocResult<Self::Input> {
+        parse(input)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> AocResult<Answer> {
+        part_one(input)
+    }
+
+    fn part_two(&self, input: &Self::Input) -> AocResult<Answer> {
+        part_two(input)
+    }
+
+    fn animate(&self, input: &Self::Input) -> AocResult<Vec<Frame>> {
+        Ok(animate(input))
+    }
+}
+
+#[cfg(test)]
 const EXAMPLE_INPUT: &str = "1163751742
 1381373672
 2136511328
@@ -13,6 +43,7 @@ const EXAMPLE_INPUT: &str = "1163751742
 1293138521
 2311944581";
 
+#[cfg(test)]
 const EXAMPLE_INPUT_2: &str = "1999999999
 1999999999
 1999999999
@@ -23,250 +54,166 @@ const EXAMPLE_INPUT_2: &str = "1999999999
 1999999111
 1999999191
 1111111191";
-const OUR_INPUT: Result<&str, std::str::Utf8Error> =
-    std::str::from_utf8(include_bytes!("../assets/day_15.txt"));
-
-struct CaveRisk<const X: usize, const Y: usize> {
-    risk: [[u8; Y]; X],
-    distance: [[usize; Y]; X],
-    visited: [[bool; Y]; X],
-    current: (usize, usize),
-    target: (usize, usize),
+struct CaveRisk {
+    risk: Grid<u8>,
+    /// the safest way through, once it's been found
+    path: Vec<(usize, usize)>,
 }
 
-const MOVE_DELTAS: [(isize, isize); 4] = [(1, 0), (-1, 0), (0, 1), (0, -1)];
-
-impl<const X: usize, const Y: usize> CaveRisk<X, Y> {
-    pub fn new(input: &str) -> Box<Self> {
-        let mut risk = [[0; Y]; X];
-
-        for (ind_y, line) in input.split_terminator('\n').enumerate() {
-            for (ind_x, c) in line.chars().enumerate() {
-                risk[ind_x][ind_y] = c.to_digit(10).unwrap() as u8;
-            }
-        }
-
-        Self::new_from_map(&risk)
-    }
-
-    pub fn new_from_map(map: &[[u8; Y]; X]) -> Box<Self> {
-        let mut cave = Box::new(CaveRisk {
-            risk: *map,
-            distance: [[usize::MAX; Y]; X],
-            visited: [[false; Y]; X],
-            current: (0, 0),
-            target: (X - 1, Y - 1),
-        });
-
-        cave.distance[0][0] = 0; // starting location has no distance
+/// How many times over the full map is the one in the input, in each direction
+const EXPANSION: usize = 5;
 
-        cave
+impl CaveRisk {
+    pub fn new(risk: Grid<u8>) -> Self {
+        CaveRisk { risk, path: vec![] }
     }
 
-    pub fn step(&mut self) -> bool {
-        // visit all neighbors
-        let x = self.current.0;
-        let y = self.current.1;
-        for (dx, dy) in MOVE_DELTAS.iter() {
-            let px = x as isize + dx;
-            let py = y as isize + dy;
-            // ensure the point is valid
-            if px < 0 || px >= X as isize || py < 0 || py >= Y as isize {
-                continue;
-            }
-
-            let px = px as usize;
-            let py = py as usize;
-            if self.visited[px][py] {
-                continue;
-            }
-
-            // compute potential new distance. Use if it's shorter than existing
-            let distance = self.distance[x][y] + self.risk[px][py] as usize;
-            if distance < self.distance[px][py] {
-                self.distance[px][py] = distance;
-            }
-        }
-
-        self.visited[x][y] = true;
-
-        // find next "current"
-        let mut next = (0, 0);
-        let mut next_distance = usize::MAX;
-        for y in 0..Y {
-            for x in 0..X {
-                if self.visited[x][y] {
-                    continue;
-                }
-                if self.distance[x][y] < next_distance {
-                    next_distance = self.distance[x][y];
-                    next = (x, y);
-                }
-            }
-        }
-        self.current = next;
-
-        // check if we should halt
-        if self.current == self.target {
-            return true;
-        } else {
-            return false;
-        }
+    /// Finds the safest way from the top left to the bottom right, returning its total risk.
+    /// Entering a cell costs its risk level, so the starting one never counts.
+    pub fn safest_path(&mut self) -> usize {
+        let target = (self.risk.width() - 1, self.risk.height() - 1);
+        let risk = &self.risk;
+        let path = graph::dijkstra(
+            (0, 0),
+            |&position| {
+                risk.neighbors(position, Adjacency::Orthogonal, &Edge::Clip)
+                    .filter_map(|(point, cell)| Some((point?, *cell as usize)))
+            },
+            |&position| position == target,
+        )
+        .expect("every cell of the map can be reached");
+
+        self.path = path.nodes;
+        path.cost
     }
 }
 
-impl<const X: usize, const Y: usize> std::fmt::Display for CaveRisk<X, Y> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        // calculate the most efficient path, if it is fully computed
-        let compute_path = false;
-        let mut position = self.target;
-        let mut path = vec![];
-        if compute_path && self.distance[position.0][position.1] != usize::MAX {
-            path.push(position);
-            loop {
-                let mut least_expensive = usize::MAX;
-                let mut next_position = (0, 0);
-
-                if position == (0, 0) {
-                    break;
-                }
-
-                for (dx, dy) in MOVE_DELTAS.iter() {
-                    let px = position.0 as isize + dx;
-                    let py = position.1 as isize + dy;
-                    // ensure the point is valid
-                    if px >= X as isize || py >= Y as isize || px < 0 || py < 0 {
-                        continue;
-                    }
-
-                    let px = px as usize;
-                    let py = py as usize;
-
-                    if self.distance[px][py] < least_expensive {
-                        least_expensive = self.distance[px][py];
-                        next_position = (px, py);
-                    }
-                }
-
-                if least_expensive == usize::MAX {
-                    break;
-                }
-                path.push(next_position);
-                position = next_position;
-            }
-        }
-
-        for y in 0..Y {
-            for x in 0..X {
-                let mut c = ansi_term::Style::new();
-                if self.distance[x][y] == usize::MAX {
-                    c = c.dimmed();
-                }
-                if path.contains(&(x, y)) {
-                    c = c.bold();
-                }
-                write!(f, "{}", c.paint(format!("{}", self.risk[x][y])))?;
-            }
-            write!(f, "\n")?;
-        }
-        Ok(())
+impl CaveRisk {
+    /// The map, with the first `steps` cells of the path standing out
+    fn draw_path(&self, steps: usize) -> Frame {
+        let path: HashSet<&(usize, usize)> = self.path.iter().take(steps).collect();
+        Frame::from_grid(&self.risk, |point, risk| {
+            let tone = if path.contains(&point) { Tone::Highlight } else { Tone::Dim };
+            (*risk, tone)
+        })
     }
 }
 
-fn expand_map<const X: usize, const Y: usize, const EX: usize, const EY: usize>(
-    input: &str,
-) -> [[u8; EY]; EX] {
-    let mut risk: [[u8; EY]; EX] = [[0; EY]; EX];
-
-    // ensure the expanded map is a multiple of the original
-    assert!(EY % Y == 0);
-    assert!(EX % X == 0);
-
-    for (ind_y, line) in input.split_terminator('\n').enumerate() {
-        for (ind_x, c) in line.chars().enumerate() {
-            risk[ind_x][ind_y] = c.to_digit(10).unwrap() as u8;
-        }
+impl Render for CaveRisk {
+    fn frame(&self) -> Frame {
+        self.draw_path(self.path.len())
     }
+}
 
-    for dy in 0..(EY / Y) {
-        for dx in 0..(EX / X) {
-            for y in 0..Y {
-                for x in 0..X {
-                    let px = x + dx * X;
-                    let py = y + dy * Y;
-                    risk[px][py] = risk[x][y] + (dy + dx) as u8;
-                    while risk[px][py] > 9 {
-                        risk[px][py] -= 9;
-                    }
-                }
-            }
-        }
+impl std::fmt::Display for CaveRisk {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.frame())
     }
+}
 
-    risk
+/// Tiles `risk` `times` over in each direction, each tile one riskier than the one above or to
+/// the left of it, wrapping back around from 9 to 1.
+fn expand_map(risk: &Grid<u8>, times: usize) -> Grid<u8> {
+    risk.tile(times, times, |(tx, ty), risk| {
+        ((*risk as usize + tx + ty + 8) % 9 + 1) as u8
+    })
 }
 
-fn part_one<const X: usize, const Y: usize>(input: &str) -> isize {
-    let mut cave = CaveRisk::<X, Y>::new(input);
+fn parse(input: &str) -> AocResult<Grid<u8>> {
+    Grid::parse(input, "a risk level from 1 to 9", |c| {
+        c.to_digit(10).filter(|&risk| risk > 0).map(|risk| risk as u8)
+    })
+}
 
-    let mut done = false;
-    while !done {
-        done = cave.step();
-    }
+fn part_one(input: &Grid<u8>) -> AocResult<Answer> {
+    let mut cave = CaveRisk::new(input.clone());
+    let risk = cave.safest_path();
 
-    print!("{}", cave);
+    log::trace!("{}", cave);
 
-    let (tx, ty) = cave.target;
-    cave.distance[tx as usize][ty as usize] as isize
+    Ok(risk.into())
 }
 
-fn part_two<const X: usize, const Y: usize, const EX: usize, const EY: usize>(
-    input: &str,
-) -> isize {
-    let map: [[u8; EY]; EX] = expand_map::<X, Y, EX, EY>(input);
-
-    let mut cave = CaveRisk::<EX, EY>::new_from_map(&map);
+fn part_two(input: &Grid<u8>) -> AocResult<Answer> {
+    let mut cave = CaveRisk::new(expand_map(input, EXPANSION));
+    let risk = cave.safest_path();
 
-    let mut done = false;
-    while !done {
-        done = cave.step();
-    }
+    log::trace!("{}", cave);
 
-    print!("{}", cave);
-
-    let (tx, ty) = cave.target;
+    Ok(risk.into())
+}
 
-    cave.distance[tx as usize][ty as usize] as isize
+/// The safest path through the small map, drawn in a bit at a time
+fn animate(input: &Grid<u8>) -> Vec<Frame> {
+    const MAX_FRAMES: usize = 100;
+    let mut cave = CaveRisk::new(input.clone());
+    cave.safest_path();
+
+    let stride = cave.path.len().div_ceil(MAX_FRAMES).max(1);
+    let mut frames: Vec<Frame> = (0..cave.path.len())
+        .step_by(stride)
+        .map(|steps| cave.draw_path(steps))
+        .collect();
+    frames.push(cave.frame());
+    frames
 }
 
 #[test]
 fn example_part_one() {
-    let result = part_one::<10, 10>(EXAMPLE_INPUT);
+    let result = part_one(&parse(EXAMPLE_INPUT).unwrap()).unwrap();
     println!("example result: {}", result);
     assert_eq!(result, 40);
 
-    let result = part_one::<10, 10>(EXAMPLE_INPUT_2);
+    let result = part_one(&parse(EXAMPLE_INPUT_2).unwrap()).unwrap();
     println!("example result: {}", result);
     assert_eq!(result, 22);
 }
 
 #[test]
 fn example_part_two() {
-    let result = part_two::<10, 10, 50, 50>(EXAMPLE_INPUT);
+    let result = part_two(&parse(EXAMPLE_INPUT).unwrap()).unwrap();
     println!("example result: {}", result);
     assert_eq!(result, 315);
 }
 
+#[test]
+fn other_sizes() {
+    let map = parse("1163\n1381\n").unwrap();
+    assert_eq!(11, part_one(&map).unwrap());
+    assert_eq!((20, 10), (expand_map(&map, 5).width(), expand_map(&map, 5).height()));
+    assert_eq!(5, expand_map(&map, 5)[(18, 8)]);
+}
+
+#[test]
+fn animation() {
+    let frames = animate(&parse(EXAMPLE_INPUT).unwrap());
+    // a frame for each step along the path, the start included, and one with all of it
+    assert_eq!(20, frames.len());
+    assert_eq!(format!("{}\n", EXAMPLE_INPUT), frames[19].text());
+}
+
+#[test]
+fn malformed() {
+    let err = parse("1163\n13a1\n").unwrap_err();
+    assert_eq!((Some(2), Some(3)), (err.line, err.column));
+    let err = parse("1163\n138\n").unwrap_err();
+    assert_eq!(Some(2), err.line);
+    let err = parse("1163\n1301\n").unwrap_err();
+    assert_eq!((Some(2), Some(3)), (err.line, err.column));
+}
+
 #[test]
 fn test_part_one() {
-    let result = part_one::<100, 100>(OUR_INPUT.unwrap());
+    let input = personal_input!(crate::YEAR, 15);
+    let result = part_one(&parse(&input).unwrap()).unwrap();
     println!("part one: {}", result);
     assert_eq!(741, result);
 }
 
 #[test]
 fn test_part_two() {
-    let result = part_two::<100, 100, 500, 500>(OUR_INPUT.unwrap());
+    let input = personal_input!(crate::YEAR, 15);
+    let result = part_two(&parse(&input).unwrap()).unwrap();
     println!("part two: {}", result);
     assert_eq!(2976, result);
 }
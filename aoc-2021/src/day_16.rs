@@ -1,8 +1,31 @@
 //! AOC Day xx
 // use aoc_2021;
 
+use aoc_common::{Answer, AocError, AocResult, Solution};
+#[cfg(test)]
+use aoc_common::personal_input;
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Packet;
+
+    fn parse(&self, input: &str) -> AocResult<Self::Input> {
+        parse(input)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> AocResult<Answer> {
+        Ok(part_one(input))
+    }
+
+    fn part_two(&self, input: &Self::Input) -> AocResult<Answer> {
+        part_two(input)
+    }
+}
+
 const PTYPE_LITERAL_VALUE: usize = 4;
 
+#[cfg(test)]
 const EXAMPLE_PART_ONE: [(&str, usize); 4] = [
     ("8A004A801A8002F478", 16),
     ("620080001611562C8802118E34", 12),
@@ -10,6 +33,7 @@ const EXAMPLE_PART_ONE: [(&str, usize); 4] = [
     ("A0016C880162017C3686B18A3D4780", 31),
 ];
 
+#[cfg(test)]
 const EXAMPLE_PART_TWO: [(&str, usize); 8] = [
     ("C200B40A82", 3),
     ("04005AC33890", 54),
@@ -21,9 +45,6 @@ const EXAMPLE_PART_TWO: [(&str, usize); 8] = [
     ("9C0141080250320F1802104A08", 1),
 ];
 
-const OUR_INPUT: Result<&str, std::str::Utf8Error> =
-    std::str::from_utf8(include_bytes!("../assets/day_16.txt"));
-
 #[derive(Debug, Eq, PartialEq)]
 enum PacketType {
     Literal,
@@ -33,7 +54,7 @@ enum PacketType {
 }
 
 #[derive(Debug)]
-struct Packet {
+pub struct Packet {
     version: usize,
     #[allow(dead_code)]
     ptype: PacketType,
@@ -42,10 +63,10 @@ struct Packet {
 }
 
 impl Packet {
-    fn calculate(&self) -> usize {
+    fn calculate(&self) -> AocResult<usize> {
         // if we're a literal, all we do is return our associated value
         if self.ptype == PacketType::Literal {
-            return self.value;
+            return Ok(self.value);
         }
 
         // if we're not a literal, we need to do some calculation
@@ -53,129 +74,147 @@ impl Packet {
 
         // calculate all of our sub-packets
         for p in self.sub_packets.iter() {
-            values.push(p.calculate());
+            values.push(p.calculate()?);
         }
 
         let op = match self.ptype {
             PacketType::OperatorSubPackets(v) => v,
             PacketType::OperatorTotalLength(v) => v,
-            _ => panic!("invalid packet type"),
+            _ => return Err(AocError::new("packet was never fully parsed")),
         };
 
+        let no_values = || AocError::new(format!("operator {} has no sub-packets", op));
+        if (5..=7).contains(&op) && values.len() != 2 {
+            return Err(AocError::new(format!(
+                "comparison operator {} needs 2 sub-packets, found {}",
+                op,
+                values.len()
+            )));
+        }
+
         match op {
-            0 => values.iter().sum(),
-            1 => values.iter().product(),
-            2 => *values.iter().min().unwrap(),
-            3 => *values.iter().max().unwrap(),
+            0 => Ok(values.iter().sum()),
+            1 => Ok(values.iter().product()),
+            2 => values.iter().min().copied().ok_or_else(no_values),
+            3 => values.iter().max().copied().ok_or_else(no_values),
             5 => {
                 // greater than
-                assert_eq!(2, values.len());
                 if values[0] > values[1] {
-                    1
+                    Ok(1)
                 } else {
-                    0
+                    Ok(0)
                 }
             }
             6 => {
                 // less than
-                assert_eq!(2, values.len());
                 if values[0] < values[1] {
-                    1
+                    Ok(1)
                 } else {
-                    0
+                    Ok(0)
                 }
             }
             7 => {
                 // equal to
-                assert_eq!(2, values.len());
                 if values[0] == values[1] {
-                    1
+                    Ok(1)
                 } else {
-                    0
+                    Ok(0)
                 }
             }
-            _ => panic!("invalid operator"),
+            _ => Err(AocError::new(format!("invalid operator {}", op))),
         }
     }
 }
 
+/// Reads the `len` bits starting at `start` as a number.
+fn read_bits(input: &str, start: usize, len: usize) -> AocResult<usize> {
+    input
+        .get(start..start + len)
+        .and_then(|bits| usize::from_str_radix(bits, 2).ok())
+        .ok_or_else(|| AocError::new("the transmission ends in the middle of a packet"))
+}
+
 /// Parses the header for our Packet
 ///
 /// # Returns
 /// (bits_used, (version, type))
-fn parse_header(input: &str) -> (usize, (usize, usize)) {
-    let packet_version = usize::from_str_radix(&input[0..3], 2).unwrap();
-    let packet_type = usize::from_str_radix(&input[3..6], 2).unwrap();
+fn parse_header(input: &str) -> AocResult<(usize, (usize, usize))> {
+    let packet_version = read_bits(input, 0, 3)?;
+    let packet_type = read_bits(input, 3, 3)?;
 
-    (6, (packet_version, packet_type))
+    Ok((6, (packet_version, packet_type)))
 }
 
-fn parse_literal_value(mut input: &str) -> (usize, usize) {
+fn parse_literal_value(mut input: &str) -> AocResult<(usize, usize)> {
     let mut final_value = 0;
     let mut bits_used = 0;
     loop {
-        let value = usize::from_str_radix(&input[1..5], 2).unwrap();
+        let value = read_bits(input, 1, 4)?;
         final_value <<= 4;
         final_value |= value;
         bits_used += 5;
-        if input.chars().nth(0).unwrap() == '0' {
+        if input.starts_with('0') {
             break;
         }
         input = &input[5..];
     }
 
-    (bits_used, final_value)
+    Ok((bits_used, final_value))
 }
 
-fn parse_op_total_length(mut input: &str) -> (usize, Vec<Packet>) {
+fn parse_op_total_length(mut input: &str) -> AocResult<(usize, Vec<Packet>)> {
     let mut bits_used = 0;
     let mut packets = vec![];
-    let mut total_length = isize::from_str_radix(&input[0..15], 2).unwrap();
+    let mut total_length = read_bits(input, 0, 15)? as isize;
     input = &input[15..];
     bits_used += 15;
 
     while total_length > 0 {
-        let (bits, packet) = parse_packet(input);
+        let (bits, packet) = parse_packet(input)?;
         input = &input[bits..];
         bits_used += bits;
         total_length -= bits as isize;
         packets.push(packet);
     }
 
-    (bits_used, packets)
+    Ok((bits_used, packets))
 }
 
-fn parse_op_num_sub(mut input: &str) -> (usize, Vec<Packet>) {
+fn parse_op_num_sub(mut input: &str) -> AocResult<(usize, Vec<Packet>)> {
     let mut bits_used = 0;
     let mut packets = vec![];
-    let num_packets = usize::from_str_radix(&input[0..11], 2).unwrap();
+    let num_packets = read_bits(input, 0, 11)?;
     input = &input[11..];
     bits_used += 11;
 
     for _ in 0..num_packets {
-        let (bits, packet) = parse_packet(input);
+        let (bits, packet) = parse_packet(input)?;
         input = &input[bits..];
         bits_used += bits;
         packets.push(packet);
     }
 
-    (bits_used, packets)
+    Ok((bits_used, packets))
 }
 
-fn hex_str_to_bin_str(input: &str) -> String {
+/// Expands `hex`, a slice of `input`, to a string of bits.
+fn hex_str_to_bin_str(input: &str, hex: &str) -> AocResult<String> {
     let mut out = String::new();
 
-    for char in input.chars() {
-        let digit = char.to_digit(16).unwrap();
+    for (ind, char) in hex.char_indices() {
+        let digit = char.to_digit(16).ok_or_else(|| {
+            let span = &hex[ind..ind + char.len_utf8()];
+            AocError::at(input, span, format!("expected a hex digit, found `{}`", span))
+        })?;
         out.push_str(&format!("{:04b}", digit));
     }
 
-    out
+    Ok(out)
 }
 
-fn parse_packet(mut input: &str) -> (usize, Packet) {
+fn parse_packet(mut input: &str) -> AocResult<(usize, Packet)> {
     let mut bits_used = 0;
-    let (bits, (p_version, p_type)) = parse_header(input);
+    let (bits, (p_version, p_type)) = parse_header(input)?;
     bits_used += bits;
     input = &input[bits..];
     let mut packet = Packet {
@@ -187,27 +226,26 @@ fn parse_packet(mut input: &str) -> (usize, Packet) {
 
     if p_type == PTYPE_LITERAL_VALUE {
         packet.ptype = PacketType::Literal;
-        let (bits, value) = parse_literal_value(input);
+        let (bits, value) = parse_literal_value(input)?;
         packet.value = value;
         bits_used += bits;
     } else {
         // we have a operator packet
-        let (bits, sub_packets) = match input.chars().nth(0).unwrap() {
-            '0' => {
+        let (bits, sub_packets) = match read_bits(input, 0, 1)? {
+            0 => {
                 packet.ptype = PacketType::OperatorTotalLength(p_type);
-                parse_op_total_length(&input[1..])
+                parse_op_total_length(&input[1..])?
             }
-            '1' => {
+            _ => {
                 packet.ptype = PacketType::OperatorSubPackets(p_type);
-                parse_op_num_sub(&input[1..])
+                parse_op_num_sub(&input[1..])?
             }
-            _ => panic!("invalid operator packet"),
         };
         packet.sub_packets = sub_packets;
         bits_used += bits + 1; // + 1 for the length type ID
     }
 
-    (bits_used, packet)
+    Ok((bits_used, packet))
 }
 
 fn sum_versions(packet: &Packet) -> usize {
@@ -220,29 +258,28 @@ fn sum_versions(packet: &Packet) -> usize {
     v_sum
 }
 
-fn part_one(input: &str) -> usize {
-    let bin_str: &str = &hex_str_to_bin_str(input);
+fn parse(input: &str) -> AocResult<Packet> {
+    let bin_str: &str = &hex_str_to_bin_str(input, input.trim())?;
     // println!("{} -> {}", input, bin_str);
 
-    let (_bits, packet) = parse_packet(bin_str);
+    let (_bits, packet) = parse_packet(bin_str)?;
     // println!("Parsed packet {:#?}", packet);
 
-    sum_versions(&packet)
+    Ok(packet)
 }
 
-fn part_two(input: &str) -> usize {
-    let bin_str: &str = &hex_str_to_bin_str(input);
-    // println!("{} -> {}", input, bin_str);
-
-    let (_bits, packet) = parse_packet(bin_str);
+fn part_one(packet: &Packet) -> Answer {
+    sum_versions(packet).into()
+}
 
-    packet.calculate()
+fn part_two(packet: &Packet) -> AocResult<Answer> {
+    packet.calculate().map(Answer::from)
 }
 
 #[test]
 fn example_part_one() {
     for (input, answer) in EXAMPLE_PART_ONE {
-        let result = part_one(input);
+        let result = part_one(&parse(input).unwrap());
         println!("example result: {}", result);
         assert_eq!(result, answer);
     }
@@ -251,22 +288,35 @@ fn example_part_one() {
 #[test]
 fn example_part_two() {
     for (input, answer) in EXAMPLE_PART_TWO {
-        let result = part_two(input);
+        let result = part_two(&parse(input).unwrap()).unwrap();
         println!("example result: {}", result);
         assert_eq!(result, answer);
     }
 }
 
+#[test]
+fn malformed() {
+    let err = parse("8A004G4A801A8002F478").unwrap_err();
+    assert_eq!((Some(1), Some(6)), (err.line, err.column));
+    // an operator packet claiming more sub-packets than there are
+    assert!(parse("8A00").is_err());
+    // a "greater than" packet (type 5) with a single literal inside
+    let packet = parse("36004408").unwrap();
+    assert!(part_two(&packet).is_err());
+}
+
 #[test]
 fn test_part_one() {
-    let result = part_one(OUR_INPUT.unwrap());
+    let input = personal_input!(crate::YEAR, 16);
+    let result = part_one(&parse(&input).unwrap());
     println!("part one: {}", result);
     assert_eq!(974, result);
 }
 
 #[test]
 fn test_part_two() {
-    let result = part_two(OUR_INPUT.unwrap());
+    let input = personal_input!(crate::YEAR, 16);
+    let result = part_two(&parse(&input).unwrap()).unwrap();
     println!("part two: {}", result);
-    assert_eq!(180616437720, result);
+    assert_eq!(180616437720_u64, result);
 }
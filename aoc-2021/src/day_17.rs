@@ -1,101 +1,109 @@
 //! AOC Day xx
 // use aoc_2021;
 
-const EXAMPLE_INPUT: &str = "target area: x=20..30, y=-10..-5";
+use aoc_common::geometry::Rect;
+use aoc_common::parse;
+use aoc_common::simulation::Simulation;
+use aoc_common::{Answer, AocResult, Point2, Solution};
+#[cfg(test)]
+use aoc_common::personal_input;
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = TargetArea;
+
+    fn parse(&self, input: &str) -> AocResult<Self::Input> {
+        TargetArea::new_from_str(input)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> AocResult<Answer> {
+        Ok(part_one(input))
+    }
+
+    fn part_two(&self, input: &Self::Input) -> AocResult<Answer> {
+        Ok(part_two(input))
+    }
+}
 
-const OUR_INPUT: &str = "target area: x=119..176, y=-141..-84";
+#[cfg(test)]
+const EXAMPLE_INPUT: &str = "target area: x=20..30, y=-10..-5";
 
 #[derive(Debug)]
-struct TargetArea {
-    x_min: isize,
-    x_max: isize,
-    y_min: isize,
-    y_max: isize,
+pub struct TargetArea {
+    area: Rect,
 }
 
 impl TargetArea {
     fn new(x_min: isize, x_max: isize, y_min: isize, y_max: isize) -> Self {
         Self {
-            x_min,
-            x_max,
-            y_min,
-            y_max,
+            area: Rect::new(Point2::new(x_min, y_min), Point2::new(x_max, y_max)),
         }
     }
 
-    fn new_from_str(input: &str) -> Self {
-        // remove prefix
-        let input = input.split("target area: ").nth(1).unwrap();
-
-        let mut x_y_split = input.split(", ");
-        let x_range = &x_y_split.next().unwrap()[2..];
-        let y_range = &x_y_split.next().unwrap()[2..];
-
-        println!("x_range: {}", x_range);
-        println!("y_range: {}", y_range);
-
-        let x_range: Vec<&str> = x_range.split("..").collect();
-        let y_range: Vec<&str> = y_range.split("..").collect();
-
-        TargetArea::new(
-            x_range[0].parse().unwrap(),
-            x_range[1].parse().unwrap(),
-            y_range[0].parse().unwrap(),
-            y_range[1].parse().unwrap(),
-        )
+    fn new_from_str(input: &str) -> AocResult<Self> {
+        let captures = parse::captures(
+            input,
+            input.trim(),
+            r"^target area: x=(\S+)\.\.(\S+), y=(\S+)\.\.(\S+)$",
+            "`target area: x=<min>..<max>, y=<min>..<max>`",
+        )?;
+
+        log::info!("x_range: {}..{}", &captures[1], &captures[2]);
+        log::info!("y_range: {}..{}", &captures[3], &captures[4]);
+
+        Ok(TargetArea::new(
+            parse::capture(input, &captures, 1, "x_min")?,
+            parse::capture(input, &captures, 2, "x_max")?,
+            parse::capture(input, &captures, 3, "y_min")?,
+            parse::capture(input, &captures, 4, "y_max")?,
+        ))
     }
 
     fn is_within(&self, t: &Trajectory) -> bool {
-        t.position.0 >= self.x_min
-            && t.position.0 <= self.x_max
-            && t.position.1 >= self.y_min
-            && t.position.1 <= self.y_max
+        self.area.contains(t.position)
     }
 
     fn is_too_far(&self, t: &Trajectory) -> bool {
-        t.position.0 > self.x_max || (t.position.1 < self.y_min && t.velocity.1 <= 0)
+        t.position.x > self.area.max.x || (t.position.y < self.area.min.y && t.velocity.y <= 0)
     }
 }
 
 struct Trajectory {
-    position: (isize, isize),
-    velocity: (isize, isize),
+    position: Point2,
+    velocity: Point2,
     max_y: isize,
 }
 
 impl Trajectory {
     fn new(initial_velocity: (isize, isize)) -> Trajectory {
         Trajectory {
-            position: (0, 0),
-            velocity: initial_velocity,
+            position: Point2::ORIGIN,
+            velocity: initial_velocity.into(),
             max_y: isize::MIN,
         }
     }
+}
 
+impl Simulation for Trajectory {
     fn step(&mut self) {
-        self.position.0 += self.velocity.0;
-        self.position.1 += self.velocity.1;
+        self.position += self.velocity;
 
-        if self.position.1 > self.max_y {
-            self.max_y = self.position.1;
+        if self.position.y > self.max_y {
+            self.max_y = self.position.y;
         }
 
-        self.velocity.1 -= 1; // gravity
+        self.velocity.y -= 1; // gravity
 
         // drag
-        if self.velocity.0 > 0 {
-            self.velocity.0 -= 1;
-        } else if self.velocity.0 < 0 {
-            self.velocity.0 += 1;
-        }
+        self.velocity.x -= self.velocity.x.signum();
     }
 }
 
-fn part_one(input: &str) -> isize {
+fn part_one(target: &TargetArea) -> Answer {
     let mut max_y = isize::MIN;
 
-    let target = TargetArea::new_from_str(input);
-    println!("{:?}", target);
+    log::info!("{:?}", target);
 
     // this arbitrary brute force tactic doesn't feel good, but works...
     for y_vel in -1000..1000 {
@@ -121,14 +129,13 @@ fn part_one(input: &str) -> isize {
         }
     }
 
-    max_y
+    max_y.into()
 }
 
-fn part_two(input: &str) -> isize {
-    let mut total_trajectories = 0;
+fn part_two(target: &TargetArea) -> Answer {
+    let mut total_trajectories: usize = 0;
 
-    let target = TargetArea::new_from_str(input);
-    println!("{:?}", target);
+    log::info!("{:?}", target);
 
     // this arbitrary brute force tactic doesn't feel good, but works...
     for y_vel in -1000..1000 {
@@ -152,33 +159,42 @@ fn part_two(input: &str) -> isize {
         }
     }
 
-    total_trajectories
+    total_trajectories.into()
 }
 
 #[test]
 fn example_part_one() {
-    let result = part_one(EXAMPLE_INPUT);
+    let result = part_one(&TargetArea::new_from_str(EXAMPLE_INPUT).unwrap());
     println!("example result: {}", result);
     assert_eq!(result, 45);
 }
 
 #[test]
 fn example_part_two() {
-    let result = part_two(EXAMPLE_INPUT);
+    let result = part_two(&TargetArea::new_from_str(EXAMPLE_INPUT).unwrap());
     println!("example result: {}", result);
     assert_eq!(result, 112);
 }
 
+#[test]
+fn malformed() {
+    let err = TargetArea::new_from_str("target area: x=20..3O, y=-10..-5").unwrap_err();
+    assert_eq!((Some(1), Some(20)), (err.line, err.column));
+    assert!(TargetArea::new_from_str("target area: x=20..30").is_err());
+}
+
 #[test]
 fn test_part_one() {
-    let result = part_one(OUR_INPUT);
+    let input = personal_input!(crate::YEAR, 17);
+    let result = part_one(&TargetArea::new_from_str(&input).unwrap());
     println!("part one: {}", result);
     assert_eq!(9870, result);
 }
 
 #[test]
 fn test_part_two() {
-    let result = part_two(OUR_INPUT);
+    let input = personal_input!(crate::YEAR, 17);
+    let result = part_two(&TargetArea::new_from_str(&input).unwrap());
     println!("part two: {}", result);
     assert_eq!(5523, result);
 }
@@ -1,22 +1,21 @@
 //! AOC Day xx
 // use aoc_2021;
+use aoc_common::Answer;
 
 const EXAMPLE_INPUT: &str = "";
-// const OUR_INPUT: Result<&str, std::str::Utf8Error> =
-//     std::str::from_utf8(include_bytes!("../assets/day_xx.txt"));
 
-fn part_one(_input: &str) -> isize {
+fn part_one(_input: &str) -> Answer {
     let mut result = 0;
     result += 2;
 
-    result
+    result.into()
 }
 
-fn part_two(_input: &str) -> isize {
+fn part_two(_input: &str) -> Answer {
     let mut result = 0;
     result += 2;
 
-    result
+    result.into()
 }
 
 #[test]
@@ -35,14 +34,16 @@ fn example_part_two() {
 
 #[test]
 fn test_part_one() {
-    // let result = part_one(OUR_INPUT.unwrap());
+    // let input = personal_input!(crate::YEAR, 20);
+    // let result = part_one(&input);
     // println!("part one: {}", result);
     // assert_eq!(42, result);
 }
 
 #[test]
 fn test_part_two() {
-    // let result = part_two(OUR_INPUT.unwrap());
+    // let input = personal_input!(crate::YEAR, 20);
+    // let result = part_two(&input);
     // println!("part two: {}", result);
     // assert_eq!(42, result);
 }
@@ -1,48 +1,133 @@
-//! AOC Day xx
-// use aoc_2021;
+//! --- Day 21: Dirac Dice ---
 
-const EXAMPLE_INPUT: &str = "";
-// const OUR_INPUT: Result<&str, std::str::Utf8Error> =
-//     std::str::from_utf8(include_bytes!("../assets/day_xx.txt"));
+use aoc_common::error::parse_at;
+use aoc_common::search::Memo;
+use aoc_common::{Answer, AocError, AocResult, Solution};
 
-fn part_one(_input: &str) -> isize {
-    let mut result = 0;
-    result += 2;
+pub struct Day21;
 
-    result
+impl Solution for Day21 {
+    type Input = [u8; 2];
+
+    fn parse(&self, input: &str) -> AocResult<Self::Input> {
+        parse(input)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> AocResult<Answer> {
+        Ok(part_one(input))
+    }
+
+    fn part_two(&self, input: &Self::Input) -> AocResult<Answer> {
+        Ok(part_two(input))
+    }
 }
 
-fn part_two(_input: &str) -> isize {
-    let mut result = 0;
-    result += 2;
+#[cfg(test)]
+const EXAMPLE_INPUT: &str = "Player 1 starting position: 4
+Player 2 starting position: 8";
 
-    result
+/// The score the practice game is played to
+const PRACTICE_TARGET: u16 = 1000;
+/// The score the game with the Dirac die is played to
+const DIRAC_TARGET: u16 = 21;
+
+/// How many of the 27 ways three rolls of the Dirac die can go add up to each total from 3 to 9
+const DIRAC_ROLLS: [(u16, u64); 7] = [(3, 1), (4, 3), (5, 6), (6, 7), (7, 6), (8, 3), (9, 1)];
+
+/// Moves `position` around the track of ten spaces by `roll`.
+fn advance(position: u16, roll: u16) -> u16 {
+    (position + roll - 1) % 10 + 1
+}
+
+fn parse(input: &str) -> AocResult<[u8; 2]> {
+    let mut positions = [0; 2];
+    let mut lines = input.lines().filter(|line| !line.is_empty());
+    for (ind, position) in positions.iter_mut().enumerate() {
+        let line = lines.next().ok_or_else(|| {
+            AocError::new(format!("missing player {}'s starting position", ind + 1))
+        })?;
+        let prefix = format!("Player {} starting position: ", ind + 1);
+        let start = line
+            .strip_prefix(prefix.as_str())
+            .ok_or_else(|| AocError::line(ind, line, format!("expected `{}<space>`", prefix)))?;
+        *position = parse_at(input, start, "a space from 1 to 10")?;
+        if !(1..=10).contains(position) {
+            return Err(AocError::at(input, start, "expected a space from 1 to 10"));
+        }
+    }
+
+    Ok(positions)
+}
+
+fn part_one(start: &[u8; 2]) -> Answer {
+    let mut positions = start.map(u16::from);
+    let mut scores = [0; 2];
+    let mut rolls: u64 = 0;
+    let mut player = 0;
+
+    while scores[0] < PRACTICE_TARGET && scores[1] < PRACTICE_TARGET {
+        // the deterministic die rolls 1 to 100 in order, over and over
+        let roll: u16 = (0..3).map(|ind| ((rolls + ind) % 100 + 1) as u16).sum();
+        rolls += 3;
+        positions[player] = advance(positions[player], roll);
+        scores[player] += positions[player];
+        player = 1 - player;
+    }
+
+    (rolls * u64::from(scores[0].min(scores[1]))).into()
+}
+
+/// The player about to roll, and the other one, as their position and score.
+type Turn = ((u16, u16), (u16, u16));
+
+/// In how many universes the player about to roll wins, and in how many the other player does.
+fn wins(memo: &mut Memo<Turn, (u64, u64)>, turn: Turn) -> (u64, u64) {
+    memo.get_or_insert_with(turn, |memo| {
+        let ((position, score), other) = turn;
+        let mut wins_here = (0, 0);
+        for (roll, universes) in DIRAC_ROLLS {
+            let position = advance(position, roll);
+            let score = score + position;
+            if score >= DIRAC_TARGET {
+                wins_here.0 += universes;
+            } else {
+                // the other player rolls next, so their wins are ours, and ours theirs
+                let (theirs, ours) = wins(memo, (other, (position, score)));
+                wins_here.0 += universes * ours;
+                wins_here.1 += universes * theirs;
+            }
+        }
+        wins_here
+    })
+}
+
+fn part_two(start: &[u8; 2]) -> Answer {
+    let [one, two] = start.map(u16::from);
+    let (one_wins, two_wins) = wins(&mut Memo::new(), ((one, 0), (two, 0)));
+    log::debug!("player 1 wins {} times, player 2 {}", one_wins, two_wins);
+
+    one_wins.max(two_wins).into()
 }
 
 #[test]
 fn example_part_one() {
-    let result = part_one(EXAMPLE_INPUT);
+    let result = part_one(&parse(EXAMPLE_INPUT).unwrap());
     println!("example result: {}", result);
-    assert_eq!(result, 2);
+    assert_eq!(result, 739785);
 }
 
 #[test]
 fn example_part_two() {
-    let result = part_two(EXAMPLE_INPUT);
+    let result = part_two(&parse(EXAMPLE_INPUT).unwrap());
     println!("example result: {}", result);
-    assert_eq!(result, 2);
-}
-
-#[test]
-fn test_part_one() {
-    // let result = part_one(OUR_INPUT.unwrap());
-    // println!("part one: {}", result);
-    // assert_eq!(42, result);
+    assert_eq!(result, 444356092776315_u64);
 }
 
 #[test]
-fn test_part_two() {
-    // let result = part_two(OUR_INPUT.unwrap());
-    // println!("part two: {}", result);
-    // assert_eq!(42, result);
+fn malformed() {
+    let err = parse("Player 1 starting position: 4\nPlayer 2 starting position: 11").unwrap_err();
+    assert_eq!((Some(2), Some(29)), (err.line, err.column));
+    let err = parse("Player 1 starting position: 4\nPlayer 3 starting position: 8").unwrap_err();
+    assert_eq!(Some(2), err.line);
+    assert!(parse("Player 1 starting position: 4").is_err());
 }
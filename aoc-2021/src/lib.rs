@@ -1,47 +1,29 @@
-#![feature(const_str_from_utf8)]
+use aoc_common::Registry;
 
-#[cfg(test)]
-mod day_01;
-#[cfg(test)]
-mod day_02;
-#[cfg(test)]
-mod day_03;
-#[cfg(test)]
-mod day_04;
-#[cfg(test)]
-mod day_05;
-#[cfg(test)]
-mod day_06;
-#[cfg(test)]
-mod day_07;
-#[cfg(test)]
-mod day_08;
-#[cfg(test)]
-mod day_09;
-#[cfg(test)]
-mod day_10;
-#[cfg(test)]
-mod day_11;
-#[cfg(test)]
-mod day_12;
-#[cfg(test)]
-mod day_13;
-#[cfg(test)]
-mod day_14;
-#[cfg(test)]
-mod day_15;
-#[cfg(test)]
-mod day_16;
-#[cfg(test)]
-mod day_17;
+pub mod day_01;
+pub mod day_02;
+pub mod day_03;
+pub mod day_04;
+pub mod day_05;
+pub mod day_06;
+pub mod day_07;
+pub mod day_08;
+pub mod day_09;
+pub mod day_10;
+pub mod day_11;
+pub mod day_12;
+pub mod day_13;
+pub mod day_14;
+pub mod day_15;
+pub mod day_16;
+pub mod day_17;
 #[cfg(test)]
 mod day_18;
 #[cfg(test)]
 mod day_19;
 #[cfg(test)]
 mod day_20;
-#[cfg(test)]
-mod day_21;
+pub mod day_21;
 #[cfg(test)]
 mod day_22;
 #[cfg(test)]
@@ -50,3 +32,40 @@ mod day_23;
 mod day_24;
 #[cfg(test)]
 mod day_25;
+
+/// The event year these solutions belong to.
+pub const YEAR: u16 = 2021;
+
+/// Installs the logger before any test runs, so `AOC_LOG` picks what the solvers print.
+#[cfg(test)]
+#[ctor::ctor]
+fn init_logging() {
+    aoc_common::logging::init_from_env();
+}
+
+/// Registers every 2021 day with the given registry.
+pub fn register(registry: &mut Registry) {
+    registry.register(YEAR, 1, day_01::Day01);
+    registry.register(YEAR, 2, day_02::Day02);
+    registry.register(YEAR, 3, day_03::Day03);
+    registry.register(YEAR, 4, day_04::Day04);
+    registry.register(YEAR, 5, day_05::Day05);
+    registry.register(YEAR, 6, day_06::Day06);
+    registry.register(YEAR, 7, day_07::Day07);
+    registry.register(YEAR, 8, day_08::Day08);
+    registry.register(YEAR, 9, day_09::Day09);
+    registry.register(YEAR, 10, day_10::Day10);
+    registry.register(YEAR, 11, day_11::Day11);
+    registry.register(YEAR, 12, day_12::Day12);
+    registry.register(YEAR, 13, day_13::Day13);
+    registry.register(YEAR, 14, day_14::Day14);
+    registry.register(YEAR, 15, day_15::Day15);
+    registry.register(YEAR, 16, day_16::Day16);
+    registry.register(YEAR, 17, day_17::Day17);
+    registry.register(YEAR, 21, day_21::Day21);
+
+    // still stubs
+    for day in (18..=20).chain(22..=25) {
+        registry.register_unimplemented(YEAR, day);
+    }
+}
@@ -0,0 +1,214 @@
+//! [`Answer`], what every part of every day returns, whatever shape the puzzle's answer takes.
+//!
+//! Most answers are integers, and 128 bits covers all of them so far; [`Answer::Big`] is there
+//! for the rest. A few answers are words, like the letters 2021 day 13 draws. Numbers compare by
+//! value however they're stored, and any answer compares against the text it was recorded as.
+
+use std::cmp::Ordering;
+use std::fmt;
+
+use num_bigint::{BigInt, BigUint};
+
+/// The answer to one part of a puzzle.
+#[derive(Debug, Clone)]
+pub enum Answer {
+    Signed(i128),
+    Unsigned(u128),
+    /// An integer too big for 128 bits.
+    Big(BigInt),
+    Text(String),
+}
+
+impl Answer {
+    /// The answer as an arbitrary precision integer, if it's a number.
+    pub fn to_bigint(&self) -> Option<BigInt> {
+        match self {
+            Answer::Signed(n) => Some(BigInt::from(*n)),
+            Answer::Unsigned(n) => Some(BigInt::from(*n)),
+            Answer::Big(n) => Some(n.clone()),
+            Answer::Text(_) => None,
+        }
+    }
+
+    /// Whether this is the answer recorded as `recorded`. Numbers match whatever parses to the
+    /// same value, text only the same text.
+    pub fn matches(&self, recorded: &str) -> bool {
+        let recorded = recorded.trim();
+        match self {
+            Answer::Text(text) => text == recorded,
+            _ => recorded.parse::<BigInt>().ok() == self.to_bigint(),
+        }
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Signed(n) => n.fmt(f),
+            Answer::Unsigned(n) => n.fmt(f),
+            Answer::Big(n) => n.fmt(f),
+            Answer::Text(text) => f.pad(text),
+        }
+    }
+}
+
+impl PartialEq for Answer {
+    fn eq(&self, other: &Answer) -> bool {
+        match (self, other) {
+            (Answer::Signed(a), Answer::Signed(b)) => a == b,
+            (Answer::Unsigned(a), Answer::Unsigned(b)) => a == b,
+            (Answer::Text(a), Answer::Text(b)) => a == b,
+            (Answer::Text(_), _) | (_, Answer::Text(_)) => false,
+            _ => self.to_bigint() == other.to_bigint(),
+        }
+    }
+}
+
+impl Eq for Answer {}
+
+impl PartialOrd for Answer {
+    /// Numbers order by value and text alphabetically, but a number and text don't order.
+    fn partial_cmp(&self, other: &Answer) -> Option<Ordering> {
+        match (self, other) {
+            (Answer::Signed(a), Answer::Signed(b)) => a.partial_cmp(b),
+            (Answer::Unsigned(a), Answer::Unsigned(b)) => a.partial_cmp(b),
+            (Answer::Text(a), Answer::Text(b)) => a.partial_cmp(b),
+            (Answer::Text(_), _) | (_, Answer::Text(_)) => None,
+            _ => self.to_bigint().partial_cmp(&other.to_bigint()),
+        }
+    }
+}
+
+impl PartialEq<str> for Answer {
+    fn eq(&self, other: &str) -> bool {
+        self.matches(other)
+    }
+}
+
+impl PartialEq<&str> for Answer {
+    fn eq(&self, other: &&str) -> bool {
+        self.matches(other)
+    }
+}
+
+impl PartialEq<String> for Answer {
+    fn eq(&self, other: &String) -> bool {
+        self.matches(other)
+    }
+}
+
+impl PartialEq<Answer> for &str {
+    fn eq(&self, other: &Answer) -> bool {
+        other.matches(self)
+    }
+}
+
+impl PartialEq<Answer> for String {
+    fn eq(&self, other: &Answer) -> bool {
+        other.matches(self)
+    }
+}
+
+/// `From`, and two-way equality and ordering, for each primitive integer, stored as the 128-bit
+/// integer of the same signedness.
+macro_rules! integers {
+    ($variant:ident: $wide:ty => $($int:ty),*) => {$(
+        impl From<$int> for Answer {
+            fn from(n: $int) -> Answer {
+                Answer::$variant(n as $wide)
+            }
+        }
+
+        impl PartialEq<$int> for Answer {
+            fn eq(&self, other: &$int) -> bool {
+                self.partial_cmp(other) == Some(Ordering::Equal)
+            }
+        }
+
+        impl PartialEq<Answer> for $int {
+            fn eq(&self, other: &Answer) -> bool {
+                self.partial_cmp(other) == Some(Ordering::Equal)
+            }
+        }
+
+        impl PartialOrd<$int> for Answer {
+            fn partial_cmp(&self, other: &$int) -> Option<Ordering> {
+                self.partial_cmp(&Answer::from(*other))
+            }
+        }
+
+        impl PartialOrd<Answer> for $int {
+            fn partial_cmp(&self, other: &Answer) -> Option<Ordering> {
+                Answer::from(*self).partial_cmp(other)
+            }
+        }
+    )*};
+}
+
+integers!(Signed: i128 => i8, i16, i32, i64, i128, isize);
+integers!(Unsigned: u128 => u8, u16, u32, u64, u128, usize);
+
+impl From<BigInt> for Answer {
+    fn from(n: BigInt) -> Answer {
+        Answer::Big(n)
+    }
+}
+
+impl From<BigUint> for Answer {
+    fn from(n: BigUint) -> Answer {
+        Answer::Big(n.into())
+    }
+}
+
+impl From<String> for Answer {
+    fn from(text: String) -> Answer {
+        Answer::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Answer {
+        Answer::Text(text.to_string())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn displays() {
+        assert_eq!("-42", Answer::from(-42).to_string());
+        assert_eq!(u128::MAX.to_string(), Answer::from(u128::MAX).to_string());
+        let big = BigInt::from(u128::MAX) * 1000;
+        assert_eq!(format!("{}000", u128::MAX), Answer::from(big).to_string());
+        assert_eq!("LRFJBJEH", Answer::from("LRFJBJEH").to_string());
+    }
+
+    #[test]
+    fn numbers_compare_by_value() {
+        assert_eq!(Answer::from(17_usize), Answer::from(17_i32));
+        assert_eq!(Answer::from(BigInt::from(17)), Answer::from(17_u8));
+        assert_ne!(Answer::from(-1_i64), Answer::from(u128::MAX));
+        assert_ne!(Answer::from(17), Answer::from("17"));
+        assert_eq!(Answer::from(17_isize), 17);
+        assert_eq!(17_u64, Answer::from(17_i8));
+
+        assert!(Answer::from(-1) < 0_usize);
+        assert!(Answer::from(BigInt::from(u128::MAX) + 1) > u128::MAX);
+        assert!(Answer::Signed(-1) < Answer::Unsigned(0));
+        assert!(Answer::from(163_usize) > 162);
+        assert_eq!(None, Answer::from(1).partial_cmp(&Answer::from("1")));
+    }
+
+    #[test]
+    fn matches_recorded_answers() {
+        assert!(Answer::from(1665_usize).matches("1665"));
+        assert!(Answer::from(-3_i32).matches("-3\n"));
+        assert!(!Answer::from(1665).matches("1666"));
+        assert!(!Answer::from(1665).matches("lots"));
+        assert_eq!(Answer::from("LRFJBJEH"), "LRFJBJEH");
+        assert_ne!(Answer::from("LRFJBJEH"), "lrfjbjeh");
+        assert_eq!("95".to_string(), Answer::from(95_u32));
+    }
+}
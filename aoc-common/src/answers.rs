@@ -0,0 +1,494 @@
+//! Known-good answers, recorded per `(year, day, part, input)`, and the harness that checks
+//! every registered solution against them.
+//!
+//! The answers file lives next to the inputs (`<input dir>/answers.txt`) and holds one answer
+//! per line:
+//!
+//! ```text
+//! # year day part input answer
+//! 2021 1 1 8f1d5a7c36e2b904 1665
+//! ```
+//!
+//! `input` is [`input_key`] of the puzzle input, so answers for someone else's input never get
+//! compared against yours.
+
+use std::any::Any;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::answer::Answer;
+use crate::error::{AocError, AocResult};
+use crate::input;
+use crate::solution::{Part, Registry};
+
+/// File name of the answers file inside the input directory.
+pub const ANSWERS_FILE: &str = "answers.txt";
+
+/// Where the answers file is expected to be.
+pub fn answers_path() -> PathBuf {
+    input::input_dir().join(ANSWERS_FILE)
+}
+
+/// A short, stable fingerprint of a puzzle input. Trailing whitespace is ignored so a missing
+/// final newline doesn't count as a different input.
+pub fn input_key(input: &str) -> String {
+    // FNV-1a, since std's hashers aren't guaranteed to be stable between releases
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for byte in input.trim_end().bytes() {
+        hash ^= u64::from(byte);
+        hash = hash.wrapping_mul(0x0100_0000_01b3);
+    }
+    format!("{:016x}", hash)
+}
+
+/// Identifies one recorded answer.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct AnswerKey {
+    pub year: u16,
+    pub day: u8,
+    pub part: Part,
+    /// [`input_key`] of the input the answer belongs to.
+    pub input: String,
+}
+
+/// The contents of an answers file.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Answers {
+    answers: BTreeMap<AnswerKey, String>,
+}
+
+impl Answers {
+    pub fn new() -> Answers {
+        Answers::default()
+    }
+
+    /// Reads an answers file. A file that doesn't exist yet is simply empty.
+    pub fn load(path: &Path) -> io::Result<Answers> {
+        match std::fs::read_to_string(path) {
+            Ok(contents) => Answers::from_text(&contents).map_err(|e| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("{}: {}", path.display(), e),
+                )
+            }),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Answers::new()),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Parses the answers file format. The error describes the first malformed line.
+    pub fn from_text(contents: &str) -> Result<Answers, String> {
+        let mut answers = Answers::new();
+        for (ind, line) in contents.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let invalid = || format!("line {}: expected `year day part input answer`", ind + 1);
+            let mut fields = line.splitn(5, ' ');
+            let mut next = || fields.next().filter(|f| !f.is_empty()).ok_or_else(invalid);
+            let year = next()?.parse().map_err(|_| invalid())?;
+            let day = next()?.parse().map_err(|_| invalid())?;
+            let part = match next()? {
+                "1" => Part::One,
+                "2" => Part::Two,
+                _ => return Err(invalid()),
+            };
+            let input = next()?.to_string();
+            let answer = next()?.trim().to_string();
+
+            answers.insert(
+                AnswerKey {
+                    year,
+                    day,
+                    part,
+                    input,
+                },
+                answer,
+            );
+        }
+
+        Ok(answers)
+    }
+
+    /// Writes every answer back out, sorted by year, day and part.
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        std::fs::write(path, self.to_string())
+    }
+
+    pub fn get(&self, key: &AnswerKey) -> Option<&str> {
+        self.answers.get(key).map(String::as_str)
+    }
+
+    pub fn insert(&mut self, key: AnswerKey, answer: String) {
+        assert!(!answer.contains('\n'), "answers must fit on one line");
+        self.answers.insert(key, answer);
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&AnswerKey, &str)> {
+        self.answers
+            .iter()
+            .map(|(key, answer)| (key, answer.as_str()))
+    }
+}
+
+impl fmt::Display for Answers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "# year day part input answer")?;
+        for (key, answer) in self.iter() {
+            writeln!(
+                f,
+                "{} {} {} {} {}",
+                key.year, key.day, key.part, key.input, answer
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// How a computed answer compares to the recorded one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// Matches the recorded answer.
+    Ok,
+    /// Nothing recorded for this input yet.
+    New,
+    /// Differs from the recorded answer.
+    Changed,
+    /// Recorded, but nothing was run for it: the day is gone, or its input is absent or changed.
+    Missing,
+    /// The solution returned an error.
+    Failed,
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let status = match self {
+            Status::Ok => "ok",
+            Status::New => "new",
+            Status::Changed => "CHANGED",
+            Status::Missing => "missing",
+            Status::Failed => "FAILED",
+        };
+        // pad so `{:8}` lines up in tables
+        f.pad(status)
+    }
+}
+
+/// One row of a [`Report`].
+#[derive(Debug, Clone)]
+pub struct Check {
+    pub key: AnswerKey,
+    pub expected: Option<String>,
+    /// What the solution produced, if it was run.
+    pub actual: Option<AocResult<Answer>>,
+}
+
+impl Check {
+    pub fn status(&self) -> Status {
+        match (&self.expected, &self.actual) {
+            (_, Some(Err(_))) => Status::Failed,
+            (Some(expected), Some(Ok(actual))) if actual.matches(expected) => Status::Ok,
+            (Some(_), Some(Ok(_))) => Status::Changed,
+            (None, Some(Ok(_))) => Status::New,
+            (_, None) => Status::Missing,
+        }
+    }
+
+    /// The actual answer as shown in a table: the answer, or the first line of the error.
+    fn actual_summary(&self) -> String {
+        match &self.actual {
+            Some(Ok(actual)) => actual.to_string(),
+            Some(Err(e)) => {
+                let e = e.to_string();
+                format!("error: {}", e.lines().next().unwrap_or_default())
+            }
+            None => "-".to_string(),
+        }
+    }
+}
+
+/// The outcome of [`verify`], in `(year, day, part)` order.
+#[derive(Debug, Clone, Default)]
+pub struct Report {
+    pub checks: Vec<Check>,
+}
+
+impl Report {
+    pub fn count(&self, status: Status) -> usize {
+        self.checks.iter().filter(|c| c.status() == status).count()
+    }
+
+    /// Whether any answer disagrees with the recorded one, or couldn't be computed at all.
+    pub fn has_failures(&self) -> bool {
+        self.count(Status::Changed) + self.count(Status::Failed) > 0
+    }
+
+    /// Copies every new answer into `answers`. Changed answers are left for a human to sort out.
+    pub fn record_new(&self, answers: &mut Answers) -> usize {
+        let mut recorded = 0;
+        for check in &self.checks {
+            if let (Status::New, Some(Ok(actual))) = (check.status(), &check.actual) {
+                answers.insert(check.key.clone(), actual.to_string());
+                recorded += 1;
+            }
+        }
+        recorded
+    }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let none = String::from("-");
+        let expected_width = self
+            .checks
+            .iter()
+            .map(|c| c.expected.as_ref().unwrap_or(&none).len())
+            .max()
+            .unwrap_or(0)
+            .max("expected".len());
+
+        writeln!(
+            f,
+            "year day part status   {:<w$} actual",
+            "expected",
+            w = expected_width
+        )?;
+        for check in &self.checks {
+            writeln!(
+                f,
+                "{} {:>3} {:>4} {:8} {:<w$} {}",
+                check.key.year,
+                check.key.day,
+                check.key.part,
+                check.status(),
+                check.expected.as_ref().unwrap_or(&none),
+                check.actual_summary(),
+                w = expected_width
+            )?;
+        }
+        write!(
+            f,
+            "{} ok, {} new, {} changed, {} missing, {} failed",
+            self.count(Status::Ok),
+            self.count(Status::New),
+            self.count(Status::Changed),
+            self.count(Status::Missing),
+            self.count(Status::Failed)
+        )
+    }
+}
+
+/// Runs every implemented day (of `year`, if given) that has an input against the recorded
+/// answers. Days run in parallel, since a few of them take minutes.
+pub fn verify(registry: &Registry, answers: &Answers, year: Option<u16>) -> Report {
+    verify_inputs(registry, answers, year, |y, d| input::load(y, d).ok())
+}
+
+/// [`verify`], with the inputs coming from `load` rather than the input directory.
+fn verify_inputs(
+    registry: &Registry,
+    answers: &Answers,
+    year: Option<u16>,
+    load: impl Fn(u16, u8) -> Option<String>,
+) -> Report {
+    let in_year = |y: u16| year.is_none_or(|year| year == y);
+
+    type Run = (u16, u8, String, [AocResult<Answer>; 2]);
+    let runs: Vec<Run> = std::thread::scope(|scope| {
+        let handles: Vec<_> = registry
+            .iter()
+            .filter(|((y, _), _)| in_year(*y))
+            .filter_map(|((y, d), entry)| {
+                let solution = entry.solution()?;
+                let input = load(y, d)?;
+                let key = input_key(&input);
+                let handle = scope.spawn(move || match solution.parse(&input) {
+                    Ok(parsed) => Part::BOTH.map(|part| solution.solve(parsed.as_ref(), part)),
+                    Err(e) => [Err(e.clone()), Err(e)],
+                });
+                Some((y, d, key, handle))
+            })
+            .collect();
+
+        // a day that panics fails both its parts, without taking the other days' results with it
+        handles
+            .into_iter()
+            .map(|(y, d, key, handle)| {
+                let results = handle.join().unwrap_or_else(|payload| {
+                    let e = panicked(payload);
+                    [Err(e.clone()), Err(e)]
+                });
+                (y, d, key, results)
+            })
+            .collect()
+    });
+
+    let mut checks = Vec::new();
+    let mut seen = BTreeSet::new();
+    for (year, day, input, results) in runs {
+        for (part, actual) in Part::BOTH.into_iter().zip(results) {
+            let key = AnswerKey {
+                year,
+                day,
+                part,
+                input: input.clone(),
+            };
+            seen.insert(key.clone());
+            checks.push(Check {
+                expected: answers.get(&key).map(str::to_string),
+                actual: Some(actual),
+                key,
+            });
+        }
+    }
+
+    for (key, answer) in answers.iter() {
+        if in_year(key.year) && !seen.contains(key) {
+            checks.push(Check {
+                key: key.clone(),
+                expected: Some(answer.to_string()),
+                actual: None,
+            });
+        }
+    }
+
+    checks.sort_by(|a, b| a.key.cmp(&b.key));
+    Report { checks }
+}
+
+/// The error standing in for the answers of a solution that panicked with `payload`.
+fn panicked(payload: Box<dyn Any + Send>) -> AocError {
+    let message = payload
+        .downcast_ref::<&str>()
+        .map(|message| message.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "no message".to_string());
+    AocError::new(format!("panicked: {}", message))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::solution::Solution;
+
+    fn key(day: u8, part: Part, input: &str) -> AnswerKey {
+        AnswerKey {
+            year: 2021,
+            day,
+            part,
+            input: input.to_string(),
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        let mut answers = Answers::new();
+        answers.insert(key(2, Part::Two, "abc"), "1176514794".to_string());
+        answers.insert(key(1, Part::One, "def"), "the answer".to_string());
+
+        let text = answers.to_string();
+        assert_eq!(
+            "# year day part input answer\n2021 1 1 def the answer\n2021 2 2 abc 1176514794\n",
+            text
+        );
+        assert_eq!(answers, Answers::from_text(&text).unwrap());
+    }
+
+    #[test]
+    fn malformed_line() {
+        let err = Answers::from_text("# header\n\n2021 1 3 abc 42\n").unwrap_err();
+        assert!(err.starts_with("line 3"), "{}", err);
+        assert!(Answers::from_text("2021 1 1 abc").is_err());
+    }
+
+    #[test]
+    fn input_key_ignores_trailing_whitespace() {
+        assert_eq!(input_key("1\n2\n3"), input_key("1\n2\n3\n"));
+        assert_ne!(input_key("1\n2\n3"), input_key("1\n2\n4"));
+    }
+
+    #[test]
+    fn statuses() {
+        let check = |expected: Option<&str>, actual: Option<&str>| Check {
+            key: key(1, Part::One, "abc"),
+            expected: expected.map(str::to_string),
+            actual: actual.map(|actual| Ok(Answer::from(actual))),
+        };
+        assert_eq!(Status::Ok, check(Some("1"), Some("1")).status());
+        assert_eq!(Status::Changed, check(Some("1"), Some("2")).status());
+        assert_eq!(Status::New, check(None, Some("2")).status());
+        assert_eq!(Status::Missing, check(Some("1"), None).status());
+
+        let failed = Check {
+            actual: Some(Err(crate::AocError::line(2, "x", "bad line"))),
+            ..check(Some("1"), None)
+        };
+        assert_eq!(Status::Failed, failed.status());
+        assert_eq!("error: line 3: bad line", failed.actual_summary());
+
+        let report = Report {
+            checks: vec![check(Some("1"), Some("2")), check(None, Some("2"))],
+        };
+        assert!(report.has_failures());
+
+        let mut answers = Answers::new();
+        assert_eq!(1, report.record_new(&mut answers));
+        assert_eq!(Some("2"), answers.get(&key(1, Part::One, "abc")));
+    }
+
+    /// Counts the lines of its input, or panics on part two if told to.
+    struct Lines {
+        panics: bool,
+    }
+
+    impl Solution for Lines {
+        type Input = usize;
+
+        fn parse(&self, input: &str) -> AocResult<usize> {
+            Ok(input.lines().count())
+        }
+
+        fn part_one(&self, input: &usize) -> AocResult<Answer> {
+            Ok((*input).into())
+        }
+
+        fn part_two(&self, input: &usize) -> AocResult<Answer> {
+            if self.panics {
+                panic!(
+                    "index out of bounds: the len is {} but the index is 9",
+                    input
+                );
+            }
+            Ok((*input * 2).into())
+        }
+    }
+
+    #[test]
+    fn survives_panics() {
+        let mut registry = Registry::new();
+        registry.register(2021, 1, Lines { panics: true });
+        registry.register(2021, 2, Lines { panics: false });
+        let input = "a\nb\n";
+        let report = verify_inputs(&registry, &Answers::new(), None, |_, _| {
+            Some(input.to_string())
+        });
+
+        let statuses: Vec<_> = report.checks.iter().map(Check::status).collect();
+        assert_eq!(
+            vec![Status::Failed, Status::Failed, Status::New, Status::New],
+            statuses
+        );
+        assert_eq!(
+            "error: panicked: index out of bounds: the len is 2 but the index is 9",
+            report.checks[1].actual_summary()
+        );
+        assert_eq!(
+            Answer::from(4),
+            report.checks[3].actual.clone().unwrap().unwrap()
+        );
+    }
+}
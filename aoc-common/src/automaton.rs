@@ -0,0 +1,366 @@
+//! Cellular automata: boards where every cell's next state follows from its own and its
+//! neighbors'.
+//!
+//! [`Automaton`] runs a dense [`Grid`] of any cell type, its neighborhoods worked out once up
+//! front, either a generation at a time with every cell updated together, or as a
+//! [`Cascade`] where firing cells set off their neighbors. [`Life`] runs an unbounded
+//! `N`-dimensional set of live cells, for Game of Life variants.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::grid::Grid;
+use crate::neighbors::{Adjacency, Edge};
+
+/// A dense board of cells stepping through generations.
+#[derive(Debug, Clone)]
+pub struct Automaton<T> {
+    cells: Grid<T>,
+    /// the row-major indices of each cell's neighbors
+    neighborhoods: Vec<Vec<usize>>,
+    generation: usize,
+}
+
+/// The neighbors of a cell, as handed to a rule.
+#[derive(Debug, Clone)]
+pub struct Neighbors<'a, T> {
+    cells: &'a Grid<T>,
+    indices: std::slice::Iter<'a, usize>,
+}
+
+impl<'a, T> Iterator for Neighbors<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        let index = *self.indices.next()?;
+        let width = self.cells.width();
+        Some(&self.cells[(index % width, index / width)])
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.indices.size_hint()
+    }
+}
+
+/// The rules of a generation in which cells that fire set off their neighbors, which may fire
+/// in turn, like a chain reaction. Each cell fires at most once a generation.
+pub trait Cascade<T> {
+    /// What every cell goes through at the start of the generation.
+    fn charge(&self, cell: &mut T);
+
+    /// Whether `cell` is ready to fire.
+    fn fires(&self, cell: &T) -> bool;
+
+    /// What a firing cell does to each of its neighbors.
+    fn spread(&self, neighbor: &mut T);
+
+    /// What becomes of a cell that fired, once the chain reaction is over.
+    fn settle(&self, cell: &mut T);
+}
+
+impl<T> Automaton<T> {
+    /// Runs `cells`, each neighbored by the cells around it that are on the board.
+    pub fn new(cells: Grid<T>, adjacency: Adjacency) -> Automaton<T> {
+        Automaton::with_neighborhood(cells, |cells, position| {
+            cells
+                .neighbors(position, adjacency, &Edge::Clip)
+                .filter_map(|(position, _)| position)
+                .collect()
+        })
+    }
+
+    /// Runs `cells`, each neighbored by the positions `neighborhood` picks for it. This is only
+    /// asked once per cell, up front, so it can base its choice on cells that never change.
+    pub fn with_neighborhood(
+        cells: Grid<T>,
+        mut neighborhood: impl FnMut(&Grid<T>, (usize, usize)) -> Vec<(usize, usize)>,
+    ) -> Automaton<T> {
+        let width = cells.width();
+        let neighborhoods = cells
+            .positions()
+            .map(|position| {
+                neighborhood(&cells, position)
+                    .into_iter()
+                    .map(|(x, y)| y * width + x)
+                    .collect()
+            })
+            .collect();
+        Automaton {
+            cells,
+            neighborhoods,
+            generation: 0,
+        }
+    }
+
+    pub fn cells(&self) -> &Grid<T> {
+        &self.cells
+    }
+
+    /// How many generations have been run.
+    pub fn generation(&self) -> usize {
+        self.generation
+    }
+
+    fn neighbors(&self, index: usize) -> Neighbors<'_, T> {
+        Neighbors {
+            cells: &self.cells,
+            indices: self.neighborhoods[index].iter(),
+        }
+    }
+
+    /// Runs a generation of `cascade`, returning how many cells fired.
+    pub fn cascade(&mut self, cascade: &impl Cascade<T>) -> usize {
+        self.generation += 1;
+        let width = self.cells.width();
+        let position = |index: usize| (index % width, index / width);
+
+        for cell in self.cells.iter_mut() {
+            cascade.charge(cell);
+        }
+
+        let mut fired = vec![false; self.cells.len()];
+        let mut ready: Vec<usize> = (0..self.cells.len())
+            .filter(|&index| cascade.fires(&self.cells[position(index)]))
+            .collect();
+        while let Some(index) = ready.pop() {
+            if fired[index] {
+                continue;
+            }
+            fired[index] = true;
+            for &neighbor in &self.neighborhoods[index] {
+                let cell = &mut self.cells[position(neighbor)];
+                cascade.spread(cell);
+                if !fired[neighbor] && cascade.fires(cell) {
+                    ready.push(neighbor);
+                }
+            }
+        }
+
+        for (index, _) in fired.iter().enumerate().filter(|(_, fired)| **fired) {
+            cascade.settle(&mut self.cells[position(index)]);
+        }
+        fired.iter().filter(|fired| **fired).count()
+    }
+}
+
+impl<T: Clone + PartialEq> Automaton<T> {
+    /// Runs a generation in which every cell changes at once, to what `rule` makes of it and its
+    /// neighbors. Returns whether any cell changed.
+    pub fn step(&mut self, mut rule: impl FnMut(&T, Neighbors<'_, T>) -> T) -> bool {
+        self.generation += 1;
+        let next: Vec<T> = self
+            .cells
+            .iter()
+            .enumerate()
+            .map(|(index, cell)| rule(cell, self.neighbors(index)))
+            .collect();
+        let changed = self.cells.iter().zip(&next).any(|(old, new)| old != new);
+        let width = self.cells.width();
+        self.cells = Grid::from_fn(width, self.cells.height(), |(x, y)| {
+            next[y * width + x].clone()
+        });
+        changed
+    }
+
+    /// Runs `generations` generations of `rule`.
+    pub fn run(&mut self, generations: usize, mut rule: impl FnMut(&T, Neighbors<'_, T>) -> T) {
+        for _ in 0..generations {
+            self.step(&mut rule);
+        }
+    }
+
+    /// Runs `rule` until a generation changes nothing, returning how many generations it took,
+    /// that last one included.
+    pub fn run_until_stable(&mut self, mut rule: impl FnMut(&T, Neighbors<'_, T>) -> T) -> usize {
+        let start = self.generation;
+        while self.step(&mut rule) {}
+        self.generation - start
+    }
+}
+
+/// An unbounded `N`-dimensional space of cells that are either alive or not, of which only the
+/// living are stored. Each cell is neighbored by the cells around it, diagonals included.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Life<const N: usize> {
+    alive: HashSet<[isize; N]>,
+    generation: usize,
+}
+
+impl<const N: usize> Life<N> {
+    pub fn new(alive: impl IntoIterator<Item = [isize; N]>) -> Life<N> {
+        Life {
+            alive: alive.into_iter().collect(),
+            generation: 0,
+        }
+    }
+
+    /// Seeds the space with the cells of a flat `grid` that `is_alive` picks, at the origin of
+    /// every dimension past the first two.
+    pub fn from_grid<T>(grid: &Grid<T>, mut is_alive: impl FnMut(&T) -> bool) -> Life<N> {
+        assert!(N >= 2, "a grid needs at least two dimensions");
+        Life::new(
+            grid.indexed()
+                .filter(|(_, cell)| is_alive(cell))
+                .map(|((x, y), _)| {
+                    let mut point = [0; N];
+                    point[0] = x as isize;
+                    point[1] = y as isize;
+                    point
+                }),
+        )
+    }
+
+    /// The number of living cells.
+    pub fn len(&self) -> usize {
+        self.alive.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.alive.is_empty()
+    }
+
+    pub fn is_alive(&self, point: &[isize; N]) -> bool {
+        self.alive.contains(point)
+    }
+
+    /// How many generations have been run.
+    pub fn generation(&self) -> usize {
+        self.generation
+    }
+
+    /// Runs a generation in which each cell lives on, or comes to life, if `rule` says so given
+    /// whether it's alive now and how many of its neighbors are.
+    pub fn step(&mut self, mut rule: impl FnMut(bool, usize) -> bool) {
+        self.generation += 1;
+        let offsets = Adjacency::Surrounding.offsets_nd::<N>();
+        // only the living and their neighbors can be alive next time around
+        let mut living_neighbors: HashMap<[isize; N], usize> = HashMap::new();
+        for cell in &self.alive {
+            for offset in &offsets {
+                let mut neighbor = *cell;
+                for (coord, delta) in neighbor.iter_mut().zip(offset) {
+                    *coord += delta;
+                }
+                *living_neighbors.entry(neighbor).or_insert(0) += 1;
+            }
+        }
+        let alive = &self.alive;
+        let mut next: HashSet<[isize; N]> = living_neighbors
+            .iter()
+            .filter(|(cell, count)| rule(alive.contains(*cell), **count))
+            .map(|(cell, _)| *cell)
+            .collect();
+        // a living cell with no living neighbors wasn't counted above
+        next.extend(
+            alive
+                .iter()
+                .filter(|cell| !living_neighbors.contains_key(*cell) && rule(true, 0)),
+        );
+        self.alive = next;
+    }
+
+    /// Runs `generations` generations of `rule`.
+    pub fn run(&mut self, generations: usize, mut rule: impl FnMut(bool, usize) -> bool) {
+        for _ in 0..generations {
+            self.step(&mut rule);
+        }
+    }
+}
+
+/// Conway's rule for the Game of Life: a living cell survives with two or three living
+/// neighbors, and a dead one comes to life with exactly three.
+pub fn conway(alive: bool, neighbors: usize) -> bool {
+    matches!((alive, neighbors), (true, 2) | (_, 3))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn board(input: &str) -> Grid<bool> {
+        Grid::parse(input, "`#` or `.`", |c| match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        })
+        .unwrap()
+    }
+
+    fn draw(grid: &Grid<bool>) -> String {
+        grid.map(|alive| if *alive { '#' } else { '.' }).to_string()
+    }
+
+    fn conway_rule(alive: &bool, neighbors: Neighbors<'_, bool>) -> bool {
+        conway(*alive, neighbors.filter(|alive| **alive).count())
+    }
+
+    #[test]
+    fn synchronous() {
+        let mut blinker = Automaton::new(
+            board(".....\n..#..\n..#..\n..#..\n.....\n"),
+            Adjacency::Surrounding,
+        );
+        assert!(blinker.step(conway_rule));
+        assert_eq!(".....\n.....\n.###.\n.....\n.....\n", draw(blinker.cells()));
+        blinker.run(3, conway_rule);
+        assert_eq!(4, blinker.generation());
+        assert_eq!(".....\n..#..\n..#..\n..#..\n.....\n", draw(blinker.cells()));
+
+        // a glider hits the corner and settles into a block
+        let mut glider = Automaton::new(
+            board(".#...\n..#..\n###..\n.....\n.....\n"),
+            Adjacency::Surrounding,
+        );
+        let generations = glider.run_until_stable(conway_rule);
+        assert_eq!(".....\n.....\n.....\n...##\n...##\n", draw(glider.cells()));
+        assert_eq!(glider.generation(), generations);
+    }
+
+    /// Cells charge by one each generation and fire past 3, charging their neighbors too.
+    struct Sparks;
+
+    impl Cascade<u8> for Sparks {
+        fn charge(&self, cell: &mut u8) {
+            *cell += 1;
+        }
+
+        fn fires(&self, cell: &u8) -> bool {
+            *cell > 3
+        }
+
+        fn spread(&self, neighbor: &mut u8) {
+            *neighbor += 1;
+        }
+
+        fn settle(&self, cell: &mut u8) {
+            *cell = 0;
+        }
+    }
+
+    #[test]
+    fn cascading() {
+        let cells = Grid::from_rows(vec![vec![3, 3, 2, 0, 0]]).unwrap();
+        let mut chain = Automaton::new(cells, Adjacency::Orthogonal);
+        assert_eq!(3, chain.cascade(&Sparks));
+        assert_eq!(
+            vec![0, 0, 0, 2, 1],
+            chain.cells().iter().copied().collect::<Vec<_>>()
+        );
+        assert_eq!(0, chain.cascade(&Sparks));
+    }
+
+    #[test]
+    fn life_in_more_dimensions() {
+        let seed = board(".#.\n..#\n###\n");
+        let mut cubes = Life::<3>::from_grid(&seed, |alive| *alive);
+        assert_eq!(5, cubes.len());
+        cubes.step(conway);
+        assert_eq!(11, cubes.len());
+        assert!(cubes.is_alive(&[0, 1, -1]));
+        cubes.run(5, conway);
+        assert_eq!(112, cubes.len());
+
+        let mut hypercubes = Life::<4>::from_grid(&seed, |alive| *alive);
+        hypercubes.run(6, conway);
+        assert_eq!(848, hypercubes.len());
+    }
+}
@@ -0,0 +1,194 @@
+//! [`AocError`], the error every parser and solution reports problems with its input through.
+
+use std::fmt;
+use std::str::FromStr;
+
+/// What was wrong with a puzzle input, pinned to the offending line and column when known.
+///
+/// ```text
+/// line 3, column 6: expected a number, found `x8`
+///     3 | acc +x8
+///       |      ^
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AocError {
+    /// 1-based line of the input.
+    pub line: Option<usize>,
+    /// 1-based column within `line`, in characters.
+    pub column: Option<usize>,
+    /// The text of the offending line.
+    pub text: Option<String>,
+    pub reason: String,
+}
+
+pub type AocResult<T> = Result<T, AocError>;
+
+impl AocError {
+    /// An error that isn't about any particular spot in the input.
+    pub fn new(reason: impl Into<String>) -> AocError {
+        AocError {
+            line: None,
+            column: None,
+            text: None,
+            reason: reason.into(),
+        }
+    }
+
+    /// An error about the whole of the line at (0-based) `index`.
+    pub fn line(index: usize, text: &str, reason: impl Into<String>) -> AocError {
+        AocError {
+            line: Some(index + 1),
+            column: None,
+            text: Some(text.to_string()),
+            reason: reason.into(),
+        }
+    }
+
+    /// An error about `span`, which must be a slice of `input`. The line and column are worked
+    /// out from where `span` sits; a `span` from elsewhere just gets no location.
+    pub fn at(input: &str, span: &str, reason: impl Into<String>) -> AocError {
+        let offset = (span.as_ptr() as usize)
+            .checked_sub(input.as_ptr() as usize)
+            .filter(|offset| offset + span.len() <= input.len());
+        let Some(offset) = offset else {
+            return AocError::new(reason);
+        };
+
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |ind| ind + 1);
+        let line_end = input[offset..]
+            .find('\n')
+            .map_or(input.len(), |ind| offset + ind);
+
+        AocError {
+            line: Some(before.matches('\n').count() + 1),
+            column: Some(input[line_start..offset].chars().count() + 1),
+            text: Some(
+                input[line_start..line_end]
+                    .trim_end_matches('\r')
+                    .to_string(),
+            ),
+            reason: reason.into(),
+        }
+    }
+
+    /// Renumbers an error raised while parsing a single line to that line's (0-based) `index`
+    /// in the whole input.
+    pub fn in_line(mut self, index: usize) -> AocError {
+        self.line = Some(index + 1);
+        self
+    }
+
+    /// Moves an error raised while parsing `span` on its own to where `span`, a slice of
+    /// `input`, sits in `input`.
+    pub fn within(mut self, input: &str, span: &str) -> AocError {
+        let start = AocError::at(input, span, "");
+        let (Some(line), Some(start_line), Some(start_column)) =
+            (self.line, start.line, start.column)
+        else {
+            return self;
+        };
+        if line == 1 {
+            self.column = self.column.map(|column| column + start_column - 1);
+        }
+        self.line = Some(start_line + line - 1);
+        self.text = input.lines().nth(start_line + line - 2).map(str::to_string);
+        self
+    }
+}
+
+/// Parses `span`, a slice of `input`, reporting a failure at its position as "expected `what`".
+pub fn parse_at<T: FromStr>(input: &str, span: &str, what: &str) -> AocResult<T> {
+    span.parse().map_err(|_| {
+        if span.is_empty() {
+            AocError::at(input, span, format!("expected {}, found nothing", what))
+        } else {
+            AocError::at(input, span, format!("expected {}, found `{}`", what, span))
+        }
+    })
+}
+
+impl fmt::Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.line, self.column) {
+            (Some(line), Some(column)) => write!(f, "line {}, column {}: ", line, column)?,
+            (Some(line), None) => write!(f, "line {}: ", line)?,
+            _ => (),
+        }
+        write!(f, "{}", self.reason)?;
+
+        if let (Some(line), Some(text)) = (self.line, &self.text) {
+            let gutter = line.to_string().len() + 4;
+            write!(f, "\n{:>w$} | {}", line, text, w = gutter)?;
+            if let Some(column) = self.column {
+                write!(f, "\n{:>w$} | {:>c$}", "", "^", w = gutter, c = column)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for AocError {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn locates_spans() {
+        let input = "nop +0\nacc +x8\njmp -2\n";
+        let span = &input[12..14];
+        assert_eq!("x8", span);
+
+        let err = AocError::at(input, span, "bad");
+        assert_eq!(Some(2), err.line);
+        assert_eq!(Some(6), err.column);
+        assert_eq!(Some("acc +x8"), err.text.as_deref());
+        assert_eq!(
+            "line 2, column 6: bad\n    2 | acc +x8\n      |      ^",
+            err.to_string()
+        );
+
+        // not a slice of the input
+        assert_eq!(None, AocError::at(input, &String::from("x8"), "bad").line);
+    }
+
+    #[test]
+    fn parses_fields() {
+        let line = "acc +x8";
+        assert_eq!(Ok(8), parse_at::<i32>(line, &line[6..], "a number"));
+
+        let err = parse_at::<i32>(line, &line[4..], "a number").unwrap_err();
+        assert_eq!("expected a number, found `+x8`", err.reason);
+        assert_eq!(Some(5), err.column);
+
+        let err = parse_at::<i32>(line, &line[7..], "a number").unwrap_err();
+        assert_eq!("expected a number, found nothing", err.reason);
+        assert_eq!(Some(4), err.in_line(3).line);
+    }
+
+    #[test]
+    fn relocates() {
+        let input = "moves\n\n  y 2\n3 x\n";
+        let board = &input[9..];
+        let err = parse_at::<u8>(board, &board[6..7], "a number").unwrap_err();
+        assert_eq!((Some(2), Some(3)), (err.line, err.column));
+        let err = err.within(input, board);
+        assert_eq!((Some(4), Some(3)), (err.line, err.column));
+        assert_eq!(Some("3 x"), err.text.as_deref());
+
+        let err = parse_at::<u8>(board, &board[0..1], "a number").unwrap_err();
+        assert_eq!(Some(3), err.within(input, board).column);
+        assert_eq!(None, AocError::new("bad").within(input, board).line);
+    }
+
+    #[test]
+    fn whole_lines() {
+        let err = AocError::line(0, "F10 X", "unknown instruction");
+        assert_eq!(
+            "line 1: unknown instruction\n    1 | F10 X",
+            err.to_string()
+        );
+        assert_eq!("no answer", AocError::new("no answer").to_string());
+    }
+}
@@ -0,0 +1,31 @@
+//! Running the worked examples tagged in a day's module docs; `aoc_examples` describes the tags
+//! and finds them, and a year crate's build script generates a test calling [`check`] for each.
+
+use crate::input::report_skip_reason;
+use crate::solution::{Part, Registry};
+
+/// Runs one example through the year's registry and checks its answer. Days that are still stubs
+/// are skipped, saying so.
+pub fn check(
+    register: fn(&mut Registry),
+    year: u16,
+    day: u8,
+    part: Part,
+    input: &str,
+    expected: &str,
+) {
+    let mut registry = Registry::new();
+    register(&mut registry);
+    let Some(entry) = registry.get(year, day) else {
+        panic!("{} day {} isn't registered", year, day);
+    };
+    let Some(solution) = entry.solution() else {
+        report_skip_reason(&format!("{} day {} is a stub", year, day));
+        return;
+    };
+
+    match solution.run(input, part) {
+        Ok(answer) => assert_eq!(expected, answer, "{} day {} part {}", year, day, part),
+        Err(e) => panic!("{} day {} part {}: {}", year, day, part, e),
+    }
+}
@@ -0,0 +1,473 @@
+//! Integer geometry on the plane: line [`Segment`]s that rasterize and intersect at any slope,
+//! axis-aligned [`Rect`]s, and the area and lattice points of a [`Polygon`].
+
+use crate::point::Point2;
+
+/// The z component of the cross product of `a` and `b`: positive when `b` turns anticlockwise
+/// from `a` with `y` growing upwards, zero when they're parallel.
+fn cross(a: Point2, b: Point2) -> isize {
+    a.x * b.y - a.y * b.x
+}
+
+fn dot(a: Point2, b: Point2) -> isize {
+    a.x * b.x + a.y * b.y
+}
+
+fn gcd(mut a: usize, mut b: usize) -> usize {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// The straight line between two lattice points, both ends included.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Segment {
+    pub start: Point2,
+    pub end: Point2,
+}
+
+/// Where two [`Segment`]s meet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Intersection {
+    /// At a single lattice point.
+    Point(Point2),
+    /// At a single point between lattice points: `numerator / denominator`, in lowest terms with
+    /// a denominator above one.
+    Fraction {
+        numerator: Point2,
+        denominator: isize,
+    },
+    /// Along a stretch of both, the segments being collinear.
+    Overlap(Segment),
+}
+
+impl Segment {
+    pub fn new(start: Point2, end: Point2) -> Segment {
+        Segment { start, end }
+    }
+
+    /// The step from `start` to `end`.
+    pub fn delta(&self) -> Point2 {
+        self.end - self.start
+    }
+
+    pub fn is_horizontal(&self) -> bool {
+        self.start.y == self.end.y
+    }
+
+    pub fn is_vertical(&self) -> bool {
+        self.start.x == self.end.x
+    }
+
+    /// Whether the segment runs along an axis, which a single point does too.
+    pub fn is_axis_aligned(&self) -> bool {
+        self.is_horizontal() || self.is_vertical()
+    }
+
+    /// Whether the segment runs at 45 degrees.
+    pub fn is_diagonal(&self) -> bool {
+        let delta = self.delta();
+        delta.x != 0 && delta.x.abs() == delta.y.abs()
+    }
+
+    /// Whether `point` lies exactly on the segment.
+    pub fn contains(&self, point: Point2) -> bool {
+        let (min_x, max_x) = (self.start.x.min(self.end.x), self.start.x.max(self.end.x));
+        let (min_y, max_y) = (self.start.y.min(self.end.y), self.start.y.max(self.end.y));
+        cross(point - self.start, self.delta()) == 0
+            && (min_x..=max_x).contains(&point.x)
+            && (min_y..=max_y).contains(&point.y)
+    }
+
+    /// The lattice points the segment passes through exactly, from `start` to `end`.
+    pub fn lattice_points(&self) -> impl Iterator<Item = Point2> {
+        let delta = self.delta();
+        let steps = gcd(delta.x.unsigned_abs(), delta.y.unsigned_abs());
+        let step = if steps == 0 {
+            Point2::ORIGIN
+        } else {
+            Point2::new(delta.x / steps as isize, delta.y / steps as isize)
+        };
+        let start = self.start;
+        (0..=steps as isize).map(move |index| start + step * index)
+    }
+
+    /// The cells a line drawn from `start` to `end` covers, by Bresenham's algorithm: one per
+    /// column or row, whichever there are more of. Horizontal, vertical and 45 degree segments
+    /// cover exactly their [`lattice_points`](Segment::lattice_points).
+    pub fn rasterize(&self) -> Rasterize {
+        let delta = self.delta();
+        Rasterize {
+            next: Some(self.start),
+            end: self.end,
+            step: Point2::new(delta.x.signum(), delta.y.signum()),
+            dx: delta.x.abs(),
+            dy: -delta.y.abs(),
+            error: delta.x.abs() - delta.y.abs(),
+        }
+    }
+
+    /// Where this segment meets `other`, if it does, worked out exactly.
+    pub fn intersection(&self, other: &Segment) -> Option<Intersection> {
+        let (r, s) = (self.delta(), other.delta());
+        let offset = other.start - self.start;
+        let mut denominator = cross(r, s);
+
+        if denominator == 0 {
+            if cross(offset, r) != 0 || cross(offset, s) != 0 {
+                // parallel, but on different lines
+                return None;
+            }
+            // collinear: any overlap runs between two of the four ends
+            let axis = if r == Point2::ORIGIN { s } else { r };
+            let mut ends = [self.start, self.end, other.start, other.end]
+                .into_iter()
+                .filter(|end| self.contains(*end) && other.contains(*end));
+            let first = ends.next()?;
+            let (from, to) = ends.fold((first, first), |(from, to), end| {
+                let along = dot(end - self.start, axis);
+                (
+                    if along < dot(from - self.start, axis) {
+                        end
+                    } else {
+                        from
+                    },
+                    if along > dot(to - self.start, axis) {
+                        end
+                    } else {
+                        to
+                    },
+                )
+            });
+            return Some(if from == to {
+                Intersection::Point(from)
+            } else {
+                Intersection::Overlap(Segment::new(from, to))
+            });
+        }
+
+        // the crossing is at `self.start + r * t` and `other.start + s * u`, t and u in 0..=1
+        let (mut t, mut u) = (cross(offset, s), cross(offset, r));
+        if denominator < 0 {
+            (denominator, t, u) = (-denominator, -t, -u);
+        }
+        if !(0..=denominator).contains(&t) || !(0..=denominator).contains(&u) {
+            return None;
+        }
+
+        let mut numerator = self.start * denominator + r * t;
+        let common = gcd(
+            gcd(numerator.x.unsigned_abs(), numerator.y.unsigned_abs()),
+            denominator as usize,
+        ) as isize;
+        numerator = Point2::new(numerator.x / common, numerator.y / common);
+        denominator /= common;
+        Some(if denominator == 1 {
+            Intersection::Point(numerator)
+        } else {
+            Intersection::Fraction {
+                numerator,
+                denominator,
+            }
+        })
+    }
+}
+
+/// The cells covered by a [`Segment`]; see [`Segment::rasterize`].
+#[derive(Debug, Clone)]
+pub struct Rasterize {
+    next: Option<Point2>,
+    end: Point2,
+    step: Point2,
+    dx: isize,
+    dy: isize,
+    error: isize,
+}
+
+impl Iterator for Rasterize {
+    type Item = Point2;
+
+    fn next(&mut self) -> Option<Point2> {
+        let point = self.next?;
+        if point == self.end {
+            self.next = None;
+            return Some(point);
+        }
+
+        let mut next = point;
+        let doubled = 2 * self.error;
+        if doubled >= self.dy {
+            self.error += self.dy;
+            next.x += self.step.x;
+        }
+        if doubled <= self.dx {
+            self.error += self.dx;
+            next.y += self.step.y;
+        }
+        self.next = Some(next);
+        Some(point)
+    }
+}
+
+/// A rectangle with sides along the axes, every edge included.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rect {
+    /// The corner with the smallest coordinates.
+    pub min: Point2,
+    /// The corner with the largest coordinates.
+    pub max: Point2,
+}
+
+impl Rect {
+    /// The rectangle with opposite corners `a` and `b`, in either order.
+    pub fn new(a: Point2, b: Point2) -> Rect {
+        Rect {
+            min: Point2::new(a.x.min(b.x), a.y.min(b.y)),
+            max: Point2::new(a.x.max(b.x), a.y.max(b.y)),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        (self.max.x - self.min.x) as usize + 1
+    }
+
+    pub fn height(&self) -> usize {
+        (self.max.y - self.min.y) as usize + 1
+    }
+
+    /// The number of lattice points inside, edges included.
+    pub fn area(&self) -> usize {
+        self.width() * self.height()
+    }
+
+    pub fn contains(&self, point: Point2) -> bool {
+        (self.min.x..=self.max.x).contains(&point.x) && (self.min.y..=self.max.y).contains(&point.y)
+    }
+
+    /// The rectangle covered by both, if they overlap at all.
+    pub fn intersection(&self, other: &Rect) -> Option<Rect> {
+        let min = Point2::new(self.min.x.max(other.min.x), self.min.y.max(other.min.y));
+        let max = Point2::new(self.max.x.min(other.max.x), self.max.y.min(other.max.y));
+        (min.x <= max.x && min.y <= max.y).then_some(Rect { min, max })
+    }
+
+    /// Every point inside, row by row.
+    pub fn points(&self) -> impl Iterator<Item = Point2> {
+        let Rect { min, max } = *self;
+        (min.y..=max.y).flat_map(move |y| (min.x..=max.x).map(move |x| Point2::new(x, y)))
+    }
+}
+
+/// A closed polygon on lattice points, with an edge from each vertex to the next and from the
+/// last back to the first. The vertices may go either way round, but the edges mustn't cross.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Polygon {
+    vertices: Vec<Point2>,
+}
+
+impl Polygon {
+    pub fn new(vertices: Vec<Point2>) -> Polygon {
+        Polygon { vertices }
+    }
+
+    pub fn vertices(&self) -> &[Point2] {
+        &self.vertices
+    }
+
+    /// Every edge, closing the loop back to the first vertex.
+    pub fn edges(&self) -> impl Iterator<Item = Segment> + '_ {
+        let next = self.vertices.iter().cycle().skip(1);
+        self.vertices
+            .iter()
+            .zip(next)
+            .map(|(start, end)| Segment::new(*start, *end))
+    }
+
+    /// Twice the enclosed area, by the shoelace formula. Doubled so it stays a whole number.
+    pub fn double_area(&self) -> usize {
+        self.edges()
+            .map(|edge| cross(edge.start, edge.end))
+            .sum::<isize>()
+            .unsigned_abs()
+    }
+
+    /// The number of lattice points on the edges.
+    pub fn boundary_points(&self) -> usize {
+        self.edges()
+            .map(|edge| {
+                let delta = edge.delta();
+                gcd(delta.x.unsigned_abs(), delta.y.unsigned_abs())
+            })
+            .sum()
+    }
+
+    /// The number of lattice points strictly inside, by Pick's theorem. A polygon enclosing no
+    /// area, whether empty, a single point or doubling back along a line, has none.
+    pub fn interior_points(&self) -> usize {
+        match self.double_area() {
+            0 => 0,
+            double_area => (double_area + 2 - self.boundary_points()) / 2,
+        }
+    }
+
+    /// The number of lattice points inside or on the edges: the cells dug out when the polygon
+    /// traces the middle of a trench one cell wide.
+    pub fn lattice_points(&self) -> usize {
+        if self.vertices.is_empty() {
+            return 0;
+        }
+        // interior plus boundary, which for a polygon enclosing nothing still counts each point
+        // along it once even though the edges there go over it twice
+        (self.double_area() + self.boundary_points()) / 2 + 1
+    }
+}
+
+impl FromIterator<Point2> for Polygon {
+    fn from_iter<I: IntoIterator<Item = Point2>>(iter: I) -> Self {
+        Polygon::new(iter.into_iter().collect())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn segment(from: (isize, isize), to: (isize, isize)) -> Segment {
+        Segment::new(from.into(), to.into())
+    }
+
+    fn points(points: impl Iterator<Item = Point2>) -> Vec<(isize, isize)> {
+        points.map(Into::into).collect()
+    }
+
+    #[test]
+    fn rasterize() {
+        assert_eq!(
+            vec![(3, 1), (2, 1), (1, 1)],
+            points(segment((3, 1), (1, 1)).rasterize())
+        );
+        assert_eq!(
+            vec![(7, 7), (6, 6), (5, 5)],
+            points(segment((7, 7), (5, 5)).rasterize())
+        );
+        assert_eq!(
+            vec![(0, 0), (1, 1), (2, 1), (3, 2), (4, 2)],
+            points(segment((0, 0), (4, 2)).rasterize())
+        );
+        assert_eq!(
+            vec![(0, 0), (0, -1), (1, -2), (1, -3)],
+            points(segment((0, 0), (1, -3)).rasterize())
+        );
+        assert_eq!(vec![(2, 2)], points(segment((2, 2), (2, 2)).rasterize()));
+
+        assert_eq!(
+            vec![(0, 0), (2, 1), (4, 2)],
+            points(segment((0, 0), (4, 2)).lattice_points())
+        );
+        assert!(segment((0, 0), (4, 2)).contains(Point2::new(2, 1)));
+        assert!(!segment((0, 0), (4, 2)).contains(Point2::new(6, 3)));
+    }
+
+    #[test]
+    fn intersections() {
+        assert_eq!(
+            Some(Intersection::Point(Point2::new(2, 2))),
+            segment((0, 0), (4, 4)).intersection(&segment((0, 4), (4, 0)))
+        );
+        assert_eq!(
+            Some(Intersection::Fraction {
+                numerator: Point2::new(1, 1),
+                denominator: 2
+            }),
+            segment((0, 0), (1, 1)).intersection(&segment((0, 1), (1, 0)))
+        );
+        assert_eq!(
+            None,
+            segment((0, 0), (1, 1)).intersection(&segment((3, 0), (2, 1)))
+        );
+        assert_eq!(
+            None,
+            segment((0, 0), (4, 0)).intersection(&segment((0, 1), (4, 1)))
+        );
+        assert_eq!(
+            Some(Intersection::Overlap(segment((2, 0), (4, 0)))),
+            segment((0, 0), (4, 0)).intersection(&segment((6, 0), (2, 0)))
+        );
+        assert_eq!(
+            Some(Intersection::Point(Point2::new(4, 0))),
+            segment((0, 0), (4, 0)).intersection(&segment((4, 0), (8, 0)))
+        );
+        assert_eq!(
+            None,
+            segment((0, 0), (4, 0)).intersection(&segment((5, 0), (8, 0)))
+        );
+    }
+
+    #[test]
+    fn rects() {
+        let a = Rect::new(Point2::new(20, -5), Point2::new(30, -10));
+        assert_eq!((Point2::new(20, -10), Point2::new(30, -5)), (a.min, a.max));
+        assert_eq!((11, 6, 66), (a.width(), a.height(), a.area()));
+        assert!(a.contains(Point2::new(25, -7)));
+        assert!(!a.contains(Point2::new(25, -4)));
+
+        let b = Rect::new(Point2::new(28, -6), Point2::new(40, 0));
+        assert_eq!(
+            Some(Rect::new(Point2::new(28, -6), Point2::new(30, -5))),
+            a.intersection(&b)
+        );
+        assert_eq!(6, a.intersection(&b).unwrap().points().count());
+        assert_eq!(
+            None,
+            a.intersection(&Rect::new(Point2::ORIGIN, Point2::ORIGIN))
+        );
+    }
+
+    #[test]
+    fn polygons() {
+        let square: Polygon = [(0, 0), (0, 3), (3, 3), (3, 0)]
+            .into_iter()
+            .map(Point2::from)
+            .collect();
+        assert_eq!(18, square.double_area());
+        assert_eq!(12, square.boundary_points());
+        assert_eq!(4, square.interior_points());
+        assert_eq!(16, square.lattice_points());
+
+        let triangle: Polygon = [(0, 0), (4, 0), (0, 2)]
+            .into_iter()
+            .map(Point2::from)
+            .collect();
+        assert_eq!(8, triangle.double_area());
+        assert_eq!(8, triangle.boundary_points());
+        assert_eq!(1, triangle.interior_points());
+        assert_eq!(9, triangle.lattice_points());
+    }
+
+    #[test]
+    fn degenerate_polygons() {
+        let empty = Polygon::new(vec![]);
+        assert_eq!(
+            (0, 0, 0),
+            (
+                empty.double_area(),
+                empty.interior_points(),
+                empty.lattice_points()
+            )
+        );
+
+        let point = Polygon::new(vec![Point2::from((2, 5))]);
+        assert_eq!((0, 1), (point.interior_points(), point.lattice_points()));
+
+        // out along a line and back, and a line with a point partway along it
+        let line: Polygon = [(0, 0), (3, 0)].into_iter().map(Point2::from).collect();
+        assert_eq!(6, line.boundary_points());
+        assert_eq!((0, 4), (line.interior_points(), line.lattice_points()));
+        let line: Polygon = [(0, 0), (4, 2), (2, 1)]
+            .into_iter()
+            .map(Point2::from)
+            .collect();
+        assert_eq!((0, 3), (line.interior_points(), line.lattice_points()));
+    }
+}
@@ -0,0 +1,541 @@
+//! Graph searches: breadth and depth first walks, shortest paths by Dijkstra's algorithm or A*,
+//! path enumeration, and topological sorting.
+//!
+//! The searches are free functions taking a `successors` closure, so they run straight over
+//! anything with a notion of "next", like the cells of a [`Grid`](crate::Grid). A [`Graph`]
+//! holds an explicit adjacency list for puzzles that hand over their edges, and offers the same
+//! searches as methods.
+
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+use crate::search::Stats;
+
+/// A route found by a search: the nodes along it, both ends included, and what it cost to walk.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<N> {
+    pub nodes: Vec<N>,
+    pub cost: usize,
+}
+
+impl<N> Path<N> {
+    /// The node the path ends on.
+    pub fn end(&self) -> &N {
+        self.nodes.last().expect("a path holds at least its start")
+    }
+}
+
+/// Follows `parents` back from `index` to the node without one, the start.
+fn walk_back<N: Clone>(nodes: &[N], parents: &[Option<usize>], mut index: usize) -> Vec<N> {
+    let mut path = vec![nodes[index].clone()];
+    while let Some(parent) = parents[index] {
+        path.push(nodes[parent].clone());
+        index = parent;
+    }
+    path.reverse();
+    path
+}
+
+/// The cheapest path from `start` to a node `is_goal` accepts, where `successors` gives each
+/// node's neighbors along with the cost of stepping to them.
+pub fn dijkstra<N, I>(
+    start: N,
+    successors: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N>>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = (N, usize)>,
+{
+    astar(start, successors, |_| 0, is_goal)
+}
+
+/// [`dijkstra`], guided by `heuristic`: an estimate of the cost left from a node to the nearest
+/// goal. The path found is only the cheapest if the estimate never overshoots.
+pub fn astar<N, I>(
+    start: N,
+    successors: impl FnMut(&N) -> I,
+    heuristic: impl FnMut(&N) -> usize,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N>>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = (N, usize)>,
+{
+    astar_with_stats(start, successors, heuristic, is_goal, &mut Stats::default())
+}
+
+/// [`astar`], tallying up what it gets through in `stats`.
+pub(crate) fn astar_with_stats<N, I>(
+    start: N,
+    mut successors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> usize,
+    mut is_goal: impl FnMut(&N) -> bool,
+    stats: &mut Stats,
+) -> Option<Path<N>>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = (N, usize)>,
+{
+    // every node seen gets an index, under which its parent and best known cost are kept
+    let mut indices = HashMap::from([(start.clone(), 0)]);
+    let mut nodes = vec![start];
+    let mut parents = vec![None];
+    let mut costs = vec![0];
+    let mut queue = BinaryHeap::from([Reverse((heuristic(&nodes[0]), 0, 0))]);
+
+    while let Some(Reverse((_, cost, index))) = queue.pop() {
+        if cost > costs[index] {
+            // a cheaper way here was found after this one was queued
+            continue;
+        }
+        if is_goal(&nodes[index]) {
+            return Some(Path {
+                nodes: walk_back(&nodes, &parents, index),
+                cost,
+            });
+        }
+
+        stats.expanded += 1;
+        for (next, step) in successors(&nodes[index]) {
+            stats.generated += 1;
+            let next_cost = cost + step;
+            let next_index = match indices.get(&next) {
+                Some(&seen) if costs[seen] <= next_cost => {
+                    stats.duplicates += 1;
+                    continue;
+                }
+                Some(&seen) => {
+                    costs[seen] = next_cost;
+                    parents[seen] = Some(index);
+                    seen
+                }
+                None => {
+                    indices.insert(next.clone(), nodes.len());
+                    nodes.push(next);
+                    parents.push(Some(index));
+                    costs.push(next_cost);
+                    nodes.len() - 1
+                }
+            };
+            let estimate = next_cost + heuristic(&nodes[next_index]);
+            queue.push(Reverse((estimate, next_cost, next_index)));
+        }
+        stats.max_frontier = stats.max_frontier.max(queue.len());
+    }
+    None
+}
+
+/// The path from `start` to a node `is_goal` accepts with the fewest steps.
+pub fn bfs<N, I>(
+    start: N,
+    mut successors: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N>>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = N>,
+{
+    let steps = |node: &N| successors(node).into_iter().map(|next| (next, 1));
+    bfs_with_stats(start, steps, is_goal, &mut Stats::default())
+}
+
+/// [`bfs`] over steps with costs, tallying up what it gets through in `stats`. The path still
+/// has the fewest steps, whatever they cost, but its cost is the sum of them.
+pub(crate) fn bfs_with_stats<N, I>(
+    start: N,
+    mut successors: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+    stats: &mut Stats,
+) -> Option<Path<N>>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = (N, usize)>,
+{
+    let mut indices = HashMap::from([(start.clone(), 0)]);
+    let mut nodes = vec![start];
+    let mut parents = vec![None];
+    let mut costs = vec![0];
+    let mut queue = VecDeque::from([0]);
+
+    while let Some(index) = queue.pop_front() {
+        if is_goal(&nodes[index]) {
+            return Some(Path {
+                nodes: walk_back(&nodes, &parents, index),
+                cost: costs[index],
+            });
+        }
+        stats.expanded += 1;
+        for (next, step) in successors(&nodes[index]) {
+            stats.generated += 1;
+            if indices.contains_key(&next) {
+                stats.duplicates += 1;
+                continue;
+            }
+            indices.insert(next.clone(), nodes.len());
+            nodes.push(next);
+            parents.push(Some(index));
+            costs.push(costs[index] + step);
+            queue.push_back(nodes.len() - 1);
+        }
+        stats.max_frontier = stats.max_frontier.max(queue.len());
+    }
+    None
+}
+
+/// Every node reachable from `start`, nearest first.
+pub fn bfs_order<N, I>(start: N, mut successors: impl FnMut(&N) -> I) -> Vec<N>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = N>,
+{
+    let mut seen = HashSet::from([start.clone()]);
+    let mut order = vec![start];
+    let mut next_up = 0;
+    while next_up < order.len() {
+        for next in successors(&order[next_up]) {
+            if seen.insert(next.clone()) {
+                order.push(next);
+            }
+        }
+        next_up += 1;
+    }
+    order
+}
+
+/// Every node reachable from `start`, each visited before any found from it, following the
+/// first successor as deep as it goes before backing up to the next.
+pub fn dfs_order<N, I>(start: N, mut successors: impl FnMut(&N) -> I) -> Vec<N>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = N>,
+{
+    let mut seen = HashSet::new();
+    let mut order = vec![];
+    let mut stack = vec![start];
+    while let Some(node) = stack.pop() {
+        if !seen.insert(node.clone()) {
+            continue;
+        }
+        let mut next: Vec<N> = successors(&node)
+            .into_iter()
+            .filter(|next| !seen.contains(next))
+            .collect();
+        next.reverse();
+        stack.extend(next);
+        order.push(node);
+    }
+    order
+}
+
+/// A directed graph, its nodes identified by any hashable `N` and each edge carrying a cost.
+/// Nodes are kept in the order they were added, which the walks and sorts follow too.
+#[derive(Debug, Clone)]
+pub struct Graph<N> {
+    nodes: Vec<N>,
+    indices: HashMap<N, usize>,
+    edges: Vec<Vec<(usize, usize)>>,
+}
+
+impl<N> Default for Graph<N> {
+    fn default() -> Self {
+        Graph {
+            nodes: vec![],
+            indices: HashMap::new(),
+            edges: vec![],
+        }
+    }
+}
+
+impl<N: Eq + Hash + Clone> Graph<N> {
+    pub fn new() -> Graph<N> {
+        Graph::default()
+    }
+
+    /// The number of nodes.
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    pub fn contains(&self, node: &N) -> bool {
+        self.indices.contains_key(node)
+    }
+
+    /// Every node, in the order they were added.
+    pub fn nodes(&self) -> impl Iterator<Item = &N> {
+        self.nodes.iter()
+    }
+
+    /// Adds `node` if it isn't there yet, returning its index.
+    fn index_of(&mut self, node: N) -> usize {
+        if let Some(&index) = self.indices.get(&node) {
+            return index;
+        }
+        self.indices.insert(node.clone(), self.nodes.len());
+        self.nodes.push(node);
+        self.edges.push(vec![]);
+        self.nodes.len() - 1
+    }
+
+    /// Adds `node` with no edges, if it isn't there yet.
+    pub fn add_node(&mut self, node: N) {
+        self.index_of(node);
+    }
+
+    /// Adds an edge from `from` to `to` costing `cost`, adding either node that's missing.
+    pub fn add_edge(&mut self, from: N, to: N, cost: usize) {
+        let (from, to) = (self.index_of(from), self.index_of(to));
+        self.edges[from].push((to, cost));
+    }
+
+    /// Adds edges both ways between `a` and `b`, each costing `cost`.
+    pub fn add_undirected_edge(&mut self, a: N, b: N, cost: usize) {
+        self.add_edge(a.clone(), b.clone(), cost);
+        self.add_edge(b, a, cost);
+    }
+
+    /// The nodes an edge from `node` leads to, with what each edge costs. A node that isn't in
+    /// the graph has none.
+    pub fn neighbors(&self, node: &N) -> impl Iterator<Item = (&N, usize)> {
+        let edges = match self.indices.get(node) {
+            Some(&index) => &self.edges[index][..],
+            None => &[],
+        };
+        edges.iter().map(|&(to, cost)| (&self.nodes[to], cost))
+    }
+
+    /// The cheapest path from `from` to `to`; see [`dijkstra`].
+    pub fn shortest_path(&self, from: &N, to: &N) -> Option<Path<&N>> {
+        let start = self.nodes.get(*self.indices.get(from)?)?;
+        dijkstra(start, |node| self.neighbors(node), |node| *node == to)
+    }
+
+    /// Every node reachable from `from`, nearest first; see [`bfs_order`].
+    pub fn bfs_order(&self, from: &N) -> Vec<&N> {
+        match self.indices.get(from) {
+            Some(&index) => bfs_order(&self.nodes[index], |node| {
+                self.neighbors(node).map(|(next, _)| next)
+            }),
+            None => vec![],
+        }
+    }
+
+    /// Every node reachable from `from`, deepest first; see [`dfs_order`].
+    pub fn dfs_order(&self, from: &N) -> Vec<&N> {
+        match self.indices.get(from) {
+            Some(&index) => dfs_order(&self.nodes[index], |node| {
+                self.neighbors(node).map(|(next, _)| next)
+            }),
+            None => vec![],
+        }
+    }
+
+    /// Every path from `from` to `to` that only steps onto nodes `can_visit` allows, given the
+    /// node and the path walked so far. A path ends the first time it reaches `to`.
+    ///
+    /// The visit policy must eventually refuse every node, or there are endless paths.
+    pub fn all_paths(
+        &self,
+        from: &N,
+        to: &N,
+        can_visit: impl FnMut(&N, &[&N]) -> bool,
+    ) -> Vec<Vec<&N>> {
+        let mut paths = vec![];
+        self.visit_paths(from, to, can_visit, |path| paths.push(path.to_vec()));
+        paths
+    }
+
+    /// How many paths [`all_paths`](Graph::all_paths) would find, without keeping any of them.
+    pub fn count_paths(&self, from: &N, to: &N, can_visit: impl FnMut(&N, &[&N]) -> bool) -> usize {
+        let mut count = 0;
+        self.visit_paths(from, to, can_visit, |_| count += 1);
+        count
+    }
+
+    /// Hands each path [`all_paths`](Graph::all_paths) would find to `visit` as it's found,
+    /// rather than collecting them.
+    pub fn visit_paths<'a>(
+        &'a self,
+        from: &N,
+        to: &N,
+        mut can_visit: impl FnMut(&N, &[&N]) -> bool,
+        mut visit: impl FnMut(&[&'a N]),
+    ) {
+        if let Some(&start) = self.indices.get(from) {
+            let mut path = vec![&self.nodes[start]];
+            self.extend_paths(to, &mut path, &mut can_visit, &mut visit);
+        }
+    }
+
+    fn extend_paths<'a>(
+        &'a self,
+        to: &N,
+        path: &mut Vec<&'a N>,
+        can_visit: &mut impl FnMut(&N, &[&N]) -> bool,
+        visit: &mut impl FnMut(&[&'a N]),
+    ) {
+        let last = *path.last().expect("a path holds at least its start");
+        if last == to {
+            visit(path);
+            return;
+        }
+        for (next, _) in self.neighbors(last) {
+            if can_visit(next, path) {
+                path.push(next);
+                self.extend_paths(to, path, can_visit, visit);
+                path.pop();
+            }
+        }
+    }
+
+    /// Every path from `from` to `to` that visits no node twice.
+    pub fn simple_paths(&self, from: &N, to: &N) -> Vec<Vec<&N>> {
+        self.all_paths(from, to, |node, path| !path.contains(&node))
+    }
+
+    /// Every node, each before all those its edges lead to, or `None` if a cycle makes that
+    /// impossible. Of the nodes free to go next, the earliest added goes first.
+    pub fn topological_sort(&self) -> Option<Vec<&N>> {
+        let mut incoming = vec![0; self.nodes.len()];
+        for edges in &self.edges {
+            for &(to, _) in edges {
+                incoming[to] += 1;
+            }
+        }
+
+        let mut ready: BinaryHeap<Reverse<usize>> = (0..self.nodes.len())
+            .filter(|&index| incoming[index] == 0)
+            .map(Reverse)
+            .collect();
+        let mut order = Vec::with_capacity(self.nodes.len());
+        while let Some(Reverse(index)) = ready.pop() {
+            order.push(&self.nodes[index]);
+            for &(to, _) in &self.edges[index] {
+                incoming[to] -= 1;
+                if incoming[to] == 0 {
+                    ready.push(Reverse(to));
+                }
+            }
+        }
+        (order.len() == self.nodes.len()).then_some(order)
+    }
+
+    /// Whether following edges can lead from some node back to itself. An undirected edge
+    /// counts, being a way there and back.
+    pub fn has_cycle(&self) -> bool {
+        self.topological_sort().is_none()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn weighted() -> Graph<char> {
+        let mut graph = Graph::new();
+        graph.add_edge('a', 'b', 7);
+        graph.add_edge('a', 'c', 2);
+        graph.add_edge('c', 'b', 3);
+        graph.add_edge('b', 'd', 1);
+        graph.add_edge('c', 'd', 8);
+        graph.add_node('e');
+        graph
+    }
+
+    #[test]
+    fn shortest_paths() {
+        let graph = weighted();
+        let path = graph.shortest_path(&'a', &'d').unwrap();
+        assert_eq!(vec![&'a', &'c', &'b', &'d'], path.nodes);
+        assert_eq!(6, path.cost);
+        assert_eq!(&'d', *path.end());
+        assert_eq!(None, graph.shortest_path(&'a', &'e'));
+        assert_eq!(None, graph.shortest_path(&'z', &'a'));
+
+        // A* across an open plane, guided by the Manhattan distance left
+        let goal = (5_isize, -3_isize);
+        let path = astar(
+            (0_isize, 0_isize),
+            |&(x, y)| {
+                [
+                    ((x + 1, y), 1),
+                    ((x - 1, y), 1),
+                    ((x, y + 1), 1),
+                    ((x, y - 1), 1),
+                ]
+            },
+            |&(x, y)| (goal.0 - x).unsigned_abs() + (goal.1 - y).unsigned_abs(),
+            |&node| node == goal,
+        )
+        .unwrap();
+        assert_eq!(8, path.cost);
+        assert_eq!(9, path.nodes.len());
+    }
+
+    #[test]
+    fn walks() {
+        let graph = weighted();
+        assert_eq!(vec![&'a', &'b', &'c', &'d'], graph.bfs_order(&'a'));
+        assert_eq!(vec![&'a', &'b', &'d', &'c'], graph.dfs_order(&'a'));
+        assert_eq!(vec![&'e'], graph.bfs_order(&'e'));
+
+        // the fewest steps, rather than the cheapest
+        let path = bfs(
+            'a',
+            |node| graph.neighbors(node).map(|(next, _)| *next),
+            |node| *node == 'd',
+        )
+        .unwrap();
+        assert_eq!((vec!['a', 'b', 'd'], 2), (path.nodes, path.cost));
+    }
+
+    #[test]
+    fn paths() {
+        let mut graph = Graph::new();
+        for (a, b) in [
+            ("start", "A"),
+            ("start", "b"),
+            ("A", "b"),
+            ("A", "end"),
+            ("b", "end"),
+        ] {
+            graph.add_undirected_edge(a, b, 1);
+        }
+        let simple = graph.simple_paths(&"start", &"end");
+        assert_eq!(4, simple.len());
+        assert!(simple.contains(&vec![&"start", &"A", &"b", &"end"]));
+
+        // big caves can be visited any number of times
+        let big = |cave: &&str, path: &[&&str]| {
+            cave.chars().all(|c| c.is_uppercase()) || !path.contains(&cave)
+        };
+        let caves = graph.all_paths(&"start", &"end", big);
+        assert_eq!(5, caves.len());
+        assert_eq!(5, graph.count_paths(&"start", &"end", big));
+        let mut longest = 0;
+        graph.visit_paths(&"start", &"end", big, |path| {
+            longest = longest.max(path.len())
+        });
+        assert_eq!(5, longest);
+    }
+
+    #[test]
+    fn sorting() {
+        let graph = weighted();
+        assert_eq!(
+            Some(vec![&'a', &'c', &'b', &'d', &'e']),
+            graph.topological_sort()
+        );
+        assert!(!graph.has_cycle());
+
+        let mut cyclic = weighted();
+        cyclic.add_edge('d', 'a', 1);
+        assert_eq!(None, cyclic.topological_sort());
+        assert!(cyclic.has_cycle());
+    }
+}
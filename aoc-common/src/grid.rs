@@ -0,0 +1,305 @@
+//! [`Grid`], a dense 2D board sized at runtime, for the puzzles played out on a map of cells.
+
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use crate::error::{AocError, AocResult};
+
+/// A `width` by `height` board of cells, stored row by row.
+///
+/// Cells are addressed by `(x, y)`, with `x` the column and `y` the row, counting from the top
+/// left. [`Grid::get`] is the checked way in; indexing with `grid[(x, y)]` panics when the
+/// position is off the board.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// A board with every cell set to `fill`.
+    pub fn new(width: usize, height: usize, fill: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// A board with each cell set to `cell((x, y))`.
+    pub fn from_fn(
+        width: usize,
+        height: usize,
+        mut cell: impl FnMut((usize, usize)) -> T,
+    ) -> Grid<T> {
+        let mut cells = Vec::with_capacity(width * height);
+        for y in 0..height {
+            for x in 0..width {
+                cells.push(cell((x, y)));
+            }
+        }
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// A board from its rows, which must all be the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> AocResult<Grid<T>> {
+        let height = rows.len();
+        let width = rows.first().map_or(0, Vec::len);
+        let mut cells = Vec::with_capacity(width * height);
+        for (index, row) in rows.into_iter().enumerate() {
+            if row.len() != width {
+                return Err(AocError::new(format!(
+                    "row {} has {} cells, expected {}",
+                    index + 1,
+                    row.len(),
+                    width
+                ))
+                .in_line(index));
+            }
+            cells.extend(row);
+        }
+        Ok(Grid {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    /// Parses a board drawn one row per line, turning each character into a cell with `cell`.
+    /// Characters `cell` rejects are reported as not being `what`, e.g. "an energy level".
+    pub fn parse(
+        input: &str,
+        what: &str,
+        mut cell: impl FnMut(char) -> Option<T>,
+    ) -> AocResult<Grid<T>> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = vec![];
+        for (index, line) in input.lines().enumerate() {
+            let mut row_width = 0;
+            for (offset, c) in line.char_indices() {
+                let value = cell(c).ok_or_else(|| {
+                    let span = &line[offset..offset + c.len_utf8()];
+                    AocError::at(input, span, format!("expected {}, found `{}`", what, c))
+                })?;
+                cells.push(value);
+                row_width += 1;
+            }
+
+            match width {
+                None => width = Some(row_width),
+                Some(width) if width != row_width => {
+                    return Err(AocError::line(
+                        index,
+                        line,
+                        format!("expected {} cells, found {}", width, row_width),
+                    ));
+                }
+                Some(_) => (),
+            }
+            height += 1;
+        }
+
+        match width {
+            Some(width) if width > 0 => Ok(Grid {
+                width,
+                height,
+                cells,
+            }),
+            _ => Err(AocError::new("the grid is empty")),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The number of cells on the board.
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Whether `(x, y)` is on the board.
+    pub fn contains(&self, (x, y): (usize, usize)) -> bool {
+        x < self.width && y < self.height
+    }
+
+    /// The cell at `(x, y)`, or `None` off the board.
+    pub fn get(&self, position: (usize, usize)) -> Option<&T> {
+        self.contains(position)
+            .then(|| &self.cells[position.1 * self.width + position.0])
+    }
+
+    /// The cell at `(x, y)` to change, or `None` off the board.
+    pub fn get_mut(&mut self, position: (usize, usize)) -> Option<&mut T> {
+        if self.contains(position) {
+            Some(&mut self.cells[position.1 * self.width + position.0])
+        } else {
+            None
+        }
+    }
+
+    /// The cells of row `y`, left to right.
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    /// Every row, top to bottom.
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks` panics on a zero size, and a board with no columns has no rows worth giving
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// The cells of column `x`, top to bottom.
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "column {} is off the board", x);
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    /// Every column, left to right.
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// Every cell, row by row.
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.cells.iter()
+    }
+
+    /// Every cell to change, row by row.
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, T> {
+        self.cells.iter_mut()
+    }
+
+    /// Every position on the board, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// Every cell along with its position, row by row.
+    pub fn indexed(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// A board of the same size with `f` applied to every cell.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: (usize, usize)) -> &T {
+        self.get(position).unwrap_or_else(|| {
+            panic!(
+                "{:?} is outside the {}x{} grid",
+                position, self.width, self.height
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, position: (usize, usize)) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(position)
+            .unwrap_or_else(|| panic!("{:?} is outside the {}x{} grid", position, width, height))
+    }
+}
+
+/// Draws the board one row per line. A width, as in `{:3}`, is applied to every cell.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let width = f.width().unwrap_or(0);
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{:width$}", cell, width = width)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn digits(input: &str) -> AocResult<Grid<u8>> {
+        Grid::parse(input, "a digit", |c| c.to_digit(10).map(|d| d as u8))
+    }
+
+    #[test]
+    fn parse_and_index() {
+        let grid = digits("123\n456\n").unwrap();
+        assert_eq!((3, 2), (grid.width(), grid.height()));
+        assert_eq!(6, grid[(2, 1)]);
+        assert_eq!(Some(&4), grid.get((0, 1)));
+        assert_eq!(None, grid.get((3, 0)));
+        assert_eq!(None, grid.get((0, 2)));
+        assert_eq!("123\n456\n", grid.to_string());
+        assert_eq!("  1  2  3\n  4  5  6\n", format!("{:3}", grid));
+    }
+
+    #[test]
+    fn rows_and_columns() {
+        let grid = Grid::from_fn(3, 2, |(x, y)| x + 10 * y);
+        assert_eq!(
+            vec![&[0, 1, 2][..], &[10, 11, 12][..]],
+            grid.rows().collect::<Vec<_>>()
+        );
+        assert_eq!(vec![1, 11], grid.column(1).copied().collect::<Vec<_>>());
+        assert_eq!(
+            vec![vec![0, 10], vec![1, 11], vec![2, 12]],
+            grid.columns()
+                .map(|column| column.copied().collect::<Vec<_>>())
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(Some(((2, 1), &12)), grid.indexed().last());
+        assert_eq!(
+            grid,
+            Grid::from_rows(vec![vec![0, 1, 2], vec![10, 11, 12]]).unwrap()
+        );
+    }
+
+    #[test]
+    fn malformed() {
+        let err = digits("123\n4x6\n").unwrap_err();
+        assert_eq!((Some(2), Some(2)), (err.line, err.column));
+
+        let err = digits("123\n45\n").unwrap_err();
+        assert_eq!((Some(2), None), (err.line, err.column));
+
+        assert!(digits("").is_err());
+        assert!(Grid::from_rows(vec![vec![1], vec![]]).is_err());
+    }
+
+    #[test]
+    #[should_panic]
+    fn index_off_the_board() {
+        let mut grid = Grid::new(2, 2, 0);
+        grid[(2, 0)] = 1;
+    }
+}
@@ -0,0 +1,521 @@
+//! Exporting puzzle states as pictures to share.
+//!
+//! An [`Image`] is a block of pixels, built from a [`Grid`] by coloring each cell or from a
+//! rendered [`Frame`] by coloring each character, and saved as a PNG or PPM. A run of images
+//! stitches together into an animated GIF with [`save_gif`]. Point sets and lines that are
+//! better drawn than rasterized, like vent lines, go into an [`Svg`] instead.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+use std::time::Duration;
+
+use crate::geometry::{Polygon, Segment};
+use crate::grid::Grid;
+use crate::point::Point2;
+use crate::render::{Frame, Tone};
+
+/// A color.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Rgb {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+}
+
+impl Rgb {
+    pub const BLACK: Rgb = Rgb::new(0, 0, 0);
+    pub const WHITE: Rgb = Rgb::new(255, 255, 255);
+
+    pub const fn new(r: u8, g: u8, b: u8) -> Rgb {
+        Rgb { r, g, b }
+    }
+
+    /// The color `t` of the way from this one to `other`.
+    pub fn lerp(self, other: Rgb, t: f64) -> Rgb {
+        let mix = |from: u8, to: u8| (from as f64 + (to as f64 - from as f64) * t).round() as u8;
+        Rgb::new(
+            mix(self.r, other.r),
+            mix(self.g, other.g),
+            mix(self.b, other.b),
+        )
+    }
+}
+
+/// As `#rrggbb`.
+impl fmt::Display for Rgb {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "#{:02x}{:02x}{:02x}", self.r, self.g, self.b)
+    }
+}
+
+/// Colors to pick from by index, for cells that are numbers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Palette {
+    colors: Vec<Rgb>,
+}
+
+impl Palette {
+    pub fn new(colors: Vec<Rgb>) -> Palette {
+        assert!(!colors.is_empty(), "a palette needs at least one color");
+        Palette { colors }
+    }
+
+    /// `steps` colors evenly spaced from `from` to `to`.
+    pub fn gradient(from: Rgb, to: Rgb, steps: usize) -> Palette {
+        assert!(steps >= 2, "a gradient needs both its ends");
+        Palette::new(
+            (0..steps)
+                .map(|step| from.lerp(to, step as f64 / (steps - 1) as f64))
+                .collect(),
+        )
+    }
+
+    pub fn len(&self) -> usize {
+        self.colors.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.colors.is_empty()
+    }
+
+    /// The color at `index`, or the last one for anything past the end.
+    pub fn color(&self, index: usize) -> Rgb {
+        self.colors[index.min(self.colors.len() - 1)]
+    }
+}
+
+/// The color [`Image::from_frame`] gives a character by default: black for blanks, and a shade
+/// for each tone otherwise, whatever the character.
+pub fn tone_color(c: char, tone: Tone) -> Rgb {
+    if c.is_whitespace() {
+        return Rgb::BLACK;
+    }
+    match tone {
+        Tone::Normal => Rgb::new(0x9a, 0xa0, 0xa6),
+        Tone::Highlight => Rgb::new(0xff, 0xd7, 0x00),
+        Tone::Dim => Rgb::new(0x30, 0x34, 0x38),
+        Tone::Accent => Rgb::new(0x00, 0xbc, 0xd4),
+    }
+}
+
+/// A picture, a pixel at a time.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    pixels: Vec<Rgb>,
+}
+
+impl Image {
+    /// An image filled with `background`.
+    pub fn new(width: usize, height: usize, background: Rgb) -> Image {
+        Image {
+            width,
+            height,
+            pixels: vec![background; width * height],
+        }
+    }
+
+    /// Draws each cell of `grid` as a `scale` pixel square in whatever color `color` gives it.
+    pub fn from_grid<T>(grid: &Grid<T>, scale: usize, mut color: impl FnMut(&T) -> Rgb) -> Image {
+        let mut image = Image::new(grid.width() * scale, grid.height() * scale, Rgb::BLACK);
+        for ((x, y), cell) in grid.indexed() {
+            image.fill_square((x, y), scale, color(cell));
+        }
+        image
+    }
+
+    /// Draws each character of `frame` as a `scale` pixel square, in whatever color `color`
+    /// gives it and its tone. Short lines are padded out in black.
+    pub fn from_frame(
+        frame: &Frame,
+        scale: usize,
+        mut color: impl FnMut(char, Tone) -> Rgb,
+    ) -> Image {
+        let width = frame.cells().map(|((x, _), _, _)| x + 1).max().unwrap_or(0);
+        let mut image = Image::new(width * scale, frame.height() * scale, Rgb::BLACK);
+        for (position, c, tone) in frame.cells() {
+            image.fill_square(position, scale, color(c, tone));
+        }
+        image
+    }
+
+    fn fill_square(&mut self, (x, y): (usize, usize), scale: usize, color: Rgb) {
+        for dy in 0..scale {
+            let start = (y * scale + dy) * self.width + x * scale;
+            self.pixels[start..start + scale].fill(color);
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The pixel at `(x, y)`, if it's in the image.
+    pub fn get(&self, (x, y): (usize, usize)) -> Option<Rgb> {
+        (x < self.width && y < self.height).then(|| self.pixels[y * self.width + x])
+    }
+
+    /// Colors the pixel at `(x, y)`. Panics if it's outside the image.
+    pub fn set(&mut self, (x, y): (usize, usize), color: Rgb) {
+        assert!(
+            x < self.width && y < self.height,
+            "({}, {}) is outside a {}x{} image",
+            x,
+            y,
+            self.width,
+            self.height
+        );
+        self.pixels[y * self.width + x] = color;
+    }
+
+    fn rgb_bytes(&self) -> Vec<u8> {
+        self.pixels
+            .iter()
+            .flat_map(|pixel| [pixel.r, pixel.g, pixel.b])
+            .collect()
+    }
+
+    /// Writes the image as a binary PPM.
+    pub fn write_ppm(&self, mut out: impl Write) -> io::Result<()> {
+        write!(out, "P6\n{} {}\n255\n", self.width, self.height)?;
+        out.write_all(&self.rgb_bytes())
+    }
+
+    /// Writes the image as a PNG.
+    pub fn write_png(&self, out: impl Write) -> io::Result<()> {
+        let mut encoder = png::Encoder::new(out, dimension(self.width)?, dimension(self.height)?);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut writer = encoder.write_header().map_err(io::Error::other)?;
+        writer
+            .write_image_data(&self.rgb_bytes())
+            .map_err(io::Error::other)?;
+        writer.finish().map_err(io::Error::other)
+    }
+
+    /// Saves the image as a PNG or PPM, going by `path`'s extension.
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let path = path.as_ref();
+        let format = extension(path);
+        let out = match format.as_str() {
+            "png" | "ppm" => BufWriter::new(File::create(path)?),
+            _ => return Err(unsupported(path, "`.png` or `.ppm`")),
+        };
+        if format == "png" {
+            self.write_png(out)
+        } else {
+            self.write_ppm(out)
+        }
+    }
+}
+
+fn dimension(pixels: usize) -> io::Result<u32> {
+    u32::try_from(pixels)
+        .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "the image is too big"))
+}
+
+fn extension(path: &Path) -> String {
+    path.extension()
+        .map(|extension| extension.to_string_lossy().to_lowercase())
+        .unwrap_or_default()
+}
+
+fn unsupported(path: &Path, expected: &str) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidInput,
+        format!("expected a {} file, found {}", expected, path.display()),
+    )
+}
+
+/// Writes `frames` as a looping animated GIF, each shown for `delay`. Frames smaller than the
+/// largest are padded out in black. GIFs only have room for 256 colors, so no more than that
+/// may be used across all the frames.
+pub fn write_gif(frames: &[Image], delay: Duration, out: impl Write) -> io::Result<()> {
+    let width = frames.iter().map(Image::width).max().unwrap_or(0);
+    let height = frames.iter().map(Image::height).max().unwrap_or(0);
+    let too_big = || io::Error::new(io::ErrorKind::InvalidInput, "the frames are too big");
+    let gif_width = u16::try_from(width).map_err(|_| too_big())?;
+    let gif_height = u16::try_from(height).map_err(|_| too_big())?;
+
+    // one palette for the whole animation, black first for the padding
+    let mut indices: HashMap<Rgb, u8> = HashMap::new();
+    let mut palette = vec![];
+    for color in std::iter::once(&Rgb::BLACK).chain(frames.iter().flat_map(|f| &f.pixels)) {
+        if !indices.contains_key(color) {
+            let index = u8::try_from(indices.len())
+                .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "more than 256 colors"))?;
+            indices.insert(*color, index);
+            palette.extend([color.r, color.g, color.b]);
+        }
+    }
+
+    let mut encoder =
+        gif::Encoder::new(out, gif_width, gif_height, &palette).map_err(io::Error::other)?;
+    encoder
+        .set_repeat(gif::Repeat::Infinite)
+        .map_err(io::Error::other)?;
+    // GIF delays are in hundredths of a second
+    let delay = u16::try_from(delay.as_millis() / 10).unwrap_or(u16::MAX);
+    for image in frames {
+        let mut buffer = vec![0; width * height];
+        for (y, row) in image.pixels.chunks(image.width.max(1)).enumerate() {
+            for (x, pixel) in row.iter().enumerate() {
+                buffer[y * width + x] = indices[pixel];
+            }
+        }
+        let frame = gif::Frame {
+            width: gif_width,
+            height: gif_height,
+            delay,
+            buffer: buffer.into(),
+            ..gif::Frame::default()
+        };
+        encoder.write_frame(&frame).map_err(io::Error::other)?;
+    }
+    Ok(())
+}
+
+/// Saves `frames` as an animated GIF, as in [`write_gif`].
+pub fn save_gif(frames: &[Image], delay: Duration, path: impl AsRef<Path>) -> io::Result<()> {
+    let path = path.as_ref();
+    if extension(path) != "gif" {
+        return Err(unsupported(path, "`.gif`"));
+    }
+    write_gif(frames, delay, BufWriter::new(File::create(path)?))
+}
+
+/// A vector drawing of points and lines on the integer plane, each point a unit square and each
+/// line running between the centers of the squares at its ends.
+#[derive(Debug, Clone, Default)]
+pub struct Svg {
+    elements: Vec<String>,
+    /// the smallest and largest coordinates drawn on
+    bounds: Option<(Point2, Point2)>,
+}
+
+impl Svg {
+    pub fn new() -> Svg {
+        Svg::default()
+    }
+
+    fn cover(&mut self, point: Point2) {
+        let (min, max) = self.bounds.get_or_insert((point, point));
+        *min = Point2::new(min.x.min(point.x), min.y.min(point.y));
+        *max = Point2::new(max.x.max(point.x), max.y.max(point.y));
+    }
+
+    pub fn point(&mut self, point: Point2, color: Rgb) -> &mut Svg {
+        self.cover(point);
+        self.elements.push(format!(
+            r#"<rect x="{}" y="{}" width="1" height="1" fill="{}"/>"#,
+            point.x, point.y, color
+        ));
+        self
+    }
+
+    pub fn segment(&mut self, segment: &Segment, color: Rgb) -> &mut Svg {
+        self.cover(segment.start);
+        self.cover(segment.end);
+        self.elements.push(format!(
+            r#"<line x1="{}" y1="{}" x2="{}" y2="{}" stroke="{}"/>"#,
+            center(segment.start.x),
+            center(segment.start.y),
+            center(segment.end.x),
+            center(segment.end.y),
+            color
+        ));
+        self
+    }
+
+    pub fn polygon(&mut self, polygon: &Polygon, color: Rgb) -> &mut Svg {
+        let mut points = vec![];
+        for vertex in polygon.vertices() {
+            self.cover(*vertex);
+            points.push(format!("{},{}", center(vertex.x), center(vertex.y)));
+        }
+        self.elements.push(format!(
+            r#"<polygon points="{}" fill="none" stroke="{}"/>"#,
+            points.join(" "),
+            color
+        ));
+        self
+    }
+
+    /// Writes the drawing as an SVG document.
+    pub fn write(&self, mut out: impl Write) -> io::Result<()> {
+        write!(out, "{}", self)
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let path = path.as_ref();
+        if extension(path) != "svg" {
+            return Err(unsupported(path, "`.svg`"));
+        }
+        self.write(BufWriter::new(File::create(path)?))
+    }
+}
+
+/// The middle of the unit square at `coordinate`.
+fn center(coordinate: isize) -> f64 {
+    coordinate as f64 + 0.5
+}
+
+/// The SVG document, on a black background with a unit of margin all round. Overlapping lines
+/// are drawn half transparent so the overlaps stand out.
+impl fmt::Display for Svg {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (min, max) = self.bounds.unwrap_or((Point2::ORIGIN, Point2::ORIGIN));
+        let (x, y) = (min.x - 1, min.y - 1);
+        let (width, height) = (max.x - min.x + 3, max.y - min.y + 3);
+        writeln!(
+            f,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="{} {} {} {}">"#,
+            x, y, width, height
+        )?;
+        writeln!(
+            f,
+            r#"<rect x="{}" y="{}" width="{}" height="{}" fill="{}"/>"#,
+            x,
+            y,
+            width,
+            height,
+            Rgb::BLACK
+        )?;
+        writeln!(
+            f,
+            r#"<g stroke-width="0.4" stroke-linecap="round" stroke-opacity="0.6">"#
+        )?;
+        for element in &self.elements {
+            writeln!(f, "{}", element)?;
+        }
+        writeln!(f, "</g>")?;
+        writeln!(f, "</svg>")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn decode_png(bytes: &[u8]) -> (u32, u32, Vec<u8>) {
+        let decoder = png::Decoder::new(bytes);
+        let mut reader = decoder.read_info().unwrap();
+        let mut pixels = vec![0; reader.output_buffer_size()];
+        let info = reader.next_frame(&mut pixels).unwrap();
+        pixels.truncate(info.buffer_size());
+        (info.width, info.height, pixels)
+    }
+
+    #[test]
+    fn palettes() {
+        let ramp = Palette::gradient(Rgb::BLACK, Rgb::WHITE, 3);
+        assert_eq!(3, ramp.len());
+        assert_eq!(Rgb::new(128, 128, 128), ramp.color(1));
+        assert_eq!(Rgb::WHITE, ramp.color(7));
+        assert_eq!("#ff8000", Rgb::new(255, 128, 0).to_string());
+    }
+
+    #[test]
+    fn rasters() {
+        let grid = Grid::from_rows(vec![vec![0, 1], vec![2, 0]]).unwrap();
+        let palette = Palette::new(vec![Rgb::BLACK, Rgb::new(255, 0, 0), Rgb::WHITE]);
+        let image = Image::from_grid(&grid, 2, |cell| palette.color(*cell));
+        assert_eq!((4, 4), (image.width(), image.height()));
+        assert_eq!(Some(Rgb::new(255, 0, 0)), image.get((3, 1)));
+        assert_eq!(Some(Rgb::WHITE), image.get((1, 2)));
+        assert_eq!(None, image.get((4, 0)));
+
+        let mut ppm = vec![];
+        image.write_ppm(&mut ppm).unwrap();
+        assert!(ppm.starts_with(b"P6\n4 4\n255\n"));
+        assert_eq!(11 + 4 * 4 * 3, ppm.len());
+
+        let mut png = vec![];
+        image.write_png(&mut png).unwrap();
+        let (width, height, pixels) = decode_png(&png);
+        assert_eq!((4, 4), (width, height));
+        assert_eq!(&ppm[11..], &pixels[..]);
+    }
+
+    #[test]
+    fn frames() {
+        let mut frame = Frame::new();
+        frame.push('#', Tone::Highlight);
+        frame.push('.', Tone::Dim);
+        frame.newline();
+        frame.push_line('L', Tone::Normal);
+        let image = Image::from_frame(&frame, 1, tone_color);
+        assert_eq!((2, 2), (image.width(), image.height()));
+        assert_eq!(Some(tone_color('#', Tone::Highlight)), image.get((0, 0)));
+        assert_eq!(Some(Rgb::BLACK), image.get((1, 1)));
+    }
+
+    #[test]
+    fn animation() {
+        let mut small = Image::new(1, 1, Rgb::WHITE);
+        small.set((0, 0), Rgb::new(255, 0, 0));
+        let frames = [Image::new(2, 2, Rgb::WHITE), small];
+        let mut gif = vec![];
+        write_gif(&frames, Duration::from_millis(100), &mut gif).unwrap();
+
+        let mut options = gif::DecodeOptions::new();
+        options.set_color_output(gif::ColorOutput::RGBA);
+        let mut decoder = options.read_info(&gif[..]).unwrap();
+        assert_eq!((2, 2), (decoder.width(), decoder.height()));
+        let first = decoder.read_next_frame().unwrap().unwrap();
+        assert_eq!(10, first.delay);
+        assert_eq!(vec![255; 16], first.buffer.to_vec());
+        let second = decoder.read_next_frame().unwrap().unwrap();
+        // padded out in black
+        assert_eq!(&[255, 0, 0, 255, 0, 0, 0, 255], &second.buffer[..8]);
+        assert!(decoder.read_next_frame().unwrap().is_none());
+
+        let many: Vec<Image> = (0..=256)
+            .map(|shade| Image::new(1, 1, Rgb::new(0, (shade % 256) as u8, (shade / 256) as u8)))
+            .collect();
+        assert!(write_gif(&many, Duration::ZERO, io::sink()).is_err());
+    }
+
+    #[test]
+    fn vectors() {
+        let mut svg = Svg::new();
+        svg.segment(
+            &Segment::new(Point2::new(0, 9), Point2::new(5, 9)),
+            Rgb::WHITE,
+        )
+        .point(Point2::new(-2, 3), Rgb::new(255, 0, 0))
+        .segment(
+            &Segment::new(Point2::new(-2, 0), Point2::new(-1, 1)),
+            Rgb::WHITE,
+        );
+        svg.polygon(
+            &Polygon::new(vec![
+                Point2::new(0, 0),
+                Point2::new(2, 0),
+                Point2::new(2, 2),
+            ]),
+            Rgb::WHITE,
+        );
+        let document = svg.to_string();
+        assert!(document
+            .starts_with(r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="-3 -1 10 12">"#));
+        assert!(
+            document.contains(r##"<line x1="0.5" y1="9.5" x2="5.5" y2="9.5" stroke="#ffffff"/>"##)
+        );
+        assert!(document.contains(r##"<rect x="-2" y="3" width="1" height="1" fill="#ff0000"/>"##));
+        assert!(document.contains(r#"x1="-1.5" y1="0.5" x2="-0.5" y2="1.5""#));
+        assert!(document.contains(r#"points="0.5,0.5 2.5,0.5 2.5,2.5""#));
+        assert!(document.ends_with("</svg>\n"));
+
+        assert!(svg.save("vents.png").is_err());
+        assert!(Image::new(1, 1, Rgb::BLACK).save("image.gif").is_err());
+    }
+}
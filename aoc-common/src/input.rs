@@ -0,0 +1,139 @@
+//! Personal puzzle inputs, read at runtime from `<input dir>/<year>/day_DD.txt`.
+//!
+//! The input directory is, in order of preference:
+//! 1. the `AOC_INPUT_DIR` environment variable,
+//! 2. `input_dir` in the nearest `aoc.toml` found walking up from the working directory
+//!    (relative paths are relative to that file),
+//! 3. `inputs` in the working directory.
+
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Environment variable overriding the input directory.
+pub const INPUT_DIR_ENV: &str = "AOC_INPUT_DIR";
+/// Name of the workspace configuration file.
+pub const CONFIG_FILE: &str = "aoc.toml";
+
+/// The directory holding one sub-directory of inputs per year.
+pub fn input_dir() -> PathBuf {
+    if let Some(dir) = std::env::var_os(INPUT_DIR_ENV) {
+        return PathBuf::from(dir);
+    }
+
+    if let Some(dir) = config_path("input_dir") {
+        return dir;
+    }
+
+    PathBuf::from("inputs")
+}
+
+/// Where the input for the given day lives, whether or not it exists.
+pub fn input_path(year: u16, day: u8) -> PathBuf {
+    input_dir()
+        .join(year.to_string())
+        .join(format!("day_{:02}.txt", day))
+}
+
+/// Reads the input for the given day. The error names the path that was tried.
+pub fn load(year: u16, day: u8) -> io::Result<String> {
+    let path = input_path(year, day);
+    std::fs::read_to_string(&path)
+        .map_err(|e| io::Error::new(e.kind(), format!("{}: {}", path.display(), e)))
+}
+
+/// Loads the personal input for a day inside a test, returning early (and saying so) when the
+/// file isn't there rather than failing.
+///
+/// ```ignore
+/// #[test]
+/// fn test_part_one() {
+///     let input = aoc_common::personal_input!(crate::YEAR, 4);
+///     assert_eq!(55_770, part_one(&parse(&input)));
+/// }
+/// ```
+#[macro_export]
+macro_rules! personal_input {
+    ($year:expr, $day:expr) => {
+        match $crate::input::load($year, $day) {
+            Ok(input) => input,
+            Err(e) => {
+                $crate::input::report_skip(&e);
+                return;
+            }
+        }
+    };
+}
+
+/// Used by [`personal_input!`]. Writes straight to stderr, since the test harness swallows
+/// `eprintln!` output from passing tests.
+#[doc(hidden)]
+pub fn report_skip(error: &io::Error) {
+    report_skip_reason(&format!("no personal input ({})", error));
+}
+
+/// Tells whoever is running the tests that one of them did nothing, and why.
+pub(crate) fn report_skip_reason(reason: &str) {
+    use std::io::Write;
+    let _ = writeln!(io::stderr(), "skipped: {}", reason);
+}
+
+/// Looks up a path-valued `key` in the nearest config file, resolved against its directory.
+pub fn config_path(key: &str) -> Option<PathBuf> {
+    let config = find_config()?;
+    let contents = std::fs::read_to_string(&config).ok()?;
+    let value = config_value(&contents, key)?;
+    let base = config.parent().unwrap_or_else(|| Path::new("."));
+    Some(base.join(value))
+}
+
+fn find_config() -> Option<PathBuf> {
+    let cwd = std::env::current_dir().ok()?;
+    cwd.ancestors()
+        .map(|dir| dir.join(CONFIG_FILE))
+        .find(|path| path.is_file())
+}
+
+/// Pulls `key = "value"` out of a config file. Only the flat, string-valued subset of TOML we
+/// actually use is understood.
+fn config_value(contents: &str, key: &str) -> Option<String> {
+    for line in contents.lines() {
+        let line = line.split('#').next().unwrap_or("").trim();
+        let Some((name, value)) = line.split_once('=') else {
+            continue;
+        };
+        if name.trim() != key {
+            continue;
+        }
+
+        let value = value.trim();
+        let value = value
+            .strip_prefix('"')
+            .and_then(|v| v.strip_suffix('"'))
+            .unwrap_or(value);
+        return Some(value.to_string());
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn reads_config_values() {
+        let config = "# where our inputs live\ninput_dir = \"../my-inputs\"  # not checked in\nother=plain\n";
+        assert_eq!(
+            Some("../my-inputs".to_string()),
+            config_value(config, "input_dir")
+        );
+        assert_eq!(Some("plain".to_string()), config_value(config, "other"));
+        assert_eq!(None, config_value(config, "missing"));
+    }
+
+    #[test]
+    fn input_paths() {
+        let path = input_path(2021, 4);
+        assert!(path.ends_with("2021/day_04.txt"));
+    }
+}
@@ -0,0 +1,34 @@
+//! Shared plumbing for the yearly Advent of Code crates.
+
+// lets the code `FromLine` generates name this crate from inside it too
+extern crate self as aoc_common;
+
+pub mod answer;
+pub mod answers;
+pub mod automaton;
+pub mod error;
+pub mod examples;
+pub mod geometry;
+pub mod graph;
+pub mod grid;
+pub mod image;
+pub mod input;
+pub mod logging;
+pub mod neighbors;
+pub mod ocr;
+pub mod parse;
+pub mod point;
+pub mod render;
+pub mod search;
+pub mod simulation;
+pub mod solution;
+pub mod sparse_grid;
+pub mod transform;
+
+pub use answer::Answer;
+pub use aoc_derive::FromLine;
+pub use error::{AocError, AocResult};
+pub use grid::Grid;
+pub use point::{Point2, Point3, PointN};
+pub use solution::{DynSolution, Entry, Part, Registry, Solution};
+pub use sparse_grid::SparseGrid;
@@ -0,0 +1,166 @@
+//! Leveled logging for the solvers.
+//!
+//! Solvers log through the `log` macros without naming a target, so every message is tagged with
+//! its module path: `aoc_2021::day_13`, `aoc_2020::day_06` and so on. A [`Filter`] then picks
+//! what to show, either everywhere or per year or day:
+//!
+//! ```text
+//! quiet                      nothing (the default)
+//! info                       progress and summaries from every day
+//! trace                      every step of every day
+//! info,aoc_2021::day_13=trace
+//! ```
+//!
+//! The runner takes the filter from `--log`; tests read it from the `AOC_LOG` environment
+//! variable.
+
+use std::str::FromStr;
+use std::sync::OnceLock;
+
+use log::{LevelFilter, Log, Metadata, Record};
+
+/// Environment variable holding the filter for tests.
+pub const LOG_ENV: &str = "AOC_LOG";
+
+/// Which messages to show: a default level, overridden for targets starting with a prefix.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Filter {
+    default: LevelFilter,
+    /// Most specific (longest) prefix first.
+    targets: Vec<(String, LevelFilter)>,
+}
+
+impl Filter {
+    /// Shows nothing at all.
+    pub fn quiet() -> Filter {
+        Filter {
+            default: LevelFilter::Off,
+            targets: vec![],
+        }
+    }
+
+    /// The level messages from `target` have to reach to be shown.
+    pub fn level_for(&self, target: &str) -> LevelFilter {
+        self.targets
+            .iter()
+            .find(|(prefix, _)| {
+                target
+                    .strip_prefix(prefix.as_str())
+                    .is_some_and(|rest| rest.is_empty() || rest.starts_with("::"))
+            })
+            .map_or(self.default, |(_, level)| *level)
+    }
+
+    /// The most verbose level any target can log at.
+    pub fn max_level(&self) -> LevelFilter {
+        self.targets
+            .iter()
+            .map(|(_, level)| *level)
+            .fold(self.default, Ord::max)
+    }
+}
+
+impl Default for Filter {
+    fn default() -> Filter {
+        Filter::quiet()
+    }
+}
+
+fn parse_level(level: &str) -> Result<LevelFilter, String> {
+    match level.trim().to_ascii_lowercase().as_str() {
+        "quiet" => Ok(LevelFilter::Off),
+        other => other
+            .parse()
+            .map_err(|_| format!("unknown log level `{}`", level.trim())),
+    }
+}
+
+impl FromStr for Filter {
+    type Err = String;
+
+    fn from_str(spec: &str) -> Result<Filter, String> {
+        let mut filter = Filter::quiet();
+        for part in spec.split(',').filter(|part| !part.trim().is_empty()) {
+            match part.split_once('=') {
+                Some((target, level)) => filter
+                    .targets
+                    .push((target.trim().to_string(), parse_level(level)?)),
+                None => filter.default = parse_level(part)?,
+            }
+        }
+        filter
+            .targets
+            .sort_by_key(|(prefix, _)| std::cmp::Reverse(prefix.len()));
+        Ok(filter)
+    }
+}
+
+struct Logger {
+    filter: Filter,
+}
+
+impl Log for Logger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= self.filter.level_for(metadata.target())
+    }
+
+    fn log(&self, record: &Record) {
+        if self.enabled(record.metadata()) {
+            // `eprintln!` rather than writing to stderr directly, so tests capture it
+            eprintln!(
+                "{:<5} {}: {}",
+                record.level(),
+                record.target(),
+                record.args()
+            );
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+static LOGGER: OnceLock<Logger> = OnceLock::new();
+
+/// Installs the logger. Only the first call in a process has any effect.
+pub fn init(filter: Filter) {
+    let logger = LOGGER.get_or_init(|| Logger { filter });
+    // fails only if some other logger got there first, in which case that one wins
+    if log::set_logger(logger).is_ok() {
+        log::set_max_level(logger.filter.max_level());
+    }
+}
+
+/// Installs the logger with the filter from [`LOG_ENV`], staying quiet when it's unset or
+/// invalid.
+pub fn init_from_env() {
+    let filter = std::env::var(LOG_ENV)
+        .ok()
+        .and_then(|spec| spec.parse().ok())
+        .unwrap_or_default();
+    init(filter);
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parses_filters() {
+        assert_eq!(Filter::quiet(), "quiet".parse().unwrap());
+        assert_eq!(Filter::quiet(), "".parse().unwrap());
+
+        let filter: Filter = "info,aoc_2021=debug,aoc_2021::day_13=trace"
+            .parse()
+            .unwrap();
+        assert_eq!(LevelFilter::Trace, filter.level_for("aoc_2021::day_13"));
+        assert_eq!(LevelFilter::Debug, filter.level_for("aoc_2021::day_14"));
+        assert_eq!(LevelFilter::Debug, filter.level_for("aoc_2021"));
+        assert_eq!(LevelFilter::Info, filter.level_for("aoc_2020::day_01"));
+        // prefixes only match whole path segments
+        assert_eq!(LevelFilter::Info, filter.level_for("aoc_2021_extra"));
+        assert_eq!(LevelFilter::Trace, filter.max_level());
+
+        assert!("loud".parse::<Filter>().is_err());
+        assert!("aoc_2021=loud".parse::<Filter>().is_err());
+    }
+}
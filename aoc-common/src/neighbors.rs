@@ -0,0 +1,210 @@
+//! Which cells are next to which: [`Adjacency`] for the shape of a neighborhood, and [`Edge`] for
+//! what happens to the neighbors of a cell on the edge of a [`Grid`].
+
+use crate::grid::Grid;
+use crate::sparse_grid::Point;
+
+/// The shape of a cell's neighborhood.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Adjacency {
+    /// The four cells sharing a side: up, right, down and left.
+    Orthogonal,
+    /// The four cells sharing only a corner.
+    Diagonal,
+    /// All eight cells around, sides and corners both.
+    Surrounding,
+    /// The six cells around a hexagon, in axial coordinates: `x` runs east and `y` south-east.
+    Hex,
+}
+
+const ORTHOGONAL: [Point; 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+const DIAGONAL: [Point; 4] = [(-1, -1), (1, -1), (1, 1), (-1, 1)];
+const SURROUNDING: [Point; 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+const HEX: [Point; 6] = [(1, 0), (1, -1), (0, -1), (-1, 0), (-1, 1), (0, 1)];
+
+impl Adjacency {
+    /// The steps from a cell to each of its neighbors.
+    pub fn offsets(self) -> &'static [Point] {
+        match self {
+            Adjacency::Orthogonal => &ORTHOGONAL,
+            Adjacency::Diagonal => &DIAGONAL,
+            Adjacency::Surrounding => &SURROUNDING,
+            Adjacency::Hex => &HEX,
+        }
+    }
+
+    /// The neighbors of `point` on an unbounded plane.
+    pub fn around(self, (x, y): Point) -> impl Iterator<Item = Point> {
+        self.offsets().iter().map(move |(dx, dy)| (x + dx, y + dy))
+    }
+
+    /// The steps from a cell to each of its neighbors in `N` dimensions: one coordinate off by
+    /// one for [`Orthogonal`](Adjacency::Orthogonal), every coordinate for
+    /// [`Diagonal`](Adjacency::Diagonal), any of them for [`Surrounding`](Adjacency::Surrounding).
+    ///
+    /// Panics for [`Hex`](Adjacency::Hex), which only tiles a plane.
+    pub fn offsets_nd<const N: usize>(self) -> Vec<[isize; N]> {
+        assert!(
+            self != Adjacency::Hex,
+            "hex adjacency only works in two dimensions"
+        );
+        let mut offsets = vec![];
+        // count through every combination of -1, 0 and 1 in base 3
+        for index in 0..3_usize.pow(N as u32) {
+            let mut offset = [0; N];
+            let mut rest = index;
+            for coord in offset.iter_mut() {
+                *coord = (rest % 3) as isize - 1;
+                rest /= 3;
+            }
+            let moved = offset.iter().filter(|coord| **coord != 0).count();
+            let keep = match self {
+                Adjacency::Orthogonal => moved == 1,
+                Adjacency::Diagonal => moved == N,
+                _ => moved > 0,
+            };
+            if keep {
+                offsets.push(offset);
+            }
+        }
+        offsets
+    }
+
+    /// The neighbors of `point` in unbounded `N`-dimensional space; see
+    /// [`offsets_nd`](Adjacency::offsets_nd). Having no edges, the space has no [`Edge`] policy
+    /// either, so a bounded space has to drop the neighbors outside it itself.
+    pub fn around_nd<const N: usize>(self, point: [isize; N]) -> impl Iterator<Item = [isize; N]> {
+        self.offsets_nd::<N>().into_iter().map(move |offset| {
+            let mut neighbor = point;
+            for (coord, delta) in neighbor.iter_mut().zip(offset) {
+                *coord += delta;
+            }
+            neighbor
+        })
+    }
+}
+
+/// What a cell on the edge of a [`Grid`] has for neighbors past the edge.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Edge<T> {
+    /// Nothing: they're left out.
+    Clip,
+    /// The cells on the far side, as if the grid repeated forever.
+    Wrap,
+    /// This value, as if the grid were surrounded by it.
+    Outside(T),
+}
+
+impl<T> Grid<T> {
+    /// The neighbors of `position`, with those past the edge handled as `edge` says. Each comes
+    /// with its position, or `None` for an [`Edge::Outside`] stand-in.
+    pub fn neighbors<'a>(
+        &'a self,
+        (x, y): (usize, usize),
+        adjacency: Adjacency,
+        edge: &'a Edge<T>,
+    ) -> impl Iterator<Item = (Option<(usize, usize)>, &'a T)> {
+        let (width, height) = (self.width() as isize, self.height() as isize);
+        adjacency.offsets().iter().filter_map(move |(dx, dy)| {
+            let (nx, ny) = (x as isize + dx, y as isize + dy);
+            let position = if (0..width).contains(&nx) && (0..height).contains(&ny) {
+                (nx as usize, ny as usize)
+            } else {
+                match edge {
+                    Edge::Clip => return None,
+                    Edge::Wrap => (
+                        nx.rem_euclid(width) as usize,
+                        ny.rem_euclid(height) as usize,
+                    ),
+                    Edge::Outside(value) => return Some((None, value)),
+                }
+            };
+            Some((Some(position), &self[position]))
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn digits() -> Grid<u32> {
+        Grid::parse("123\n456\n789\n", "a digit", |c| c.to_digit(10)).unwrap()
+    }
+
+    fn values<'a>(neighbors: impl Iterator<Item = (Option<(usize, usize)>, &'a u32)>) -> Vec<u32> {
+        let mut values: Vec<u32> = neighbors.map(|(_, value)| *value).collect();
+        values.sort();
+        values
+    }
+
+    #[test]
+    fn edge_policies() {
+        let grid = digits();
+        let corner = (0, 0);
+        assert_eq!(
+            vec![2, 4],
+            values(grid.neighbors(corner, Adjacency::Orthogonal, &Edge::Clip))
+        );
+        assert_eq!(
+            vec![2, 3, 4, 7],
+            values(grid.neighbors(corner, Adjacency::Orthogonal, &Edge::Wrap))
+        );
+        assert_eq!(
+            vec![0, 0, 0, 0, 0, 2, 4, 5],
+            values(grid.neighbors(corner, Adjacency::Surrounding, &Edge::Outside(0)))
+        );
+        assert_eq!(
+            vec![5],
+            values(grid.neighbors(corner, Adjacency::Diagonal, &Edge::Clip))
+        );
+        assert_eq!(
+            vec![Some((1, 0)), Some((0, 1))],
+            grid.neighbors(corner, Adjacency::Orthogonal, &Edge::Clip)
+                .map(|(position, _)| position)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            8,
+            grid.neighbors((1, 1), Adjacency::Surrounding, &Edge::Wrap)
+                .count()
+        );
+    }
+
+    #[test]
+    fn hex_and_nd() {
+        let mut hex: Vec<Point> = Adjacency::Hex.around((0, 0)).collect();
+        hex.sort();
+        assert_eq!(
+            vec![(-1, 0), (-1, 1), (0, -1), (0, 1), (1, -1), (1, 0)],
+            hex
+        );
+
+        assert_eq!(26, Adjacency::Surrounding.offsets_nd::<3>().len());
+        assert_eq!(80, Adjacency::Surrounding.offsets_nd::<4>().len());
+        assert_eq!(6, Adjacency::Orthogonal.offsets_nd::<3>().len());
+        assert_eq!(8, Adjacency::Diagonal.offsets_nd::<3>().len());
+        assert!(Adjacency::Surrounding
+            .around_nd([1, 2, 3])
+            .any(|point| point == [2, 2, 2]));
+
+        let mut planar: Vec<Point> = Adjacency::Surrounding
+            .offsets_nd::<2>()
+            .into_iter()
+            .map(|[x, y]| (x, y))
+            .collect();
+        planar.sort();
+        let mut surrounding = Adjacency::Surrounding.offsets().to_vec();
+        surrounding.sort();
+        assert_eq!(surrounding, planar);
+    }
+}
@@ -0,0 +1,328 @@
+//! Reading the block capitals some puzzles draw their answers in.
+//!
+//! Advent of Code draws letters in one of two fonts, 4x6 or 6x10 pixels, with at least one
+//! blank column between them. [`read`] picks the font from the height of what's lit, splits it
+//! into letters at the blank columns and looks each one up. Anything it can't make out is
+//! reported as an error at the letter's position; [`recognize`] gives every letter, known or not.
+
+use crate::error::{AocError, AocResult};
+use crate::grid::Grid;
+use crate::sparse_grid::SparseGrid;
+
+/// A set of letters, all drawn the same height.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Font {
+    pub name: &'static str,
+    /// How many pixels tall every letter is.
+    pub height: usize,
+    /// Each letter and its drawing, a row to a line.
+    glyphs: &'static [(char, &'static str)],
+}
+
+impl Font {
+    /// The usual 4x6 font.
+    pub const SMALL: Font = Font {
+        name: "4x6",
+        height: 6,
+        glyphs: &[
+            ('A', ".##.\n#..#\n#..#\n####\n#..#\n#..#"),
+            ('B', "###.\n#..#\n###.\n#..#\n#..#\n###."),
+            ('C', ".##.\n#..#\n#...\n#...\n#..#\n.##."),
+            ('E', "####\n#...\n###.\n#...\n#...\n####"),
+            ('F', "####\n#...\n###.\n#...\n#...\n#..."),
+            ('G', ".##.\n#..#\n#...\n#.##\n#..#\n.###"),
+            ('H', "#..#\n#..#\n####\n#..#\n#..#\n#..#"),
+            ('I', ".###\n..#.\n..#.\n..#.\n..#.\n.###"),
+            ('J', "..##\n...#\n...#\n...#\n#..#\n.##."),
+            ('K', "#..#\n#.#.\n##..\n#.#.\n#.#.\n#..#"),
+            ('L', "#...\n#...\n#...\n#...\n#...\n####"),
+            ('O', ".##.\n#..#\n#..#\n#..#\n#..#\n.##."),
+            ('P', "###.\n#..#\n#..#\n###.\n#...\n#..."),
+            ('R', "###.\n#..#\n#..#\n###.\n#.#.\n#..#"),
+            ('S', ".###\n#...\n#...\n.##.\n...#\n###."),
+            ('U', "#..#\n#..#\n#..#\n#..#\n#..#\n.##."),
+            ('Z', "####\n...#\n..#.\n.#..\n#...\n####"),
+        ],
+    };
+
+    /// The larger 6x10 font.
+    pub const LARGE: Font = Font {
+        name: "6x10",
+        height: 10,
+        glyphs: &[
+            (
+                'A',
+                "..##..\n.#..#.\n#....#\n#....#\n#....#\n######\n#....#\n#....#\n#....#\n#....#",
+            ),
+            (
+                'B',
+                "#####.\n#....#\n#....#\n#....#\n#####.\n#....#\n#....#\n#....#\n#....#\n#####.",
+            ),
+            (
+                'C',
+                ".####.\n#....#\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#....#\n.####.",
+            ),
+            (
+                'E',
+                "######\n#.....\n#.....\n#.....\n#####.\n#.....\n#.....\n#.....\n#.....\n######",
+            ),
+            (
+                'F',
+                "######\n#.....\n#.....\n#.....\n#####.\n#.....\n#.....\n#.....\n#.....\n#.....",
+            ),
+            (
+                'G',
+                ".####.\n#....#\n#.....\n#.....\n#.....\n#..###\n#....#\n#....#\n#...##\n.###.#",
+            ),
+            (
+                'H',
+                "#....#\n#....#\n#....#\n#....#\n######\n#....#\n#....#\n#....#\n#....#\n#....#",
+            ),
+            (
+                'J',
+                "...###\n....#.\n....#.\n....#.\n....#.\n....#.\n....#.\n#...#.\n#...#.\n.###..",
+            ),
+            (
+                'K',
+                "#....#\n#...#.\n#..#..\n#.#...\n##....\n##....\n#.#...\n#..#..\n#...#.\n#....#",
+            ),
+            (
+                'L',
+                "#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n######",
+            ),
+            (
+                'N',
+                "#....#\n##...#\n##...#\n#.#..#\n#.#..#\n#..#.#\n#..#.#\n#...##\n#...##\n#....#",
+            ),
+            (
+                'P',
+                "#####.\n#....#\n#....#\n#....#\n#####.\n#.....\n#.....\n#.....\n#.....\n#.....",
+            ),
+            (
+                'R',
+                "#####.\n#....#\n#....#\n#....#\n#####.\n#..#..\n#...#.\n#...#.\n#....#\n#....#",
+            ),
+            (
+                'X',
+                "#....#\n#....#\n.#..#.\n.#..#.\n..##..\n..##..\n.#..#.\n.#..#.\n#....#\n#....#",
+            ),
+            (
+                'Z',
+                "######\n.....#\n.....#\n....#.\n...#..\n..#...\n.#....\n#.....\n#.....\n######",
+            ),
+        ],
+    };
+
+    pub const ALL: [Font; 2] = [Font::SMALL, Font::LARGE];
+
+    /// The font whose letters are `height` pixels tall.
+    pub fn for_height(height: usize) -> Option<Font> {
+        Font::ALL.into_iter().find(|font| font.height == height)
+    }
+
+    /// The letter drawn as `drawing`, a row to a line with `#` for lit pixels, ignoring any
+    /// blank columns either side.
+    pub fn letter(&self, drawing: &str) -> Option<char> {
+        let drawing = trim_columns(drawing);
+        self.glyphs
+            .iter()
+            .find(|(_, glyph)| trim_columns(glyph) == drawing)
+            .map(|(letter, _)| *letter)
+    }
+}
+
+/// `drawing` without the columns on either side that have nothing lit.
+fn trim_columns(drawing: &str) -> String {
+    let rows: Vec<&str> = drawing.lines().collect();
+    let width = rows.iter().map(|row| row.len()).max().unwrap_or(0);
+    let lit = |x: usize| rows.iter().any(|row| row.as_bytes().get(x) == Some(&b'#'));
+    let Some(first) = (0..width).find(|x| lit(*x)) else {
+        return String::new();
+    };
+    let last = (0..width).rfind(|x| lit(*x)).unwrap_or(first);
+    let trimmed: Vec<String> = rows
+        .iter()
+        .map(|row| format!("{:.<w$}", row, w = last + 1)[first..=last].to_string())
+        .collect();
+    trimmed.join("\n")
+}
+
+/// A letter picked out of a drawing.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Glyph {
+    /// Where the top left corner of the letter is.
+    pub position: (isize, isize),
+    /// What it says, if it's a letter the font knows.
+    pub letter: Option<char>,
+    /// The letter's pixels, a row to a line with `#` for lit pixels.
+    pub drawing: String,
+}
+
+/// Splits what's lit in `lit` into letters and reads each one, in the font as tall as they are.
+/// Positions are offset by `origin`, the position of the top left of `lit`.
+fn recognize_at(lit: &Grid<bool>, origin: (isize, isize)) -> AocResult<Vec<Glyph>> {
+    let lit_rows: Vec<usize> = (0..lit.height())
+        .filter(|y| lit.row(*y).iter().any(|lit| *lit))
+        .collect();
+    let (Some(&top), Some(&bottom)) = (lit_rows.first(), lit_rows.last()) else {
+        return Err(AocError::new("there are no letters to read"));
+    };
+    let font = Font::for_height(bottom - top + 1).ok_or_else(|| {
+        AocError::new(format!(
+            "expected letters 6 or 10 pixels tall, found {}",
+            bottom - top + 1
+        ))
+    })?;
+
+    let drawing = |x: usize| -> String {
+        (top..=bottom)
+            .map(|y| if lit[(x, y)] { '#' } else { '.' })
+            .collect()
+    };
+    let is_blank = |x: usize| (top..=bottom).all(|y| !lit[(x, y)]);
+
+    let mut glyphs = vec![];
+    let mut x = 0;
+    while x < lit.width() {
+        if is_blank(x) {
+            x += 1;
+            continue;
+        }
+        let start = x;
+        while x < lit.width() && !is_blank(x) {
+            x += 1;
+        }
+        let columns: Vec<String> = (start..x).map(drawing).collect();
+        let drawing = (0..font.height)
+            .map(|row| {
+                columns
+                    .iter()
+                    .map(|column| column.as_bytes()[row] as char)
+                    .collect::<String>()
+            })
+            .collect::<Vec<String>>()
+            .join("\n");
+        glyphs.push(Glyph {
+            position: (origin.0 + start as isize, origin.1 + top as isize),
+            letter: font.letter(&drawing),
+            drawing,
+        });
+    }
+    Ok(glyphs)
+}
+
+/// Every letter drawn in `lit`, left to right, whether or not it could be read.
+pub fn recognize(lit: &Grid<bool>) -> AocResult<Vec<Glyph>> {
+    recognize_at(lit, (0, 0))
+}
+
+/// The text in `glyphs`, or an error at the first one that isn't a letter. Grids are numbered
+/// from 1 in errors, like the lines and columns of an input.
+fn spell(glyphs: Vec<Glyph>, from: isize) -> AocResult<String> {
+    glyphs
+        .into_iter()
+        .map(|glyph| {
+            glyph.letter.ok_or_else(|| AocError {
+                line: Some((glyph.position.1 + from) as usize),
+                column: Some((glyph.position.0 + from) as usize),
+                text: None,
+                reason: format!("unrecognized letter:\n{}", glyph.drawing),
+            })
+        })
+        .collect()
+}
+
+/// The text drawn in `lit`.
+pub fn read(lit: &Grid<bool>) -> AocResult<String> {
+    spell(recognize(lit)?, 1)
+}
+
+/// The text drawn by the points of `points`. Errors give the point at the top left of the
+/// letter as the line and column, as is, rather than numbering from 1.
+pub fn read_sparse<T>(points: &SparseGrid<T>) -> AocResult<String> {
+    let bounds = points
+        .bounds()
+        .ok_or_else(|| AocError::new("there are no letters to read"))?;
+    let lit = Grid::from_fn(bounds.width(), bounds.height(), |(x, y)| {
+        points.contains((bounds.min.0 + x as isize, bounds.min.1 + y as isize))
+    });
+    spell(recognize_at(&lit, bounds.min)?, 0)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Lays `letters` of `font` out in a row, a blank column between each.
+    fn write(font: &Font, letters: &str) -> Grid<bool> {
+        let glyphs: Vec<Vec<&str>> = letters
+            .chars()
+            .map(|letter| {
+                let (_, glyph) = font.glyphs.iter().find(|(l, _)| *l == letter).unwrap();
+                glyph.lines().collect()
+            })
+            .collect();
+        let rows: Vec<String> = (0..font.height)
+            .map(|row| {
+                glyphs
+                    .iter()
+                    .map(|glyph| glyph[row])
+                    .collect::<Vec<&str>>()
+                    .join(".")
+            })
+            .collect();
+        Grid::parse(&rows.join("\n"), "a pixel", |c| Some(c == '#')).unwrap()
+    }
+
+    #[test]
+    fn fonts() {
+        for font in Font::ALL {
+            let letters: String = font.glyphs.iter().map(|(letter, _)| *letter).collect();
+            for (letter, glyph) in font.glyphs {
+                assert_eq!(font.height, glyph.lines().count(), "{}", letter);
+            }
+            assert_eq!(Ok(letters.clone()), read(&write(&font, &letters)));
+        }
+        assert_eq!(
+            Some('I'),
+            Font::SMALL.letter("###\n.#.\n.#.\n.#.\n.#.\n###")
+        );
+    }
+
+    #[test]
+    fn dense() {
+        // the 2021 day 13 example: a square, which isn't a letter
+        let square = Grid::parse(
+            "#####\n#...#\n#...#\n#...#\n#####\n.....\n.....\n",
+            "a pixel",
+            |c| Some(c == '#'),
+        )
+        .unwrap();
+        assert!(read(&square).unwrap_err().reason.ends_with("tall, found 5"));
+
+        let mut text = write(&Font::SMALL, "HI");
+        text[(6, 0)] = false;
+        let err = read(&text).unwrap_err();
+        assert_eq!((Some(1), Some(7)), (err.line, err.column));
+        assert!(err.reason.ends_with("\n.##\n.#.\n.#.\n.#.\n.#.\n###"));
+
+        let glyphs = recognize(&text).unwrap();
+        assert_eq!(Some('H'), glyphs[0].letter);
+        assert_eq!(None, glyphs[1].letter);
+    }
+
+    #[test]
+    fn sparse() {
+        let text = write(&Font::LARGE, "NEXA");
+        let mut points = SparseGrid::new();
+        for ((x, y), _) in text.indexed().filter(|(_, lit)| **lit) {
+            points.insert((x as isize - 10, y as isize + 3), ());
+        }
+        assert_eq!(Ok("NEXA".to_string()), read_sparse(&points));
+
+        points.remove((-10, 3));
+        let err = read_sparse(&points).unwrap_err();
+        assert_eq!((Some(3), Some(-10_isize as usize)), (err.line, err.column));
+        assert!(read_sparse(&SparseGrid::<()>::new()).is_err());
+    }
+}
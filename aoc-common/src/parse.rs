@@ -0,0 +1,323 @@
+//! Helpers for the shapes puzzle inputs keep coming in: numbers scattered along a line, comma
+//! separated lists, blank line separated blocks, `key:value` records, `a -> b` rules and grids of
+//! digits.
+//!
+//! Everything hands back slices of the input rather than copies, so a problem found later on can
+//! still be pinned to its spot with [`AocError::at`]; the helpers' own errors already are. They
+//! take the whole `input` alongside the `text` being parsed for that reason, `text` being a slice
+//! of `input` (or `input` itself).
+//!
+//! [`regex`] compiles each pattern once, however often it's asked for.
+//!
+//! Lines that are records of a few fields can instead `#[derive(FromLine)]` (see
+//! [`crate::FromLine`]), and be read with [`line`] or [`lines`].
+
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::str::FromStr;
+use std::sync::{Mutex, OnceLock};
+
+pub use regex::{Captures, Regex};
+
+use crate::error::{parse_at, AocError, AocResult};
+use crate::grid::Grid;
+
+/// The compiled `pattern`, compiled the first time it's asked for and kept for the rest of the
+/// run. Panics if `pattern` isn't a valid regex, since patterns are part of the code.
+pub fn regex(pattern: &str) -> &'static Regex {
+    static CACHE: OnceLock<Mutex<HashMap<String, &'static Regex>>> = OnceLock::new();
+    let mut cache = CACHE
+        .get_or_init(Default::default)
+        .lock()
+        .expect("a thread panicked compiling a regex");
+    if let Some(regex) = cache.get(pattern) {
+        return regex;
+    }
+    let regex = Regex::new(pattern).unwrap_or_else(|e| panic!("bad regex `{}`: {}", pattern, e));
+    let regex: &'static Regex = Box::leak(Box::new(regex));
+    cache.insert(pattern.to_string(), regex);
+    regex
+}
+
+/// Matches `text` against `pattern`, reporting text that doesn't match as not being `what`,
+/// e.g. "`<x>,<y>`". Patterns are usually anchored with `^...$` to match all of `text`.
+pub fn captures<'a>(
+    input: &str,
+    text: &'a str,
+    pattern: &str,
+    what: &str,
+) -> AocResult<Captures<'a>> {
+    regex(pattern)
+        .captures(text)
+        .ok_or_else(|| AocError::at(input, text, format!("expected {}, found `{}`", what, text)))
+}
+
+/// Capture group `index` of `captures`, matched in `line`, parsed as `field`'s type. Failures
+/// name the group and the field and point at the capture.
+pub fn capture<T>(line: &str, captures: &Captures, index: usize, field: &str) -> AocResult<T>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    let Some(capture) = captures.get(index) else {
+        return Err(AocError::at(
+            line,
+            line,
+            format!("capture {} (`{}`) didn't match anything", index, field),
+        ));
+    };
+    capture.as_str().parse().map_err(|e| {
+        AocError::at(
+            line,
+            capture.as_str(),
+            format!(
+                "capture {} (`{}`) `{}`: {}",
+                index,
+                field,
+                capture.as_str(),
+                e
+            ),
+        )
+    })
+}
+
+/// `text`, a slice of `input`, parsed as a `T` that reports errors within the text itself, as
+/// [`crate::FromLine`] types do, with the errors moved to where `text` sits in `input`.
+pub fn line<T: FromStr<Err = AocError>>(input: &str, text: &str) -> AocResult<T> {
+    text.parse().map_err(|e: AocError| e.within(input, text))
+}
+
+/// Each line of `input` with something on it, parsed as by [`line`].
+pub fn lines<T: FromStr<Err = AocError>>(input: &str) -> AocResult<Vec<T>> {
+    input
+        .lines()
+        .filter(|text| !text.trim().is_empty())
+        .map(|text| line(input, text))
+        .collect()
+}
+
+/// Every integer in `text`, whatever lies between them. A `-` straight before the digits makes
+/// one negative, so `1-3` is `1` and `-3`.
+pub fn ints<T: FromStr>(input: &str, text: &str) -> AocResult<Vec<T>> {
+    regex(r"-?\d+")
+        .find_iter(text)
+        .map(|int| parse_at(input, int.as_str(), "an integer that fits"))
+        .collect()
+}
+
+/// The comma separated values of `text`, each parsed as `what`. Whitespace around the values
+/// is ignored.
+pub fn comma_list<T: FromStr>(input: &str, text: &str, what: &str) -> AocResult<Vec<T>> {
+    text.trim()
+        .split(',')
+        .map(|value| parse_at(input, value.trim(), what))
+        .collect()
+}
+
+/// The blocks of lines in `text` separated by blank lines, without the blank lines or the
+/// newline ending each block. Blank lines at either end, or several in a row, don't make empty
+/// blocks.
+pub fn blocks(text: &str) -> impl Iterator<Item = &str> {
+    let mut rest = text;
+    std::iter::from_fn(move || {
+        // skip to the start of the first line with something on it
+        loop {
+            let line_end = rest.find('\n').map_or(rest.len(), |end| end + 1);
+            if line_end == 0 {
+                return None;
+            }
+            if !rest[..line_end].trim().is_empty() {
+                break;
+            }
+            rest = &rest[line_end..];
+        }
+
+        // and on to the end of the last one before a blank line
+        let mut end = 0;
+        while end < rest.len() {
+            let line_end = rest[end..]
+                .find('\n')
+                .map_or(rest.len(), |ind| end + ind + 1);
+            if rest[end..line_end].trim().is_empty() {
+                break;
+            }
+            end = line_end;
+        }
+        let block = rest[..end].trim_end_matches(['\n', '\r']);
+        rest = &rest[end..];
+        Some(block)
+    })
+}
+
+/// The whitespace separated `key:value` fields of `text`, in order.
+pub fn records<'a>(input: &str, text: &'a str) -> AocResult<Vec<(&'a str, &'a str)>> {
+    text.split_whitespace()
+        .map(|field| {
+            field.split_once(':').ok_or_else(|| {
+                AocError::at(
+                    input,
+                    field,
+                    format!("expected `key:value`, found `{}`", field),
+                )
+            })
+        })
+        .collect()
+}
+
+/// The two sides of a rule like `AB -> C`, trimmed.
+pub fn rule<'a>(input: &str, text: &'a str) -> AocResult<(&'a str, &'a str)> {
+    text.split_once("->")
+        .map(|(from, to)| (from.trim(), to.trim()))
+        .ok_or_else(|| AocError::at(input, text, format!("expected `a -> b`, found `{}`", text)))
+}
+
+/// A board of single digits, a row to a line, each digit being `what`, e.g. "a height".
+pub fn digit_grid(input: &str, what: &str) -> AocResult<Grid<u8>> {
+    Grid::parse(input, what, |c| c.to_digit(10).map(|digit| digit as u8))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn cached_regexes() -> AocResult<()> {
+        let first = regex(r"^(\d+),(\d+)$");
+        assert!(std::ptr::eq(first, regex(r"^(\d+),(\d+)$")));
+        assert!(!std::ptr::eq(first, regex(r"^(\d+)-(\d+)$")));
+
+        const POLICY: &str = r"^(\d+)-(\d+) (\w): (\w+)$";
+        let input = "1-3 a: abcde\n1-3 b cdefg\n";
+        let mut lines = input.lines();
+        assert_eq!(
+            "abcde",
+            &captures(input, lines.next().unwrap(), POLICY, "")?[4]
+        );
+        let err = captures(input, lines.next().unwrap(), POLICY, "a policy").unwrap_err();
+        assert_eq!((Some(2), Some(1)), (err.line, err.column));
+        Ok(())
+    }
+
+    #[test]
+    fn numbers() {
+        let input = "target area: x=20..30, y=-10..-5";
+        assert_eq!(Ok(vec![20, 30, -10, -5]), ints::<i32>(input, input));
+
+        let input = "1,2\n300, 4";
+        let line = input.lines().nth(1).unwrap();
+        let err = ints::<u8>(input, line).unwrap_err();
+        assert_eq!((Some(2), Some(1)), (err.line, err.column));
+        assert_eq!(Ok(vec![300, 4]), comma_list::<u16>(input, line, "a number"));
+        assert_eq!(
+            Ok(vec![1, 2]),
+            comma_list::<u8>(input, input.lines().next().unwrap(), "")
+        );
+
+        let err = comma_list::<u8>(input, "3,,4", "a number").unwrap_err();
+        assert_eq!("expected a number, found nothing", err.reason);
+    }
+
+    #[test]
+    fn blocks_of_lines() {
+        let input = "\nabc\nab\n\n\n a \n\r\nb\n\n";
+        let found: Vec<&str> = blocks(input).collect();
+        assert_eq!(vec!["abc\nab", " a ", "b"], found);
+        assert_eq!(0, blocks("\n\n").count());
+        assert_eq!(vec!["one"], blocks("one").collect::<Vec<_>>());
+
+        // still slices of the input, for errors
+        let err = AocError::at(input, found[1], "bad");
+        assert_eq!(Some(6), err.line);
+    }
+
+    #[test]
+    fn key_values() {
+        let input = "ecl:gry pid:860033327\nbyr:1937 iyr2017\n";
+        let mut blocks = blocks(input);
+        let block = blocks.next().unwrap();
+        let err = records(input, block).unwrap_err();
+        assert_eq!((Some(2), Some(10)), (err.line, err.column));
+        assert_eq!(
+            Ok(vec![("ecl", "gry"), ("pid", "860033327")]),
+            records(input, input.lines().next().unwrap())
+        );
+    }
+
+    #[test]
+    fn rules() {
+        assert_eq!(Ok(("CH", "B")), rule("CH -> B", "CH -> B"));
+        let input = "CH -> B\nHH - N\n";
+        let err = rule(input, input.lines().nth(1).unwrap()).unwrap_err();
+        assert_eq!((Some(2), Some(1)), (err.line, err.column));
+    }
+
+    #[derive(Debug, PartialEq, crate::FromLine)]
+    #[line(r"^(\d+)-(\d+) (\w): (\w+)$")]
+    struct Policy {
+        first: usize,
+        second: usize,
+        letter: char,
+        password: String,
+    }
+
+    #[derive(Debug, PartialEq, crate::FromLine)]
+    enum Instruction {
+        #[line(r"^nop")]
+        Nop,
+        #[line(r"^(acc|jmp) (\S+)$", "`<acc|jmp> <argument>`")]
+        Op(String, isize),
+    }
+
+    #[test]
+    fn from_lines() -> AocResult<()> {
+        let policy: Policy = "1-3 a: abcde".parse()?;
+        assert_eq!(
+            Policy {
+                first: 1,
+                second: 3,
+                letter: 'a',
+                password: "abcde".to_string()
+            },
+            policy
+        );
+
+        let input = "1-3 a: abcde\n\n1-3 b cdefg\n";
+        let err = lines::<Policy>(input).unwrap_err();
+        assert_eq!((Some(3), Some(1)), (err.line, err.column));
+        assert!(err.reason.starts_with("expected `^(\\d+)"));
+        let input = "ab\n1-99999999999999999999 b: cdefg";
+        let err = line::<Policy>(input, &input[3..]).unwrap_err();
+        assert_eq!((Some(2), Some(3)), (err.line, err.column));
+        assert_eq!(
+            "capture 2 (`second`) `99999999999999999999`: number too large to fit in target type",
+            err.reason
+        );
+
+        let input = "nop +0\nacc +7\njmp -x\n";
+        assert_eq!(
+            Ok(vec![
+                Instruction::Nop,
+                Instruction::Op("acc".to_string(), 7)
+            ]),
+            lines(&input[..14])
+        );
+        let err = lines::<Instruction>(input).unwrap_err();
+        assert_eq!((Some(3), Some(5)), (err.line, err.column));
+        assert!(err.reason.starts_with("capture 2 (`1`) `-x`"));
+        let err = "mul +3".parse::<Instruction>().unwrap_err();
+        assert_eq!(
+            "expected `^nop` or `<acc|jmp> <argument>`, found `mul +3`",
+            err.reason
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn digits() {
+        let grid = digit_grid("123\n456\n", "a digit").unwrap();
+        assert_eq!(6, grid[(2, 1)]);
+        let err = digit_grid("123\n4x6\n", "a digit").unwrap_err();
+        assert_eq!("expected a digit, found `x`", err.reason);
+        assert_eq!((Some(2), Some(2)), (err.line, err.column));
+    }
+}
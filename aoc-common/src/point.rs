@@ -0,0 +1,429 @@
+//! Integer points, or vectors, in two ([`Point2`]), three ([`Point3`]) or any number
+//! ([`PointN`]) of dimensions.
+//!
+//! They add, subtract, negate and scale like vectors, measure Manhattan and Chebyshev distances,
+//! turn in quarter turns, and parse from comma-separated coordinates like `3,-4`.
+
+use std::fmt;
+use std::ops::{Add, AddAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign};
+use std::str::FromStr;
+
+use crate::error::{parse_at, AocError, AocResult};
+
+/// Parses `span`, a slice of `input` holding `N` comma-separated coordinates, reporting any
+/// problem at its position in `input`.
+fn parse_coords<const N: usize>(input: &str, span: &str) -> AocResult<[isize; N]> {
+    let parts: Vec<&str> = span.split(',').collect();
+    if parts.len() != N {
+        let format = match N {
+            2 => "`x,y`".to_string(),
+            3 => "`x,y,z`".to_string(),
+            _ => format!("{} comma-separated coordinates", N),
+        };
+        return Err(AocError::at(
+            input,
+            span,
+            format!("expected {}, found `{}`", format, span),
+        ));
+    }
+
+    let mut coords = [0; N];
+    for (coord, part) in coords.iter_mut().zip(parts) {
+        *coord = parse_at(input, part.trim(), "a coordinate")?;
+    }
+    Ok(coords)
+}
+
+/// Implements the vector arithmetic, distances and parsing shared by the named point types.
+macro_rules! point {
+    ($name:ident, $n:literal, $($field:ident),+) => {
+        impl $name {
+            pub const ORIGIN: $name = $name { $($field: 0),+ };
+
+            pub const fn new($($field: isize),+) -> $name {
+                $name { $($field),+ }
+            }
+
+            /// The coordinates, in order.
+            pub fn coords(self) -> [isize; $n] {
+                [$(self.$field),+]
+            }
+
+            /// The distance from the origin moving along the axes.
+            pub fn manhattan(self) -> usize {
+                0 $(+ self.$field.unsigned_abs())+
+            }
+
+            /// The distance from the origin moving diagonals included, like a chess king.
+            pub fn chebyshev(self) -> usize {
+                [$(self.$field.unsigned_abs()),+].into_iter().max().unwrap_or(0)
+            }
+
+            pub fn manhattan_to(self, other: $name) -> usize {
+                (other - self).manhattan()
+            }
+
+            pub fn chebyshev_to(self, other: $name) -> usize {
+                (other - self).chebyshev()
+            }
+
+            /// Parses `span`, a slice of `input`, reporting any problem at its position in `input`.
+            pub fn parse_at(input: &str, span: &str) -> AocResult<$name> {
+                let [$($field),+] = parse_coords::<$n>(input, span)?;
+                Ok($name { $($field),+ })
+            }
+        }
+
+        impl Add for $name {
+            type Output = $name;
+
+            fn add(self, rhs: $name) -> $name {
+                $name { $($field: self.$field + rhs.$field),+ }
+            }
+        }
+
+        impl Sub for $name {
+            type Output = $name;
+
+            fn sub(self, rhs: $name) -> $name {
+                $name { $($field: self.$field - rhs.$field),+ }
+            }
+        }
+
+        impl Neg for $name {
+            type Output = $name;
+
+            fn neg(self) -> $name {
+                $name { $($field: -self.$field),+ }
+            }
+        }
+
+        impl Mul<isize> for $name {
+            type Output = $name;
+
+            fn mul(self, rhs: isize) -> $name {
+                $name { $($field: self.$field * rhs),+ }
+            }
+        }
+
+        impl AddAssign for $name {
+            fn add_assign(&mut self, rhs: $name) {
+                *self = *self + rhs;
+            }
+        }
+
+        impl SubAssign for $name {
+            fn sub_assign(&mut self, rhs: $name) {
+                *self = *self - rhs;
+            }
+        }
+
+        impl MulAssign<isize> for $name {
+            fn mul_assign(&mut self, rhs: isize) {
+                *self = *self * rhs;
+            }
+        }
+
+        /// Parses comma-separated coordinates, with errors located within the string itself.
+        impl FromStr for $name {
+            type Err = AocError;
+
+            fn from_str(s: &str) -> AocResult<$name> {
+                $name::parse_at(s, s)
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                let coords: Vec<String> = self.coords().iter().map(isize::to_string).collect();
+                write!(f, "{}", coords.join(","))
+            }
+        }
+
+        impl From<[isize; $n]> for $name {
+            fn from([$($field),+]: [isize; $n]) -> $name {
+                $name { $($field),+ }
+            }
+        }
+
+        impl From<$name> for PointN<$n> {
+            fn from(point: $name) -> PointN<$n> {
+                PointN(point.coords())
+            }
+        }
+    };
+}
+
+/// A point on a plane. Like a [`Grid`](crate::Grid), `y` grows downwards unless a puzzle says
+/// otherwise; it only matters for which way [`rotate_cw`](Point2::rotate_cw) turns.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point2 {
+    pub x: isize,
+    pub y: isize,
+}
+
+point!(Point2, 2, x, y);
+
+impl Point2 {
+    /// Turns `quarter_turns` quarter turns clockwise about the origin, with `y` growing
+    /// downwards: `(1, 0)` turns to `(0, 1)`. Negative turns go anticlockwise.
+    pub fn rotate_cw(self, quarter_turns: isize) -> Point2 {
+        match quarter_turns.rem_euclid(4) {
+            0 => self,
+            1 => Point2::new(-self.y, self.x),
+            2 => -self,
+            _ => Point2::new(self.y, -self.x),
+        }
+    }
+
+    /// Turns `quarter_turns` quarter turns anticlockwise about the origin, with `y` growing
+    /// downwards.
+    pub fn rotate_ccw(self, quarter_turns: isize) -> Point2 {
+        self.rotate_cw(-quarter_turns)
+    }
+}
+
+impl From<(isize, isize)> for Point2 {
+    fn from((x, y): (isize, isize)) -> Point2 {
+        Point2 { x, y }
+    }
+}
+
+impl From<Point2> for (isize, isize) {
+    fn from(point: Point2) -> (isize, isize) {
+        (point.x, point.y)
+    }
+}
+
+/// A point in space.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point3 {
+    pub x: isize,
+    pub y: isize,
+    pub z: isize,
+}
+
+point!(Point3, 3, x, y, z);
+
+impl Point3 {
+    /// Turns `quarter_turns` quarter turns about the `x` axis, `y` towards `z`.
+    pub fn rotate_x(self, quarter_turns: isize) -> Point3 {
+        PointN::from(self).rotate(1, 2, quarter_turns).0.into()
+    }
+
+    /// Turns `quarter_turns` quarter turns about the `y` axis, `z` towards `x`.
+    pub fn rotate_y(self, quarter_turns: isize) -> Point3 {
+        PointN::from(self).rotate(2, 0, quarter_turns).0.into()
+    }
+
+    /// Turns `quarter_turns` quarter turns about the `z` axis, `x` towards `y`.
+    pub fn rotate_z(self, quarter_turns: isize) -> Point3 {
+        PointN::from(self).rotate(0, 1, quarter_turns).0.into()
+    }
+}
+
+impl From<(isize, isize, isize)> for Point3 {
+    fn from((x, y, z): (isize, isize, isize)) -> Point3 {
+        Point3 { x, y, z }
+    }
+}
+
+/// A point in `N` dimensions, for when two or three aren't enough.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct PointN<const N: usize>(pub [isize; N]);
+
+impl<const N: usize> PointN<N> {
+    pub const ORIGIN: PointN<N> = PointN([0; N]);
+
+    /// The distance from the origin moving along the axes.
+    pub fn manhattan(self) -> usize {
+        self.0.iter().map(|coord| coord.unsigned_abs()).sum()
+    }
+
+    /// The distance from the origin moving diagonals included.
+    pub fn chebyshev(self) -> usize {
+        self.0
+            .iter()
+            .map(|coord| coord.unsigned_abs())
+            .max()
+            .unwrap_or(0)
+    }
+
+    pub fn manhattan_to(self, other: PointN<N>) -> usize {
+        (other - self).manhattan()
+    }
+
+    pub fn chebyshev_to(self, other: PointN<N>) -> usize {
+        (other - self).chebyshev()
+    }
+
+    /// Turns `quarter_turns` quarter turns in the plane of axes `from` and `to`, the `from` axis
+    /// towards the `to` one.
+    pub fn rotate(mut self, from: usize, to: usize, quarter_turns: isize) -> PointN<N> {
+        assert!(from != to, "a rotation needs two different axes");
+        for _ in 0..quarter_turns.rem_euclid(4) {
+            (self.0[from], self.0[to]) = (-self.0[to], self.0[from]);
+        }
+        self
+    }
+
+    /// Parses `span`, a slice of `input`, reporting any problem at its position in `input`.
+    pub fn parse_at(input: &str, span: &str) -> AocResult<PointN<N>> {
+        parse_coords(input, span).map(PointN)
+    }
+
+    fn zip_with(mut self, rhs: PointN<N>, f: impl Fn(isize, isize) -> isize) -> PointN<N> {
+        for (coord, other) in self.0.iter_mut().zip(rhs.0) {
+            *coord = f(*coord, other);
+        }
+        self
+    }
+}
+
+impl<const N: usize> Default for PointN<N> {
+    fn default() -> Self {
+        PointN::ORIGIN
+    }
+}
+
+impl<const N: usize> Add for PointN<N> {
+    type Output = PointN<N>;
+
+    fn add(self, rhs: PointN<N>) -> PointN<N> {
+        self.zip_with(rhs, |a, b| a + b)
+    }
+}
+
+impl<const N: usize> Sub for PointN<N> {
+    type Output = PointN<N>;
+
+    fn sub(self, rhs: PointN<N>) -> PointN<N> {
+        self.zip_with(rhs, |a, b| a - b)
+    }
+}
+
+impl<const N: usize> Neg for PointN<N> {
+    type Output = PointN<N>;
+
+    fn neg(self) -> PointN<N> {
+        PointN::ORIGIN - self
+    }
+}
+
+impl<const N: usize> Mul<isize> for PointN<N> {
+    type Output = PointN<N>;
+
+    fn mul(self, rhs: isize) -> PointN<N> {
+        PointN(self.0.map(|coord| coord * rhs))
+    }
+}
+
+impl<const N: usize> AddAssign for PointN<N> {
+    fn add_assign(&mut self, rhs: PointN<N>) {
+        *self = *self + rhs;
+    }
+}
+
+impl<const N: usize> SubAssign for PointN<N> {
+    fn sub_assign(&mut self, rhs: PointN<N>) {
+        *self = *self - rhs;
+    }
+}
+
+impl<const N: usize> MulAssign<isize> for PointN<N> {
+    fn mul_assign(&mut self, rhs: isize) {
+        *self = *self * rhs;
+    }
+}
+
+impl<const N: usize> Index<usize> for PointN<N> {
+    type Output = isize;
+
+    fn index(&self, axis: usize) -> &isize {
+        &self.0[axis]
+    }
+}
+
+impl<const N: usize> IndexMut<usize> for PointN<N> {
+    fn index_mut(&mut self, axis: usize) -> &mut isize {
+        &mut self.0[axis]
+    }
+}
+
+/// Parses comma-separated coordinates, with errors located within the string itself.
+impl<const N: usize> FromStr for PointN<N> {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> AocResult<PointN<N>> {
+        PointN::parse_at(s, s)
+    }
+}
+
+impl<const N: usize> fmt::Display for PointN<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let coords: Vec<String> = self.0.iter().map(isize::to_string).collect();
+        write!(f, "{}", coords.join(","))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn arithmetic_and_distances() {
+        let a = Point2::new(3, -4);
+        let b = Point2::new(-1, 2);
+        assert_eq!(Point2::new(2, -2), a + b);
+        assert_eq!(Point2::new(4, -6), a - b);
+        assert_eq!(Point2::new(-3, 4), -a);
+        assert_eq!(Point2::new(6, -8), a * 2);
+        assert_eq!(7, a.manhattan());
+        assert_eq!(4, a.chebyshev());
+        assert_eq!(10, a.manhattan_to(b));
+        assert_eq!(6, a.chebyshev_to(b));
+
+        let mut c = Point3::new(1, 2, 3);
+        c += Point3::new(1, 1, 1);
+        c *= -1;
+        assert_eq!(Point3::new(-2, -3, -4), c);
+        assert_eq!(9, c.manhattan());
+
+        let d = PointN([1, -2, 3, -4]);
+        assert_eq!(PointN([2, -4, 6, -8]), d + d);
+        assert_eq!(10, d.manhattan());
+        assert_eq!(4, d.chebyshev());
+        assert_eq!(-4, d[3]);
+    }
+
+    #[test]
+    fn rotations() {
+        let p = Point2::new(1, 0);
+        assert_eq!(Point2::new(0, 1), p.rotate_cw(1));
+        assert_eq!(Point2::new(0, -1), p.rotate_ccw(1));
+        assert_eq!(p, p.rotate_cw(4));
+        assert_eq!(p.rotate_cw(3), p.rotate_cw(-1));
+
+        let q = Point3::new(1, 2, 3);
+        assert_eq!(Point3::new(-2, 1, 3), q.rotate_z(1));
+        assert_eq!(Point3::new(1, -3, 2), q.rotate_x(1));
+        assert_eq!(Point3::new(3, 2, -1), q.rotate_y(1));
+        assert_eq!(q, q.rotate_x(1).rotate_x(-1));
+    }
+
+    #[test]
+    fn parsing() {
+        assert_eq!(Point2::new(3, -4), "3,-4".parse().unwrap());
+        assert_eq!(Point3::new(1, 2, 3), "1, 2, 3".parse().unwrap());
+        assert_eq!(PointN([1, 2, 3, 4]), "1,2,3,4".parse().unwrap());
+        assert_eq!("3,-4", Point2::new(3, -4).to_string());
+
+        let err = "1,x".parse::<Point2>().unwrap_err();
+        assert_eq!((Some(1), Some(3)), (err.line, err.column));
+        assert!("1,2,3".parse::<Point2>().is_err());
+
+        let input = "a: 7,8\nb: 9,y";
+        let err = Point2::parse_at(input, &input[10..]).unwrap_err();
+        assert_eq!((Some(2), Some(6)), (err.line, err.column));
+    }
+}
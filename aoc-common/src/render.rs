@@ -0,0 +1,552 @@
+//! Drawing puzzle states in the terminal, and playing a run of them back as an animation.
+//!
+//! A state draws itself as a [`Frame`]: lines of text, each span marked with a [`Tone`] rather
+//! than a hard-coded escape sequence. A [`Theme`] decides what the tones look like when the
+//! frame is painted, down to nothing at all when `NO_COLOR` is set. A [`Player`] redraws a
+//! recorded run of frames in place at a steady frame rate, and takes [`Control`]s to pause,
+//! step and seek through them as it goes.
+
+use std::fmt::{self, Display};
+use std::io::{self, BufRead, Write};
+use std::str::FromStr;
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::time::Duration;
+
+use crate::error::{AocError, AocResult};
+use crate::grid::Grid;
+
+/// How a span of a frame should stand out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Tone {
+    #[default]
+    Normal,
+    /// What the eye should be drawn to: a flash, a marked number, the path taken.
+    Highlight,
+    /// The background everything else stands out from.
+    Dim,
+    /// Something that stands out in a different way from the highlights, like a fold line.
+    Accent,
+}
+
+/// The escape sequences that paint each [`Tone`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Theme {
+    pub name: &'static str,
+    pub normal: &'static str,
+    pub highlight: &'static str,
+    pub dim: &'static str,
+    pub accent: &'static str,
+}
+
+impl Theme {
+    /// Bold and underlined highlights on the terminal's own colors.
+    pub const CLASSIC: Theme = Theme {
+        name: "classic",
+        normal: "",
+        highlight: "\x1b[1;4m",
+        dim: "\x1b[2m",
+        accent: "\x1b[1m",
+    };
+
+    /// Bright colors on grey.
+    pub const VIVID: Theme = Theme {
+        name: "vivid",
+        normal: "",
+        highlight: "\x1b[1;93m",
+        dim: "\x1b[90m",
+        accent: "\x1b[1;96m",
+    };
+
+    /// No escapes at all.
+    pub const PLAIN: Theme = Theme {
+        name: "plain",
+        normal: "",
+        highlight: "",
+        dim: "",
+        accent: "",
+    };
+
+    pub const ALL: [Theme; 3] = [Theme::CLASSIC, Theme::VIVID, Theme::PLAIN];
+
+    /// The theme called `name`.
+    pub fn named(name: &str) -> AocResult<Theme> {
+        Theme::ALL
+            .into_iter()
+            .find(|theme| theme.name == name)
+            .ok_or_else(|| {
+                let names: Vec<&str> = Theme::ALL.iter().map(|theme| theme.name).collect();
+                AocError::new(format!(
+                    "expected a theme ({}), found `{}`",
+                    names.join(", "),
+                    name
+                ))
+            })
+    }
+
+    /// [`Theme::CLASSIC`], unless `NO_COLOR` is set to anything, in which case
+    /// [`Theme::PLAIN`].
+    pub fn from_env() -> Theme {
+        match std::env::var_os("NO_COLOR") {
+            Some(value) if !value.is_empty() => Theme::PLAIN,
+            _ => Theme::CLASSIC,
+        }
+    }
+
+    fn escape(&self, tone: Tone) -> &'static str {
+        match tone {
+            Tone::Normal => self.normal,
+            Tone::Highlight => self.highlight,
+            Tone::Dim => self.dim,
+            Tone::Accent => self.accent,
+        }
+    }
+}
+
+impl FromStr for Theme {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> AocResult<Theme> {
+        Theme::named(s)
+    }
+}
+
+/// A picture of a state: lines of text, made of spans in different tones.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Frame {
+    lines: Vec<Vec<(String, Tone)>>,
+}
+
+impl Frame {
+    pub fn new() -> Frame {
+        Frame::default()
+    }
+
+    /// Draws each cell of `grid` as whatever `draw` makes of it, a row to a line.
+    pub fn from_grid<T, D: Display>(
+        grid: &Grid<T>,
+        mut draw: impl FnMut((usize, usize), &T) -> (D, Tone),
+    ) -> Frame {
+        let mut frame = Frame::new();
+        for (position, cell) in grid.indexed() {
+            let (text, tone) = draw(position, cell);
+            frame.push(text, tone);
+            if position.0 == grid.width() - 1 {
+                frame.newline();
+            }
+        }
+        frame
+    }
+
+    /// Adds `text` to the end of the last line.
+    pub fn push(&mut self, text: impl Display, tone: Tone) {
+        if self.lines.is_empty() {
+            self.lines.push(vec![]);
+        }
+        let line = self.lines.last_mut().expect("there's always a line");
+        match line.last_mut() {
+            // runs of the same tone don't need escaping again
+            Some((span, span_tone)) if *span_tone == tone => span.push_str(&text.to_string()),
+            _ => line.push((text.to_string(), tone)),
+        }
+    }
+
+    /// Ends the last line, so the next push starts a new one.
+    pub fn newline(&mut self) {
+        if self.lines.is_empty() {
+            self.lines.push(vec![]);
+        }
+        self.lines.push(vec![]);
+    }
+
+    /// Adds `text` as a line of its own.
+    pub fn push_line(&mut self, text: impl Display, tone: Tone) {
+        if self.lines.last().is_some_and(|line| !line.is_empty()) {
+            self.newline();
+        }
+        self.push(text, tone);
+        self.newline();
+    }
+
+    /// Adds all of `other` below this frame.
+    pub fn append(&mut self, other: Frame) {
+        if self.lines.last().is_some_and(|line| line.is_empty()) {
+            self.lines.pop();
+        }
+        self.lines.extend(other.lines);
+    }
+
+    /// Every character of the frame and its tone, by its `(column, line)`.
+    pub fn cells(&self) -> impl Iterator<Item = ((usize, usize), char, Tone)> + '_ {
+        self.lines[..self.height()]
+            .iter()
+            .enumerate()
+            .flat_map(|(row, line)| {
+                line.iter()
+                    .flat_map(|(text, tone)| text.chars().map(move |c| (c, *tone)))
+                    .enumerate()
+                    .map(move |(column, (c, tone))| ((column, row), c, tone))
+            })
+    }
+
+    /// How many lines the frame takes up once painted.
+    pub fn height(&self) -> usize {
+        match self.lines.last() {
+            Some(line) if line.is_empty() => self.lines.len() - 1,
+            _ => self.lines.len(),
+        }
+    }
+
+    /// The frame's text, in the colors of `theme`, every line ending in a newline.
+    pub fn paint(&self, theme: &Theme) -> String {
+        let mut painted = String::new();
+        for line in &self.lines[..self.height()] {
+            for (text, tone) in line {
+                let escape = theme.escape(*tone);
+                painted.push_str(escape);
+                painted.push_str(text);
+                if !escape.is_empty() {
+                    painted.push_str("\x1b[0m");
+                }
+            }
+            painted.push('\n');
+        }
+        painted
+    }
+
+    /// The frame's text without any escapes.
+    pub fn text(&self) -> String {
+        self.paint(&Theme::PLAIN)
+    }
+}
+
+/// Painted in the theme [`Theme::from_env`] picks.
+impl Display for Frame {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.paint(&Theme::from_env()))
+    }
+}
+
+/// A state that can draw itself.
+pub trait Render {
+    fn frame(&self) -> Frame;
+}
+
+/// What a [`Player`] can be told to do while it plays.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Control {
+    /// Stop where it is, or carry on if it's stopped.
+    TogglePause,
+    /// Move this many frames forwards, or backwards if negative, and pause there.
+    Step(isize),
+    /// Jump to a frame, by its index, and pause there.
+    Seek(usize),
+    /// Double the frame rate.
+    Faster,
+    /// Halve the frame rate.
+    Slower,
+    /// Stop playing.
+    Quit,
+}
+
+/// Parses the commands typed at a playing animation: `p` pauses, `n` (or nothing) steps forward,
+/// `b` steps back, `g N` (or just `N`) seeks to frame `N`, `+` and `-` change the speed and `q`
+/// quits.
+impl FromStr for Control {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> AocResult<Control> {
+        let s = s.trim();
+        let seek = s.strip_prefix('g').unwrap_or(s).trim();
+        match s {
+            "p" => Ok(Control::TogglePause),
+            "" | "n" => Ok(Control::Step(1)),
+            "b" => Ok(Control::Step(-1)),
+            "+" => Ok(Control::Faster),
+            "-" => Ok(Control::Slower),
+            "q" => Ok(Control::Quit),
+            _ => seek.parse().map(Control::Seek).map_err(|_| {
+                AocError::new(format!(
+                    "expected `p`, `n`, `b`, `g <frame>`, `+`, `-` or `q`, found `{}`",
+                    s
+                ))
+            }),
+        }
+    }
+}
+
+/// Reads [`Control`]s from stdin a line at a time, on a thread of its own so the animation keeps
+/// playing in the meantime. Lines that aren't controls are skipped with a warning.
+pub fn stdin_controls() -> Receiver<Control> {
+    let (sender, receiver) = mpsc::channel();
+    std::thread::spawn(move || {
+        for line in io::stdin().lock().lines() {
+            let Ok(line) = line else {
+                break;
+            };
+            match line.parse() {
+                Ok(control) => {
+                    if sender.send(control).is_err() {
+                        break;
+                    }
+                }
+                Err(e) => log::warn!("{}", e),
+            }
+        }
+    });
+    receiver
+}
+
+/// Plays a recorded run of frames back, each one drawn over the last.
+#[derive(Debug, Clone)]
+pub struct Player {
+    frames: Vec<Frame>,
+    theme: Theme,
+    fps: f64,
+    position: usize,
+    paused: bool,
+}
+
+impl Player {
+    /// A player for `frames` at 10 frames a second, in the theme [`Theme::from_env`] picks.
+    pub fn new(frames: Vec<Frame>) -> Player {
+        Player {
+            frames,
+            theme: Theme::from_env(),
+            fps: 10.0,
+            position: 0,
+            paused: false,
+        }
+    }
+
+    pub fn with_theme(mut self, theme: Theme) -> Player {
+        self.theme = theme;
+        self
+    }
+
+    pub fn with_fps(mut self, fps: f64) -> Player {
+        assert!(fps > 0.0, "the frame rate must be positive");
+        self.fps = fps;
+        self
+    }
+
+    /// The number of frames.
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    /// The index of the frame being shown.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    /// Carries out `control`, returning whether to keep playing.
+    pub fn apply(&mut self, control: Control) -> bool {
+        let last = self.frames.len().saturating_sub(1);
+        match control {
+            Control::TogglePause => self.paused = !self.paused,
+            Control::Step(frames) => {
+                self.position = self.position.saturating_add_signed(frames).min(last);
+                self.paused = true;
+            }
+            Control::Seek(frame) => {
+                self.position = frame.min(last);
+                self.paused = true;
+            }
+            Control::Faster => self.fps *= 2.0,
+            Control::Slower => self.fps /= 2.0,
+            Control::Quit => return false,
+        }
+        true
+    }
+
+    /// Plays the frames to `out`, acting on `controls` as they arrive, until the last frame has
+    /// been shown or playback is quit. A paused player waits for a control; one that's paused
+    /// with no more controls coming stops.
+    pub fn play(&mut self, out: &mut impl Write, controls: &Receiver<Control>) -> io::Result<()> {
+        if self.frames.is_empty() {
+            return Ok(());
+        }
+
+        let mut drawn: Option<(usize, usize)> = None;
+        let mut connected = true;
+        loop {
+            if drawn.map(|(position, _)| position) != Some(self.position) {
+                let height = drawn.map_or(0, |(_, height)| height);
+                drawn = Some((self.position, self.draw(out, height)?));
+            }
+
+            let interval = Duration::from_secs_f64(1.0 / self.fps);
+            let control = if !connected {
+                if self.paused {
+                    break;
+                }
+                std::thread::sleep(interval);
+                None
+            } else if self.paused {
+                controls.recv().ok()
+            } else {
+                match controls.recv_timeout(interval) {
+                    Ok(control) => Some(control),
+                    Err(RecvTimeoutError::Timeout) => None,
+                    Err(RecvTimeoutError::Disconnected) => {
+                        connected = false;
+                        continue;
+                    }
+                }
+            };
+
+            match control {
+                Some(control) => {
+                    if !self.apply(control) {
+                        break;
+                    }
+                }
+                None if self.paused => connected = false,
+                None if self.position + 1 < self.frames.len() => self.position += 1,
+                None => break,
+            }
+        }
+        Ok(())
+    }
+
+    /// Draws the current frame and a status line over the `height` lines drawn last time,
+    /// returning how many lines it took.
+    fn draw(&self, out: &mut impl Write, height: usize) -> io::Result<usize> {
+        if height > 0 {
+            // back to the start of the last frame, and clear everything from there down
+            write!(out, "\x1b[{}F", height)?;
+        }
+        write!(out, "\x1b[J")?;
+
+        let frame = &self.frames[self.position];
+        out.write_all(frame.paint(&self.theme).as_bytes())?;
+        writeln!(
+            out,
+            "frame {}/{}{}",
+            self.position + 1,
+            self.frames.len(),
+            if self.paused { " (paused)" } else { "" }
+        )?;
+        out.flush()?;
+        Ok(frame.height() + 1)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn counter(n: usize) -> Frame {
+        let mut frame = Frame::new();
+        frame.push_line(n, Tone::Highlight);
+        frame
+    }
+
+    #[test]
+    fn frames() {
+        let grid = Grid::from_rows(vec![vec![1, 0], vec![0, 2]]).unwrap();
+        let mut frame = Frame::from_grid(&grid, |_, cell| {
+            (*cell, if *cell == 0 { Tone::Dim } else { Tone::Normal })
+        });
+        assert_eq!("10\n02\n", frame.text());
+        assert_eq!(
+            "1\x1b[2m0\x1b[0m\n\x1b[2m0\x1b[0m2\n",
+            frame.paint(&Theme::CLASSIC)
+        );
+        assert_eq!(2, frame.height());
+
+        frame.push_line("done", Tone::Accent);
+        frame.append(counter(3));
+        assert_eq!("10\n02\ndone\n3\n", frame.text());
+        assert_eq!(4, frame.height());
+
+        // neighboring spans in the same tone share their escapes
+        let mut run = Frame::new();
+        run.push('a', Tone::Highlight);
+        run.push('b', Tone::Highlight);
+        assert_eq!("\x1b[1;4mab\x1b[0m\n", run.paint(&Theme::CLASSIC));
+    }
+
+    #[test]
+    fn themes() {
+        assert_eq!(Ok(Theme::VIVID), "vivid".parse());
+        assert!(Theme::named("sepia")
+            .unwrap_err()
+            .reason
+            .contains("classic, vivid, plain"));
+        assert_eq!("3\n", counter(3).paint(&Theme::PLAIN));
+    }
+
+    #[test]
+    fn controls() {
+        assert_eq!(Ok(Control::Step(1)), "".parse());
+        assert_eq!(Ok(Control::Step(-1)), "b".parse());
+        assert_eq!(Ok(Control::Seek(12)), "g 12".parse());
+        assert_eq!(Ok(Control::Seek(3)), "3\n".parse());
+        assert_eq!(Ok(Control::Quit), "q".parse());
+        assert!("g".parse::<Control>().is_err());
+        assert!("stop".parse::<Control>().is_err());
+    }
+
+    /// The frames `output` showed, by the number on them.
+    fn shown(output: &[u8]) -> Vec<usize> {
+        let output = String::from_utf8(output.to_vec()).unwrap();
+        output
+            .split("\x1b[J")
+            .skip(1)
+            .map(|frame| frame.lines().next().unwrap().parse().unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn playback() {
+        let frames: Vec<Frame> = (0..5).map(counter).collect();
+
+        // left alone, it plays every frame through once
+        let (_sender, controls) = mpsc::channel();
+        let mut player = Player::new(frames.clone())
+            .with_theme(Theme::PLAIN)
+            .with_fps(1000.0);
+        let mut output = vec![];
+        player.play(&mut output, &controls).unwrap();
+        assert_eq!(vec![0, 1, 2, 3, 4], shown(&output));
+        assert!(String::from_utf8(output)
+            .unwrap()
+            .ends_with("4\nframe 5/5\n"));
+
+        // controls sent up front are acted on before it moves on by itself
+        let (sender, controls) = mpsc::channel();
+        for control in [
+            Control::TogglePause,
+            Control::Step(1),
+            Control::Step(1),
+            Control::Step(-1),
+            Control::Seek(9),
+            Control::Step(-2),
+        ] {
+            sender.send(control).unwrap();
+        }
+        drop(sender);
+        let mut player = Player::new(frames).with_theme(Theme::PLAIN);
+        let mut output = vec![];
+        player.play(&mut output, &controls).unwrap();
+        assert_eq!(vec![0, 1, 2, 1, 4, 2], shown(&output));
+        assert!(player.is_paused());
+        // every frame after the first is drawn over the one before, status line and all
+        assert_eq!(
+            5,
+            String::from_utf8(output)
+                .unwrap()
+                .matches("\x1b[2F")
+                .count()
+        );
+    }
+}
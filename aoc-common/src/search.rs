@@ -0,0 +1,315 @@
+//! Searches over states generated on the fly, for puzzles whose graph is too big, or too
+//! awkward, to build up front: a puzzle describes its moves by implementing [`State`], and a
+//! [`Search`] finds the way to a goal. Its breadth first and best first searches are those of
+//! [`crate::graph`], run over the states with [`Stats`] kept on them.
+//!
+//! For counting puzzles that branch into many overlapping outcomes, [`Memo`] caches the results
+//! of a recursive function.
+
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+
+use crate::graph::{self, Path};
+
+/// A position in a puzzle, and the moves out of it.
+///
+/// States that compare equal are treated as one, so leave anything that doesn't change what
+/// comes next (like a running total) out of `Eq` and `Hash` to let the searches skip repeats.
+pub trait State: Clone + Eq + Hash {
+    /// The states one move away, each with what the move costs.
+    fn successors(&self) -> Vec<(Self, usize)>;
+
+    /// Whether this state solves the puzzle.
+    fn is_goal(&self) -> bool;
+
+    /// An estimate of the cost left to reach a goal, used by [`Search::astar`] and
+    /// [`Search::ida_star`]. They only find the cheapest path if it never overshoots.
+    fn heuristic(&self) -> usize {
+        0
+    }
+}
+
+/// What a search got through to find its answer.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Stats {
+    /// States whose successors were looked at.
+    pub expanded: usize,
+    /// Successors handed back by [`State::successors`].
+    pub generated: usize,
+    /// Successors dropped for having been reached already, at least as cheaply.
+    pub duplicates: usize,
+    /// The most states waiting to be expanded at once.
+    pub max_frontier: usize,
+}
+
+/// Runs searches over [`State`]s, keeping [`Stats`] on the last one.
+#[derive(Debug, Clone, Default)]
+pub struct Search {
+    stats: Stats,
+}
+
+impl Search {
+    pub fn new() -> Search {
+        Search::default()
+    }
+
+    /// The statistics of the last search run.
+    pub fn stats(&self) -> Stats {
+        self.stats
+    }
+
+    /// The path to a goal with the fewest moves, whatever they cost. Its cost is still the sum
+    /// of its moves.
+    pub fn bfs<S: State>(&mut self, start: S) -> Option<Path<S>> {
+        self.stats = Stats::default();
+        graph::bfs_with_stats(start, S::successors, S::is_goal, &mut self.stats)
+    }
+
+    /// The cheapest path to a goal, ignoring the heuristic.
+    pub fn dijkstra<S: State>(&mut self, start: S) -> Option<Path<S>> {
+        self.best_first(start, |_| 0)
+    }
+
+    /// The cheapest path to a goal, as long as [`State::heuristic`] never overshoots, looking at
+    /// the most promising states first.
+    pub fn astar<S: State>(&mut self, start: S) -> Option<Path<S>> {
+        self.best_first(start, S::heuristic)
+    }
+
+    fn best_first<S: State>(
+        &mut self,
+        start: S,
+        heuristic: impl Fn(&S) -> usize,
+    ) -> Option<Path<S>> {
+        self.stats = Stats::default();
+        graph::astar_with_stats(start, S::successors, heuristic, S::is_goal, &mut self.stats)
+    }
+
+    /// [`astar`](Search::astar) in the memory of a depth first search: deepens a cost bound
+    /// until a goal fits within it. Only states on the current path are remembered, so it suits
+    /// searches whose frontier would be too big to hold.
+    pub fn ida_star<S: State>(&mut self, start: S) -> Option<Path<S>> {
+        self.stats = Stats::default();
+        let mut bound = start.heuristic();
+        let mut path = vec![start.clone()];
+        let mut on_path = HashSet::from([start]);
+        loop {
+            match self.bounded_dfs(&mut path, &mut on_path, 0, bound) {
+                Deepen::Found(cost) => return Some(Path { nodes: path, cost }),
+                Deepen::Beyond(next_bound) => bound = next_bound,
+                Deepen::Exhausted => return None,
+            }
+        }
+    }
+
+    fn bounded_dfs<S: State>(
+        &mut self,
+        path: &mut Vec<S>,
+        on_path: &mut HashSet<S>,
+        cost: usize,
+        bound: usize,
+    ) -> Deepen {
+        let state = path.last().expect("the path holds at least the start");
+        let estimate = cost + state.heuristic();
+        if estimate > bound {
+            return Deepen::Beyond(estimate);
+        }
+        if state.is_goal() {
+            return Deepen::Found(cost);
+        }
+
+        self.stats.expanded += 1;
+        self.stats.max_frontier = self.stats.max_frontier.max(path.len());
+        let mut next_bound = Deepen::Exhausted;
+        for (next, step) in state.successors() {
+            self.stats.generated += 1;
+            if !on_path.insert(next.clone()) {
+                self.stats.duplicates += 1;
+                continue;
+            }
+            path.push(next);
+            match self.bounded_dfs(path, on_path, cost + step, bound) {
+                Deepen::Found(cost) => return Deepen::Found(cost),
+                Deepen::Beyond(over) => {
+                    if !matches!(next_bound, Deepen::Beyond(lowest) if lowest <= over) {
+                        next_bound = Deepen::Beyond(over);
+                    }
+                }
+                Deepen::Exhausted => (),
+            }
+            on_path.remove(&path.pop().expect("just pushed"));
+        }
+        next_bound
+    }
+}
+
+/// How a bounded pass of [`Search::ida_star`] went.
+enum Deepen {
+    /// Reached a goal, at this cost.
+    Found(usize),
+    /// Ran into the bound; the smallest estimate past it is the next bound to try.
+    Beyond(usize),
+    /// Ran out of states without touching the bound, so no bound will do.
+    Exhausted,
+}
+
+/// A cache of the results of a recursive function, for counting puzzles whose branches keep
+/// landing on the same subproblems.
+#[derive(Debug, Clone)]
+pub struct Memo<K, V> {
+    cache: HashMap<K, V>,
+    hits: usize,
+}
+
+impl<K, V> Default for Memo<K, V> {
+    fn default() -> Self {
+        Memo {
+            cache: HashMap::new(),
+            hits: 0,
+        }
+    }
+}
+
+impl<K: Eq + Hash, V: Clone> Memo<K, V> {
+    pub fn new() -> Memo<K, V> {
+        Memo::default()
+    }
+
+    /// The result for `key`, worked out by `compute` the first time it's asked for. `compute`
+    /// gets the memo back to look up the subproblems it depends on.
+    pub fn get_or_insert_with(&mut self, key: K, compute: impl FnOnce(&mut Self) -> V) -> V {
+        if let Some(value) = self.cache.get(&key) {
+            self.hits += 1;
+            return value.clone();
+        }
+        let value = compute(self);
+        self.cache.insert(key, value.clone());
+        value
+    }
+
+    /// How many results have been worked out.
+    pub fn len(&self) -> usize {
+        self.cache.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cache.is_empty()
+    }
+
+    /// How many times a result was answered from the cache.
+    pub fn hits(&self) -> usize {
+        self.hits
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Getting from one number to another by adding one, at a cost of one, or doubling, at a
+    /// cost of `double`.
+    #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+    struct Climb {
+        at: usize,
+        target: usize,
+        double: usize,
+    }
+
+    impl State for Climb {
+        fn successors(&self) -> Vec<(Self, usize)> {
+            let step = |at| Climb { at, ..*self };
+            [(step(self.at + 1), 1), (step(self.at * 2), self.double)]
+                .into_iter()
+                .filter(|(next, _)| next.at <= self.target)
+                .collect()
+        }
+
+        fn is_goal(&self) -> bool {
+            self.at == self.target
+        }
+
+        fn heuristic(&self) -> usize {
+            // doubling can cover any distance in a single move
+            usize::from(self.at != self.target).min(self.double)
+        }
+    }
+
+    fn climb(target: usize, double: usize) -> Climb {
+        Climb {
+            at: 1,
+            target,
+            double,
+        }
+    }
+
+    fn stops(path: &Path<Climb>) -> Vec<usize> {
+        path.nodes.iter().map(|state| state.at).collect()
+    }
+
+    #[test]
+    fn searches_agree() {
+        let mut search = Search::new();
+
+        // 1 -> 2 -> 4 -> 5 -> 10, the fewest moves whatever doubling costs
+        let path = search.bfs(climb(10, 5)).unwrap();
+        assert_eq!((vec![1, 2, 4, 5, 10], 12), (stops(&path), path.cost));
+        assert!(search.stats().expanded > 0);
+
+        // with doubling that dear, counting up is the cheapest way
+        let path = search.dijkstra(climb(10, 5)).unwrap();
+        assert_eq!(9, path.cost);
+        let path = search.astar(climb(10, 5)).unwrap();
+        assert_eq!(9, path.cost);
+        let path = search.ida_star(climb(10, 5)).unwrap();
+        assert_eq!(9, path.cost);
+
+        let path = search.astar(climb(10, 1)).unwrap();
+        assert_eq!(4, path.cost);
+        let path = search.ida_star(climb(10, 1)).unwrap();
+        assert_eq!(4, path.cost);
+
+        assert_eq!(
+            None,
+            search.dijkstra(Climb {
+                at: 11,
+                ..climb(10, 1)
+            })
+        );
+        assert_eq!(
+            None,
+            search.ida_star(Climb {
+                at: 11,
+                ..climb(10, 1)
+            })
+        );
+    }
+
+    #[test]
+    fn deduplication() {
+        let mut search = Search::new();
+        search.dijkstra(climb(64, 1)).unwrap();
+        let stats = search.stats();
+        // each number is only expanded once, however many ways there are to reach it
+        assert!(stats.expanded <= 64);
+        assert!(stats.duplicates > 0);
+        assert!(stats.generated > stats.expanded);
+        assert!(stats.max_frontier > 0);
+    }
+
+    #[test]
+    fn memoization() {
+        fn ways(memo: &mut Memo<usize, u64>, stairs: usize) -> u64 {
+            if stairs <= 1 {
+                return 1;
+            }
+            memo.get_or_insert_with(stairs, |memo| {
+                ways(memo, stairs - 1) + ways(memo, stairs - 2)
+            })
+        }
+
+        let mut memo = Memo::new();
+        assert_eq!(2_504_730_781_961, ways(&mut memo, 60));
+        assert_eq!(59, memo.len());
+        assert!(memo.hits() > 0);
+    }
+}
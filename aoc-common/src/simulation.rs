@@ -0,0 +1,248 @@
+//! [`Simulation`], for puzzles that step a state along until something happens, and the
+//! [`Runner`] that steps it, counting the steps and keeping a history if asked.
+//!
+//! Simulations that come back around to an earlier state can skip ahead: [`find_cycle`] spots
+//! the loop by Brent's algorithm, and [`state_at`] uses it to answer for any step, however far
+//! off, after simulating no more than the first trip around.
+
+/// A state that moves along one step at a time.
+pub trait Simulation {
+    /// Moves the state along a step.
+    fn step(&mut self);
+
+    /// Moves the state along `steps` steps.
+    fn run(&mut self, steps: usize) {
+        for _ in 0..steps {
+            self.step();
+        }
+    }
+}
+
+/// Steps a [`Simulation`], counting how far it's gone and, if asked, keeping every state it
+/// passes through.
+#[derive(Debug, Clone)]
+pub struct Runner<S> {
+    state: S,
+    steps: usize,
+    history: Option<Vec<S>>,
+}
+
+impl<S: Simulation + Clone> Runner<S> {
+    pub fn new(state: S) -> Runner<S> {
+        Runner {
+            state,
+            steps: 0,
+            history: None,
+        }
+    }
+
+    /// A runner that keeps a copy of every state, the starting one included.
+    pub fn with_history(state: S) -> Runner<S> {
+        Runner {
+            history: Some(vec![state.clone()]),
+            state,
+            steps: 0,
+        }
+    }
+
+    pub fn state(&self) -> &S {
+        &self.state
+    }
+
+    pub fn into_state(self) -> S {
+        self.state
+    }
+
+    /// How many steps have been taken.
+    pub fn steps(&self) -> usize {
+        self.steps
+    }
+
+    /// Every state so far, indexed by the number of steps taken to reach it, if the runner is
+    /// keeping them.
+    pub fn history(&self) -> Option<&[S]> {
+        self.history.as_deref()
+    }
+
+    pub fn step(&mut self) {
+        self.state.step();
+        self.steps += 1;
+        if let Some(history) = &mut self.history {
+            history.push(self.state.clone());
+        }
+    }
+
+    /// Takes `steps` steps.
+    pub fn run(&mut self, steps: usize) {
+        for _ in 0..steps {
+            self.step();
+        }
+    }
+
+    /// Steps until `done` is happy with the state, returning how many steps that took. A state
+    /// that's already done takes none.
+    pub fn run_until(&mut self, mut done: impl FnMut(&S) -> bool) -> usize {
+        let start = self.steps;
+        while !done(&self.state) {
+            self.step();
+        }
+        self.steps - start
+    }
+}
+
+/// Where a simulation starts repeating itself.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Cycle {
+    /// The first step of the loop: the state after this many steps comes around again.
+    pub start: usize,
+    /// How many steps it takes to come around.
+    pub length: usize,
+}
+
+impl Cycle {
+    /// The step no later than `start + length` that has the same state as step `step`.
+    pub fn equivalent_step(&self, step: usize) -> usize {
+        if step < self.start {
+            step
+        } else {
+            self.start + (step - self.start) % self.length
+        }
+    }
+}
+
+/// Finds the loop the simulation from `initial` falls into, by Brent's algorithm, using only a
+/// couple of states' worth of memory. Gives up with `None` after `limit` steps.
+pub fn find_cycle<S: Simulation + Clone + Eq>(initial: &S, limit: usize) -> Option<Cycle> {
+    let mut taken = 0;
+    let mut step = |state: &mut S| {
+        taken += 1;
+        state.step();
+        taken <= limit
+    };
+
+    // the hare runs ahead in ever doubling stretches, the tortoise waiting at the start of each,
+    // until the hare laps it
+    let mut power = 1;
+    let mut length = 1;
+    let mut tortoise = initial.clone();
+    let mut hare = initial.clone();
+    if !step(&mut hare) {
+        return None;
+    }
+    while tortoise != hare {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+        if !step(&mut hare) {
+            return None;
+        }
+        length += 1;
+    }
+
+    // with the hare a whole loop ahead, walk both on until they meet at its start
+    let mut tortoise = initial.clone();
+    let mut hare = initial.clone();
+    for _ in 0..length {
+        if !step(&mut hare) {
+            return None;
+        }
+    }
+    let mut start = 0;
+    while tortoise != hare {
+        if !step(&mut tortoise) || !step(&mut hare) {
+            return None;
+        }
+        start += 1;
+    }
+
+    Some(Cycle { start, length })
+}
+
+/// The state of the simulation from `initial` after `steps` steps. Once it's found to loop,
+/// the steps past the first time around are skipped.
+pub fn state_at<S: Simulation + Clone + Eq>(initial: &S, steps: usize) -> S {
+    // finding the loop takes no more than a few trips around it, so if it can't be found in as
+    // many steps as were asked for, just taking them is no worse
+    let steps = match find_cycle(initial, steps) {
+        Some(cycle) => cycle.equivalent_step(steps),
+        None => steps,
+    };
+    let mut state = initial.clone();
+    state.run(steps);
+    state
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Repeatedly squares a number modulo another, which has to loop eventually.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    struct Squaring {
+        value: u64,
+        modulus: u64,
+    }
+
+    impl Simulation for Squaring {
+        fn step(&mut self) {
+            self.value = (self.value * self.value + 1) % self.modulus;
+        }
+    }
+
+    fn squaring(value: u64) -> Squaring {
+        Squaring {
+            value,
+            modulus: 255,
+        }
+    }
+
+    #[test]
+    fn runner() {
+        let mut runner = Runner::with_history(squaring(3));
+        runner.run(2);
+        assert_eq!(2, runner.steps());
+        let values: Vec<u64> = runner.history().unwrap().iter().map(|s| s.value).collect();
+        assert_eq!(vec![3, 10, 101], values);
+
+        assert_eq!(5, runner.run_until(|state| state.value == 95));
+        assert_eq!(7, runner.steps());
+        assert_eq!(8, runner.history().unwrap().len());
+        assert_eq!(95, runner.into_state().value);
+        assert_eq!(None, Runner::new(squaring(3)).history());
+        assert_eq!(
+            0,
+            Runner::new(squaring(3)).run_until(|state| state.value == 3)
+        );
+    }
+
+    #[test]
+    fn cycles() {
+        // 3, 10, 101, 2, 5, 26, 167, 95, 101, ...
+        let cycle = find_cycle(&squaring(3), 1000).unwrap();
+        assert_eq!(
+            Cycle {
+                start: 2,
+                length: 6
+            },
+            cycle
+        );
+        assert_eq!(None, find_cycle(&squaring(3), 5));
+
+        // everything agrees with taking the steps one at a time
+        let mut stepped = squaring(3);
+        for steps in 0..50 {
+            assert_eq!(stepped, state_at(&squaring(3), steps));
+            assert_eq!(
+                stepped,
+                state_at(&squaring(3), cycle.equivalent_step(steps))
+            );
+            stepped.step();
+        }
+
+        // and far enough along that taking them isn't an option
+        let far = state_at(&squaring(3), 1_000_000_000_000);
+        assert_eq!(state_at(&squaring(3), 2 + (1_000_000_000_000 - 2) % 6), far);
+    }
+}
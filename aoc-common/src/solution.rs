@@ -0,0 +1,247 @@
+//! The [`Solution`] trait every day implements, and the [`Registry`] that lets tooling look a day
+//! up by `(year, day)` and run it without knowing anything about its types.
+
+use std::any::Any;
+use std::collections::BTreeMap;
+
+use crate::answer::Answer;
+use crate::error::AocResult;
+use crate::render::Frame;
+
+/// Which half of a day's puzzle to run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const BOTH: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl std::fmt::Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::One => f.pad("1"),
+            Part::Two => f.pad("2"),
+        }
+    }
+}
+
+/// A single day's puzzle, split into a parsing stage and the two parts.
+///
+/// Both parts receive the same parsed input, so anything expensive to build from the raw text
+/// belongs in [`Solution::parse`]. Malformed input is reported as an [`AocError`](crate::AocError)
+/// rather than a panic, from either stage.
+pub trait Solution {
+    /// The parsed puzzle input shared by both parts.
+    type Input;
+
+    fn parse(&self, input: &str) -> AocResult<Self::Input>;
+    fn part_one(&self, input: &Self::Input) -> AocResult<Answer>;
+    fn part_two(&self, input: &Self::Input) -> AocResult<Answer>;
+
+    /// The states the puzzle passes through on the way to an answer, drawn for `aoc animate`.
+    /// Days with nothing worth watching leave this empty.
+    fn animate(&self, _input: &Self::Input) -> AocResult<Vec<Frame>> {
+        Ok(vec![])
+    }
+}
+
+/// Object safe view of a [`Solution`] with its input and output types erased.
+///
+/// Implemented for every thread safe [`Solution`], this is what the [`Registry`] hands out.
+pub trait DynSolution: Send + Sync {
+    /// Parses the raw puzzle input. The result is only meaningful to [`DynSolution::solve`] on
+    /// the same solution.
+    fn parse(&self, input: &str) -> AocResult<Box<dyn Any>>;
+
+    /// Runs the given part against input produced by [`DynSolution::parse`].
+    fn solve(&self, parsed: &dyn Any, part: Part) -> AocResult<Answer>;
+
+    /// Draws the run through input produced by [`DynSolution::parse`], as in
+    /// [`Solution::animate`].
+    fn animate(&self, parsed: &dyn Any) -> AocResult<Vec<Frame>>;
+
+    /// Parses `input` and runs the given part against it.
+    fn run(&self, input: &str, part: Part) -> AocResult<Answer> {
+        let parsed = self.parse(input)?;
+        self.solve(parsed.as_ref(), part)
+    }
+}
+
+impl<S> DynSolution for S
+where
+    S: Solution + Send + Sync,
+    S::Input: 'static,
+{
+    fn parse(&self, input: &str) -> AocResult<Box<dyn Any>> {
+        Ok(Box::new(Solution::parse(self, input)?))
+    }
+
+    fn solve(&self, parsed: &dyn Any, part: Part) -> AocResult<Answer> {
+        let parsed = parsed
+            .downcast_ref::<S::Input>()
+            .expect("input was parsed by a different solution");
+        match part {
+            Part::One => self.part_one(parsed),
+            Part::Two => self.part_two(parsed),
+        }
+    }
+
+    fn animate(&self, parsed: &dyn Any) -> AocResult<Vec<Frame>> {
+        let parsed = parsed
+            .downcast_ref::<S::Input>()
+            .expect("input was parsed by a different solution");
+        Solution::animate(self, parsed)
+    }
+}
+
+/// A registered day.
+pub enum Entry {
+    Implemented(Box<dyn DynSolution>),
+    /// The module exists, but only as a stub.
+    Unimplemented,
+}
+
+impl Entry {
+    /// The solution for this day, if it has one.
+    pub fn solution(&self) -> Option<&dyn DynSolution> {
+        match self {
+            Entry::Implemented(solution) => Some(solution.as_ref()),
+            Entry::Unimplemented => None,
+        }
+    }
+}
+
+/// Every known day, keyed (and iterated) by `(year, day)`.
+#[derive(Default)]
+pub struct Registry {
+    days: BTreeMap<(u16, u8), Entry>,
+}
+
+impl Registry {
+    pub fn new() -> Registry {
+        Registry::default()
+    }
+
+    /// Registers the solution for the given day.
+    pub fn register<S>(&mut self, year: u16, day: u8, solution: S)
+    where
+        S: Solution + Send + Sync + 'static,
+        S::Input: 'static,
+    {
+        self.insert(year, day, Entry::Implemented(Box::new(solution)));
+    }
+
+    /// Records that the given day exists but hasn't been solved yet.
+    pub fn register_unimplemented(&mut self, year: u16, day: u8) {
+        self.insert(year, day, Entry::Unimplemented);
+    }
+
+    fn insert(&mut self, year: u16, day: u8, entry: Entry) {
+        assert!(
+            (1..=25).contains(&day),
+            "{} day {} is out of range",
+            year,
+            day
+        );
+        let previous = self.days.insert((year, day), entry);
+        assert!(previous.is_none(), "{} day {} registered twice", year, day);
+    }
+
+    pub fn get(&self, year: u16, day: u8) -> Option<&Entry> {
+        self.days.get(&(year, day))
+    }
+
+    /// The solution for the given day, if it is registered and implemented.
+    pub fn solution(&self, year: u16, day: u8) -> Option<&dyn DynSolution> {
+        self.get(year, day).and_then(Entry::solution)
+    }
+
+    /// All registered days in `(year, day)` order.
+    pub fn iter(&self) -> impl Iterator<Item = ((u16, u8), &Entry)> {
+        self.days.iter().map(|(key, entry)| (*key, entry))
+    }
+
+    /// All registered days of the given year, in order.
+    pub fn year(&self, year: u16) -> impl Iterator<Item = (u8, &Entry)> {
+        self.days
+            .range((year, 0)..=(year, u8::MAX))
+            .map(|((_, day), entry)| (*day, entry))
+    }
+
+    /// The years with at least one registered day.
+    pub fn years(&self) -> Vec<u16> {
+        let mut years: Vec<u16> = self.days.keys().map(|(year, _)| *year).collect();
+        years.dedup();
+        years
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        type Input = Vec<i32>;
+
+        fn parse(&self, input: &str) -> AocResult<Self::Input> {
+            input
+                .lines()
+                .map(|line| crate::error::parse_at(input, line, "a number"))
+                .collect()
+        }
+
+        fn part_one(&self, input: &Self::Input) -> AocResult<Answer> {
+            Ok(input.iter().sum::<i32>().into())
+        }
+
+        fn part_two(&self, input: &Self::Input) -> AocResult<Answer> {
+            Ok(input.iter().product::<i32>().into())
+        }
+    }
+
+    #[test]
+    fn run_through_registry() {
+        let mut registry = Registry::new();
+        registry.register(2020, 1, Sum);
+        registry.register_unimplemented(2020, 2);
+
+        let solution = registry.solution(2020, 1).unwrap();
+        assert_eq!(Ok(Answer::from(9)), solution.run("2\n3\n4", Part::One));
+        assert_eq!(Ok(Answer::from(24)), solution.run("2\n3\n4", Part::Two));
+
+        let err = solution.run("2\nthree\n4", Part::One).unwrap_err();
+        assert_eq!(Some(2), err.line);
+
+        assert!(registry.get(2020, 2).is_some());
+        assert!(registry.solution(2020, 2).is_none());
+        assert!(registry.get(2020, 3).is_none());
+    }
+
+    #[test]
+    fn iterates_in_order() {
+        let mut registry = Registry::new();
+        registry.register_unimplemented(2021, 1);
+        registry.register(2020, 2, Sum);
+        registry.register_unimplemented(2020, 1);
+
+        let keys: Vec<(u16, u8)> = registry.iter().map(|(key, _)| key).collect();
+        assert_eq!(vec![(2020, 1), (2020, 2), (2021, 1)], keys);
+
+        let days: Vec<u8> = registry.year(2020).map(|(day, _)| day).collect();
+        assert_eq!(vec![1, 2], days);
+        assert_eq!(vec![2020, 2021], registry.years());
+    }
+
+    #[test]
+    #[should_panic]
+    fn double_registration() {
+        let mut registry = Registry::new();
+        registry.register(2020, 1, Sum);
+        registry.register(2020, 1, Sum);
+    }
+}
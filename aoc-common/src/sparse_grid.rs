@@ -0,0 +1,267 @@
+//! [`SparseGrid`], an unbounded 2D plane holding only the cells that are set, for the puzzles
+//! whose maps have no fixed size.
+
+use std::collections::HashMap;
+
+use crate::neighbors::Adjacency;
+
+/// A position on a [`SparseGrid`]: `(x, y)`, with `y` growing downwards like a [`Grid`](crate::Grid).
+pub type Point = (isize, isize);
+
+/// The smallest rectangle holding every set cell of a [`SparseGrid`], corners included.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Bounds {
+    /// The top left corner.
+    pub min: Point,
+    /// The bottom right corner.
+    pub max: Point,
+}
+
+impl Bounds {
+    /// The bounds of a single point.
+    pub fn new(point: Point) -> Bounds {
+        Bounds {
+            min: point,
+            max: point,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        (self.max.0 - self.min.0) as usize + 1
+    }
+
+    pub fn height(&self) -> usize {
+        (self.max.1 - self.min.1) as usize + 1
+    }
+
+    pub fn contains(&self, (x, y): Point) -> bool {
+        (self.min.0..=self.max.0).contains(&x) && (self.min.1..=self.max.1).contains(&y)
+    }
+
+    /// Grows the bounds just enough to hold `point`.
+    pub fn include(&mut self, (x, y): Point) {
+        self.min = (self.min.0.min(x), self.min.1.min(y));
+        self.max = (self.max.0.max(x), self.max.1.max(y));
+    }
+
+    /// Every point within the bounds, row by row.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let Bounds { min, max } = *self;
+        (min.1..=max.1).flat_map(move |y| (min.0..=max.0).map(move |x| (x, y)))
+    }
+}
+
+/// An unbounded plane of cells addressed by signed `(x, y)`, of which only the set ones are
+/// stored. `SparseGrid<()>` is a set of points.
+///
+/// The [`Bounds`] of the set cells are kept up to date as cells come and go, so drawing the
+/// occupied window doesn't need a pass over every cell first.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Point, T>,
+    bounds: Option<Bounds>,
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        SparseGrid {
+            cells: HashMap::new(),
+            bounds: None,
+        }
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> SparseGrid<T> {
+        SparseGrid::default()
+    }
+
+    /// The number of set cells.
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// The smallest rectangle holding every set cell, or `None` when nothing is set.
+    pub fn bounds(&self) -> Option<Bounds> {
+        self.bounds
+    }
+
+    /// Sets the cell at `point`, returning what was there before.
+    pub fn insert(&mut self, point: Point, value: T) -> Option<T> {
+        match &mut self.bounds {
+            Some(bounds) => bounds.include(point),
+            None => self.bounds = Some(Bounds::new(point)),
+        }
+        self.cells.insert(point, value)
+    }
+
+    /// Clears the cell at `point`, returning what was there.
+    pub fn remove(&mut self, point: Point) -> Option<T> {
+        let removed = self.cells.remove(&point)?;
+        // only a cell on the edge can have been holding the bounds out
+        let on_edge = self.bounds.is_some_and(|Bounds { min, max }| {
+            point.0 == min.0 || point.0 == max.0 || point.1 == min.1 || point.1 == max.1
+        });
+        if on_edge {
+            self.bounds = bounds_of(self.cells.keys().copied());
+        }
+        Some(removed)
+    }
+
+    /// Whether the cell at `point` is set.
+    pub fn contains(&self, point: Point) -> bool {
+        self.cells.contains_key(&point)
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.cells.get(&point)
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.cells.get_mut(&point)
+    }
+
+    /// The cell at `point`, set to `default()` first if it isn't yet.
+    pub fn entry_or_insert_with(&mut self, point: Point, default: impl FnOnce() -> T) -> &mut T {
+        if !self.cells.contains_key(&point) {
+            self.insert(point, default());
+        }
+        self.cells.get_mut(&point).expect("just inserted")
+    }
+
+    /// Every set cell, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.cells.iter().map(|(point, value)| (*point, value))
+    }
+
+    /// The position of every set cell, in no particular order.
+    pub fn points(&self) -> impl Iterator<Item = Point> + '_ {
+        self.cells.keys().copied()
+    }
+
+    /// The set cells among the neighbors of `point`.
+    pub fn neighbors(
+        &self,
+        point: Point,
+        adjacency: Adjacency,
+    ) -> impl Iterator<Item = (Point, &T)> {
+        adjacency
+            .around(point)
+            .filter_map(|point| self.cells.get(&point).map(|value| (point, value)))
+    }
+
+    /// Draws the occupied window one row per line, turning each position and what's set there
+    /// into a character with `cell`. An empty grid draws as nothing.
+    pub fn render(&self, mut cell: impl FnMut(Point, Option<&T>) -> char) -> String {
+        let Some(bounds) = self.bounds else {
+            return String::new();
+        };
+        let mut out = String::with_capacity((bounds.width() + 1) * bounds.height());
+        for point in bounds.points() {
+            out.push(cell(point, self.cells.get(&point)));
+            if point.0 == bounds.max.0 {
+                out.push('\n');
+            }
+        }
+        out
+    }
+}
+
+/// The bounds of `points`, or `None` if there aren't any.
+fn bounds_of(points: impl Iterator<Item = Point>) -> Option<Bounds> {
+    points.fold(None, |bounds, point| match bounds {
+        Some(mut bounds) => {
+            bounds.include(point);
+            Some(bounds)
+        }
+        None => Some(Bounds::new(point)),
+    })
+}
+
+impl<T> FromIterator<(Point, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Point, T)>>(iter: I) -> Self {
+        let mut grid = SparseGrid::new();
+        grid.extend(iter);
+        grid
+    }
+}
+
+impl<T> Extend<(Point, T)> for SparseGrid<T> {
+    fn extend<I: IntoIterator<Item = (Point, T)>>(&mut self, iter: I) {
+        for (point, value) in iter {
+            self.insert(point, value);
+        }
+    }
+}
+
+/// Collects a set of points.
+impl FromIterator<Point> for SparseGrid<()> {
+    fn from_iter<I: IntoIterator<Item = Point>>(iter: I) -> Self {
+        iter.into_iter().map(|point| (point, ())).collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn bounds_follow_cells() {
+        let mut grid = SparseGrid::new();
+        assert_eq!(None, grid.bounds());
+        grid.insert((2, 3), 'a');
+        grid.insert((-1, 5), 'b');
+        grid.insert((0, 4), 'c');
+        assert_eq!(
+            Some(Bounds {
+                min: (-1, 3),
+                max: (2, 5)
+            }),
+            grid.bounds()
+        );
+
+        assert_eq!(Some('b'), grid.remove((-1, 5)));
+        assert_eq!(
+            Some(Bounds {
+                min: (0, 3),
+                max: (2, 4)
+            }),
+            grid.bounds()
+        );
+        assert_eq!(None, grid.remove((-1, 5)));
+
+        grid.remove((0, 4));
+        grid.remove((2, 3));
+        assert!(grid.is_empty());
+        assert_eq!(None, grid.bounds());
+    }
+
+    #[test]
+    fn neighbors_and_render() {
+        let grid: SparseGrid<()> = [(0, 0), (1, 1), (3, 1), (-1, -1)].into_iter().collect();
+        let mut neighbors: Vec<Point> = grid
+            .neighbors((0, 0), Adjacency::Surrounding)
+            .map(|(point, _)| point)
+            .collect();
+        neighbors.sort();
+        assert_eq!(vec![(-1, -1), (1, 1)], neighbors);
+        assert_eq!(0, grid.neighbors((3, -1), Adjacency::Surrounding).count());
+
+        let drawn = grid.render(|_, cell| if cell.is_some() { '#' } else { '.' });
+        assert_eq!("#....\n.#...\n..#.#\n", drawn);
+        assert_eq!("", SparseGrid::<()>::new().render(|_, _| '#'));
+    }
+
+    #[test]
+    fn entries() {
+        let mut grid = SparseGrid::new();
+        *grid.entry_or_insert_with((-5, 7), || 0) += 1;
+        *grid.entry_or_insert_with((-5, 7), || 0) += 1;
+        assert_eq!(Some(&2), grid.get((-5, 7)));
+        assert_eq!(1, grid.len());
+    }
+}
@@ -0,0 +1,433 @@
+//! Turning, flipping, cropping, tiling and folding [`Grid`]s and [`SparseGrid`]s.
+//!
+//! The eight ways to lay a square back down on itself are the [`Orientation`]s; every other
+//! rotation or flip is one of those.
+
+use std::str::FromStr;
+
+use crate::error::{AocError, AocResult};
+use crate::grid::Grid;
+use crate::sparse_grid::{Bounds, Point, SparseGrid};
+
+/// One of the eight orientations of a square: flipped left to right or not, then turned some
+/// number of quarter turns clockwise.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Orientation {
+    turns: u8,
+    flipped: bool,
+}
+
+impl Orientation {
+    /// Leaves everything where it is.
+    pub const IDENTITY: Orientation = Orientation::new(0, false);
+
+    /// Swaps rows and columns.
+    pub const TRANSPOSE: Orientation = Orientation::new(3, true);
+
+    /// All eight orientations, the four unflipped ones first.
+    pub const ALL: [Orientation; 8] = [
+        Orientation::new(0, false),
+        Orientation::new(1, false),
+        Orientation::new(2, false),
+        Orientation::new(3, false),
+        Orientation::new(0, true),
+        Orientation::new(1, true),
+        Orientation::new(2, true),
+        Orientation::new(3, true),
+    ];
+
+    /// Flips left to right if `flipped`, then turns `turns` quarter turns clockwise.
+    pub const fn new(turns: u8, flipped: bool) -> Orientation {
+        Orientation {
+            turns: turns % 4,
+            flipped,
+        }
+    }
+
+    /// How many quarter turns clockwise, after any flip.
+    pub fn turns(self) -> u8 {
+        self.turns
+    }
+
+    /// Whether it flips left to right before turning.
+    pub fn flipped(self) -> bool {
+        self.flipped
+    }
+
+    /// This orientation followed by `next`.
+    pub fn then(self, next: Orientation) -> Orientation {
+        // flipping reverses the direction of any turns made before it
+        let turns = if next.flipped {
+            next.turns + 4 - self.turns
+        } else {
+            next.turns + self.turns
+        };
+        Orientation::new(turns, self.flipped != next.flipped)
+    }
+
+    /// The orientation that undoes this one.
+    pub fn inverse(self) -> Orientation {
+        if self.flipped {
+            self
+        } else {
+            Orientation::new(4 - self.turns, false)
+        }
+    }
+
+    /// Where `point` ends up, turning about the origin. `y` grows downwards, so a clockwise turn
+    /// takes `(1, 0)` to `(0, 1)`.
+    pub fn apply(self, (x, y): Point) -> Point {
+        let (mut x, mut y) = if self.flipped { (-x, y) } else { (x, y) };
+        for _ in 0..self.turns {
+            (x, y) = (-y, x);
+        }
+        (x, y)
+    }
+}
+
+/// Which way a fold line runs across.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Axis {
+    /// A vertical line at some `x`, folding the right side over to the left.
+    X,
+    /// A horizontal line at some `y`, folding the bottom up.
+    Y,
+}
+
+impl FromStr for Axis {
+    type Err = AocError;
+
+    /// `x` or `y`, as in `fold along x=5`.
+    fn from_str(s: &str) -> AocResult<Axis> {
+        match s {
+            "x" => Ok(Axis::X),
+            "y" => Ok(Axis::Y),
+            _ => Err(AocError::new(format!("expected `x` or `y`, found `{}`", s))),
+        }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// The grid laid down in `orientation`. Turning a quarter swaps the width and height.
+    pub fn orient(&self, orientation: Orientation) -> Grid<T> {
+        if self.is_empty() {
+            return self.clone();
+        }
+        let far = (self.width() as isize - 1, self.height() as isize - 1);
+        let (a, b) = (orientation.apply((0, 0)), orientation.apply(far));
+        let origin = (a.0.min(b.0), a.1.min(b.1));
+        let (width, height) = (
+            (a.0 - b.0).unsigned_abs() + 1,
+            (a.1 - b.1).unsigned_abs() + 1,
+        );
+
+        let inverse = orientation.inverse();
+        Grid::from_fn(width, height, |(x, y)| {
+            let (sx, sy) = inverse.apply((x as isize + origin.0, y as isize + origin.1));
+            self[(sx as usize, sy as usize)].clone()
+        })
+    }
+
+    /// A quarter turn clockwise.
+    pub fn rotate_cw(&self) -> Grid<T> {
+        self.orient(Orientation::new(1, false))
+    }
+
+    /// A mirror image, left to right.
+    pub fn flip_horizontal(&self) -> Grid<T> {
+        self.orient(Orientation::new(0, true))
+    }
+
+    /// A mirror image, top to bottom.
+    pub fn flip_vertical(&self) -> Grid<T> {
+        self.orient(Orientation::new(2, true))
+    }
+
+    /// Rows become columns.
+    pub fn transpose(&self) -> Grid<T> {
+        self.orient(Orientation::TRANSPOSE)
+    }
+
+    /// The `width` by `height` window with its top left at `(x, y)`, or `None` if it doesn't
+    /// fit on the grid.
+    pub fn crop(&self, (x, y): (usize, usize), width: usize, height: usize) -> Option<Grid<T>> {
+        if x + width > self.width() || y + height > self.height() {
+            return None;
+        }
+        Some(Grid::from_fn(width, height, |(dx, dy)| {
+            self[(x + dx, y + dy)].clone()
+        }))
+    }
+
+    /// `across` by `down` copies of the grid side by side, each cell of the copy at tile
+    /// `(tx, ty)` worked out from the original by `cell((tx, ty), original)`.
+    pub fn tile(
+        &self,
+        across: usize,
+        down: usize,
+        mut cell: impl FnMut((usize, usize), &T) -> T,
+    ) -> Grid<T> {
+        let (width, height) = (self.width(), self.height());
+        Grid::from_fn(width * across, height * down, |(x, y)| {
+            cell((x / width, y / height), &self[(x % width, y % height)])
+        })
+    }
+
+    /// Folds the grid along the line at `at`, dropping the line itself and laying the far side
+    /// over the near one mirror-wise. Where two cells land together they're combined with
+    /// `merge(near, far)`.
+    ///
+    /// Fails if the line isn't within the grid, or if the far side is bigger than the near side,
+    /// as it would hang off the edge.
+    pub fn fold(
+        &self,
+        axis: Axis,
+        at: usize,
+        mut merge: impl FnMut(&T, &T) -> T,
+    ) -> AocResult<Grid<T>> {
+        let (width, height) = match axis {
+            Axis::X => (at, self.height()),
+            Axis::Y => (self.width(), at),
+        };
+        let across = match axis {
+            Axis::X => self.width(),
+            Axis::Y => self.height(),
+        };
+        if at >= across {
+            return Err(AocError::new(format!(
+                "can't fold at {}, outside a grid {} across",
+                at, across
+            )));
+        }
+        let far = across - at - 1;
+        if far > at {
+            return Err(AocError::new(format!(
+                "folding at {} hangs {} cells off the edge",
+                at,
+                far - at
+            )));
+        }
+
+        Ok(Grid::from_fn(width, height, |(x, y)| {
+            let mirrored = match axis {
+                Axis::X => (2 * at - x, y),
+                Axis::Y => (x, 2 * at - y),
+            };
+            match self.get(mirrored) {
+                Some(far) => merge(&self[(x, y)], far),
+                None => self[(x, y)].clone(),
+            }
+        }))
+    }
+}
+
+impl<T: Clone> SparseGrid<T> {
+    /// Every cell moved to where `orientation` takes it, turning about the origin.
+    pub fn orient(&self, orientation: Orientation) -> SparseGrid<T> {
+        self.iter()
+            .map(|(point, value)| (orientation.apply(point), value.clone()))
+            .collect()
+    }
+
+    /// Rows become columns.
+    pub fn transpose(&self) -> SparseGrid<T> {
+        self.orient(Orientation::TRANSPOSE)
+    }
+
+    /// Just the cells within `bounds`.
+    pub fn crop(&self, bounds: Bounds) -> SparseGrid<T> {
+        self.iter()
+            .filter(|(point, _)| bounds.contains(*point))
+            .map(|(point, value)| (point, value.clone()))
+            .collect()
+    }
+
+    /// `across` by `down` copies of the occupied window side by side, each cell of the copy at
+    /// tile `(tx, ty)` worked out from the original by `cell((tx, ty), original)`.
+    pub fn tile(
+        &self,
+        across: usize,
+        down: usize,
+        mut cell: impl FnMut((usize, usize), &T) -> T,
+    ) -> SparseGrid<T> {
+        let Some(bounds) = self.bounds() else {
+            return SparseGrid::new();
+        };
+        let (width, height) = (bounds.width() as isize, bounds.height() as isize);
+        let mut tiled = SparseGrid::new();
+        for ty in 0..down {
+            for tx in 0..across {
+                for ((x, y), value) in self.iter() {
+                    let point = (x + tx as isize * width, y + ty as isize * height);
+                    tiled.insert(point, cell((tx, ty), value));
+                }
+            }
+        }
+        tiled
+    }
+
+    /// Folds the plane along the line at `at`, dropping any cells on the line and laying the
+    /// far side over the near one mirror-wise. Where two cells land together they're combined
+    /// with `merge(near, far)`.
+    pub fn fold(&self, axis: Axis, at: isize, mut merge: impl FnMut(&T, &T) -> T) -> SparseGrid<T> {
+        let mut folded = SparseGrid::new();
+        let (near, far): (Vec<_>, Vec<_>) = self
+            .iter()
+            .filter(|((x, y), _)| match axis {
+                Axis::X => *x != at,
+                Axis::Y => *y != at,
+            })
+            .partition(|((x, y), _)| match axis {
+                Axis::X => *x < at,
+                Axis::Y => *y < at,
+            });
+        folded.extend(
+            near.into_iter()
+                .map(|(point, value)| (point, value.clone())),
+        );
+
+        for ((x, y), value) in far {
+            let mirrored = match axis {
+                Axis::X => (2 * at - x, y),
+                Axis::Y => (x, 2 * at - y),
+            };
+            let value = match folded.get(mirrored) {
+                Some(near) => merge(near, value),
+                None => value.clone(),
+            };
+            folded.insert(mirrored, value);
+        }
+        folded
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn orientation() -> impl Strategy<Value = Orientation> {
+        (0..8_usize).prop_map(|index| Orientation::ALL[index])
+    }
+
+    fn grid() -> impl Strategy<Value = Grid<u8>> {
+        (1..6_usize, 1..6_usize).prop_flat_map(|(width, height)| {
+            proptest::collection::vec(any::<u8>(), width * height)
+                .prop_map(move |cells| Grid::from_fn(width, height, |(x, y)| cells[y * width + x]))
+        })
+    }
+
+    fn sparse_grid() -> impl Strategy<Value = SparseGrid<u8>> {
+        proptest::collection::vec(((-20..20_isize, -20..20_isize), any::<u8>()), 0..20)
+            .prop_map(|cells| cells.into_iter().collect())
+    }
+
+    #[test]
+    fn turns_and_flips() {
+        let grid = Grid::parse("ab\ncd\nef\n", "a letter", Some).unwrap();
+        assert_eq!("eca\nfdb\n", grid.rotate_cw().to_string());
+        assert_eq!("ba\ndc\nfe\n", grid.flip_horizontal().to_string());
+        assert_eq!("ef\ncd\nab\n", grid.flip_vertical().to_string());
+        assert_eq!("ace\nbdf\n", grid.transpose().to_string());
+        assert_eq!(
+            8,
+            Orientation::ALL
+                .iter()
+                .map(|o| grid.orient(*o))
+                .collect::<std::collections::HashSet<_>>()
+                .len()
+        );
+    }
+
+    #[test]
+    fn crop_tile_and_fold() {
+        let grid = Grid::parse("123\n456\n", "a digit", |c| c.to_digit(10)).unwrap();
+        assert_eq!("23\n56\n", grid.crop((1, 0), 2, 2).unwrap().to_string());
+        assert_eq!(None, grid.crop((2, 0), 2, 2));
+
+        let tiled = grid.tile(2, 1, |(tx, _), cell| cell + tx as u32);
+        assert_eq!("123234\n456567\n", tiled.to_string());
+
+        assert_eq!(
+            "3\n24\n",
+            grid.fold(Axis::X, 1, |near, far| near * far)
+                .unwrap()
+                .to_string()
+        );
+        assert_eq!(
+            "1\n2\n3\n",
+            grid.fold(Axis::Y, 1, |near, far| near + far)
+                .unwrap()
+                .transpose()
+                .to_string()
+        );
+        let err = grid.fold(Axis::X, 0, |near, _| *near).unwrap_err();
+        assert_eq!("folding at 0 hangs 2 cells off the edge", err.reason);
+        let err = grid.fold(Axis::Y, 2, |near, _| *near).unwrap_err();
+        assert_eq!("can't fold at 2, outside a grid 2 across", err.reason);
+
+        let points: SparseGrid<()> = [(0, 0), (4, 0), (2, 1), (3, 3)].into_iter().collect();
+        let folded = points.fold(Axis::X, 2, |_, _| ());
+        let mut folded: Vec<Point> = folded.points().collect();
+        folded.sort();
+        assert_eq!(vec![(0, 0), (1, 3)], folded);
+    }
+
+    proptest! {
+        #[test]
+        fn orientations_compose(a in orientation(), b in orientation(), x in -50..50_isize, y in -50..50_isize) {
+            prop_assert_eq!(b.apply(a.apply((x, y))), a.then(b).apply((x, y)));
+            prop_assert_eq!(Orientation::IDENTITY, a.then(a.inverse()));
+            prop_assert_eq!(Orientation::IDENTITY, a.inverse().then(a));
+        }
+
+        #[test]
+        fn grid_orientations_compose(grid in grid(), a in orientation(), b in orientation()) {
+            prop_assert_eq!(grid.orient(a).orient(b), grid.orient(a.then(b)));
+            prop_assert_eq!(&grid, &grid.orient(a).orient(a.inverse()));
+            prop_assert_eq!(&grid, &grid.transpose().transpose());
+            prop_assert_eq!(&grid, &grid.rotate_cw().rotate_cw().rotate_cw().rotate_cw());
+        }
+
+        #[test]
+        fn sparse_orientations_compose(grid in sparse_grid(), a in orientation(), b in orientation()) {
+            prop_assert_eq!(grid.orient(a).orient(b), grid.orient(a.then(b)));
+            prop_assert_eq!(&grid, &grid.transpose().transpose());
+            prop_assert_eq!(grid.len(), grid.orient(a).len());
+        }
+
+        #[test]
+        fn tiles_crop_back_out(grid in grid(), across in 1..4_usize, down in 1..4_usize) {
+            let tiled = grid.tile(across, down, |(tx, ty), cell| cell.wrapping_add((tx + ty) as u8));
+            let (width, height) = (grid.width(), grid.height());
+            for ty in 0..down {
+                for tx in 0..across {
+                    let tile = tiled.crop((tx * width, ty * height), width, height).unwrap();
+                    prop_assert_eq!(grid.map(|cell| cell.wrapping_add((tx + ty) as u8)), tile);
+                }
+            }
+        }
+
+        #[test]
+        fn folds_agree(grid in grid(), at in 2..4_usize) {
+            // a fold at the middle of a grid twice as wide, less the fold line
+            let wide = Grid::from_fn(2 * at + 1, grid.height(), |(x, y)| grid.get((x, y)).copied());
+            let dense = wide.fold(Axis::X, at, |near, far| near.or(*far)).unwrap();
+            let sparse: SparseGrid<u8> = wide
+                .indexed()
+                .filter_map(|((x, y), cell)| cell.map(|cell| ((x as isize, y as isize), cell)))
+                .collect::<SparseGrid<u8>>()
+                .fold(Axis::X, at as isize, |near, _| *near);
+            for ((x, y), cell) in dense.indexed() {
+                prop_assert_eq!(*cell, sparse.get((x as isize, y as isize)).copied());
+            }
+        }
+
+        #[test]
+        fn folds_stay_on_the_grid(grid in grid(), at in 0..20_usize, vertical in any::<bool>()) {
+            let (axis, across) = if vertical { (Axis::X, grid.width()) } else { (Axis::Y, grid.height()) };
+            let folded = grid.fold(axis, at, |near, _| *near);
+            prop_assert_eq!(at < across && 2 * at + 1 >= across, folded.is_ok());
+        }
+    }
+}
@@ -0,0 +1,170 @@
+//! `#[derive(FromLine)]`, re-exported as `aoc_common::FromLine`.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{quote, ToTokens};
+use syn::punctuated::Punctuated;
+use syn::{parse_macro_input, Attribute, Data, DeriveInput, Error, Fields, LitStr, Token};
+
+/// Derives `FromStr` for a type read from a line matching a regex, given by a `#[line(pattern)]`
+/// attribute on a struct or on each variant of an enum. Each capture group is parsed as the next
+/// field through the field's own `FromStr`, so the pattern needs one group per field. An enum is
+/// the first variant whose pattern matches.
+///
+/// ```ignore
+/// #[derive(FromLine)]
+/// #[line(r"^(\d+)-(\d+) (\w): (\w+)$", "`<first>-<second> <letter>: <password>`")]
+/// struct PasswordEntry {
+///     first: usize,
+///     second: usize,
+///     character: char,
+///     password: String,
+/// }
+/// ```
+///
+/// The optional second argument describes the line for when one doesn't match, which otherwise
+/// reports the pattern. The error is an `AocError` placed on the line on its own; a capture that
+/// fails to parse is named and pointed at. `aoc_common::parse::line` moves it to where the line
+/// sits in the whole input.
+#[proc_macro_derive(FromLine, attributes(line))]
+pub fn derive_from_line(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    from_line(&input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// What a `#[line(pattern)]` or `#[line(pattern, expected)]` attribute says.
+struct Line {
+    pattern: LitStr,
+    /// How to describe a line that should have matched, e.g. "`<x>,<y>`".
+    expected: String,
+    /// The number of capture groups in the pattern, not counting the whole match.
+    groups: usize,
+}
+
+impl Line {
+    /// The `#[line(...)]` attribute among `attrs`, which are those of `owner`.
+    fn find(attrs: &[Attribute], owner: impl ToTokens) -> syn::Result<Line> {
+        let attr = attrs
+            .iter()
+            .find(|attr| attr.path().is_ident("line"))
+            .ok_or_else(|| {
+                Error::new_spanned(&owner, "expected a `#[line(r\"pattern\")]` attribute")
+            })?;
+        let args = attr.parse_args_with(Punctuated::<LitStr, Token![,]>::parse_terminated)?;
+        let mut args = args.into_iter();
+        let (Some(pattern), expected, None) = (args.next(), args.next(), args.next()) else {
+            return Err(Error::new_spanned(
+                attr,
+                "expected `#[line(pattern)]` or `#[line(pattern, expected)]`",
+            ));
+        };
+
+        // the pattern is compiled again at run time, but any mistake in it is better found now
+        let regex = regex::Regex::new(&pattern.value())
+            .map_err(|e| Error::new(pattern.span(), format!("bad pattern: {}", e)))?;
+        Ok(Line {
+            expected: expected.map_or_else(|| format!("`{}`", pattern.value()), |e| e.value()),
+            groups: regex.captures_len() - 1,
+            pattern,
+        })
+    }
+
+    /// `path`, the struct or variant, built from `fields`, one capture group of `captures` each.
+    fn construct(&self, path: TokenStream2, fields: &Fields) -> syn::Result<TokenStream2> {
+        if fields.len() != self.groups {
+            return Err(Error::new(
+                self.pattern.span(),
+                format!(
+                    "pattern has {} capture groups for {} fields",
+                    self.groups,
+                    fields.len()
+                ),
+            ));
+        }
+
+        let values = fields.iter().enumerate().map(|(ind, field)| {
+            let index = ind + 1;
+            let name = field
+                .ident
+                .as_ref()
+                .map_or_else(|| ind.to_string(), |ident| ident.to_string());
+            quote!(::aoc_common::parse::capture(line, &captures, #index, #name)?)
+        });
+        Ok(match fields {
+            Fields::Named(_) => {
+                let names = fields.iter().map(|field| &field.ident);
+                quote!(#path { #(#names: #values),* })
+            }
+            Fields::Unnamed(_) => quote!(#path(#(#values),*)),
+            Fields::Unit => path,
+        })
+    }
+}
+
+fn from_line(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let name = &input.ident;
+    let body = match &input.data {
+        Data::Struct(data) => {
+            let line = Line::find(&input.attrs, name)?;
+            let (pattern, expected) = (&line.pattern, &line.expected);
+            let value = line.construct(quote!(#name), &data.fields)?;
+            if data.fields.is_empty() {
+                quote! {
+                    ::aoc_common::parse::captures(line, line, #pattern, #expected)?;
+                    Ok(#value)
+                }
+            } else {
+                quote! {
+                    let captures = ::aoc_common::parse::captures(line, line, #pattern, #expected)?;
+                    Ok(#value)
+                }
+            }
+        }
+        Data::Enum(data) => {
+            let mut arms = Vec::new();
+            let mut expected = Vec::new();
+            for variant in &data.variants {
+                let line = Line::find(&variant.attrs, variant)?;
+                let (pattern, ident) = (&line.pattern, &variant.ident);
+                let value = line.construct(quote!(#name::#ident), &variant.fields)?;
+                arms.push(if variant.fields.is_empty() {
+                    quote! {
+                        if ::aoc_common::parse::regex(#pattern).is_match(line) {
+                            return Ok(#value);
+                        }
+                    }
+                } else {
+                    quote! {
+                        if let Some(captures) = ::aoc_common::parse::regex(#pattern).captures(line) {
+                            return Ok(#value);
+                        }
+                    }
+                });
+                expected.push(line.expected);
+            }
+            let expected = expected.join(" or ");
+            quote! {
+                #(#arms)*
+                Err(::aoc_common::AocError::at(
+                    line,
+                    line,
+                    format!("expected {}, found `{}`", #expected, line),
+                ))
+            }
+        }
+        Data::Union(_) => return Err(Error::new_spanned(name, "unions can't derive `FromLine`")),
+    };
+
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::std::str::FromStr for #name #type_generics #where_clause {
+            type Err = ::aoc_common::AocError;
+
+            fn from_str(line: &str) -> ::aoc_common::AocResult<Self> {
+                #body
+            }
+        }
+    })
+}
@@ -0,0 +1,7 @@
+//! Misuses of `#[derive(FromLine)]` that should be caught at compile time, each with the error
+//! it should be caught with.
+
+#[test]
+fn compile_errors() {
+    trybuild::TestCases::new().compile_fail("tests/ui/*.rs");
+}
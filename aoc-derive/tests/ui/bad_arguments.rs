@@ -0,0 +1,7 @@
+use aoc_common::FromLine;
+
+#[derive(FromLine)]
+#[line(r"^(\d+)$", "`<n>`", "a number")]
+struct Count(usize);
+
+fn main() {}
@@ -0,0 +1,10 @@
+use aoc_common::FromLine;
+
+#[derive(FromLine)]
+#[line(r"^(\d+,(\d+)$")]
+struct Point {
+    x: isize,
+    y: isize,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use aoc_common::FromLine;
+
+#[derive(FromLine)]
+#[line(r"^(\d+),(\d+)$")]
+struct Point {
+    x: isize,
+    y: isize,
+    z: isize,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use aoc_common::FromLine;
+
+#[derive(FromLine)]
+struct Point {
+    x: isize,
+    y: isize,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use aoc_common::FromLine;
+
+#[derive(FromLine)]
+enum Instruction {
+    #[line(r"^nop$")]
+    Nop,
+    Acc(isize),
+}
+
+fn main() {}
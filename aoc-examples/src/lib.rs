@@ -0,0 +1,226 @@
+//! Worked examples pulled out of a day's module docs, so the puzzle text and the tests can't
+//! drift apart.
+//!
+//! A fenced block becomes an example when its info string says `example`, along with the answers
+//! the puzzle text gives for it:
+//!
+//! ````text
+//! //! ```skip example part_one=514579
+//! //! 1721
+//! //! 979
+//! //! ```
+//! ````
+//!
+//! Examples the puzzle only gives inline are tagged with an HTML comment instead, which rustdoc
+//! doesn't render:
+//!
+//! ```text
+//! //!   - Given the starting numbers 1,3,2, the 2020th number spoken is 1.
+//! //! <!-- example "1,3,2" part_one=1 -->
+//! ```
+//!
+//! A year crate's build script turns every example into a test that runs it through that year's
+//! registry with `aoc_common::examples::check`, one test per tagged answer. Being used from build
+//! scripts, this crate has no dependencies, `aoc-common` included.
+
+use std::fmt::Write;
+
+/// Which part of the puzzle an example's answer is for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+/// One worked example from a module's docs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    /// The (1-based) line of the source file the example starts on.
+    pub line: usize,
+    pub input: String,
+    pub answers: Vec<(Part, String)>,
+}
+
+/// Parses the `part_one=<answer>` and `part_two=<answer>` tags out of `tags`, ignoring anything
+/// else. `line` is only used for error messages.
+fn parse_answers<'a>(
+    tags: impl Iterator<Item = &'a str>,
+    line: usize,
+) -> Result<Vec<(Part, String)>, String> {
+    let mut answers = vec![];
+    for tag in tags {
+        let (part, answer) = match tag.split_once('=') {
+            Some(("part_one", answer)) => (Part::One, answer),
+            Some(("part_two", answer)) => (Part::Two, answer),
+            _ => continue,
+        };
+        if answer.is_empty() {
+            return Err(format!("line {}: `{}` has no answer", line, tag));
+        }
+        answers.push((part, answer.to_string()));
+    }
+    if answers.is_empty() {
+        return Err(format!(
+            "line {}: an example needs a `part_one=` or `part_two=` answer",
+            line
+        ));
+    }
+    Ok(answers)
+}
+
+/// Finds every tagged example in the `//!` docs of a source file.
+pub fn extract(source: &str) -> Result<Vec<Example>, String> {
+    let mut examples = vec![];
+    // the example whose fenced block is being read, if any
+    let mut open: Option<Example> = None;
+    // whether an untagged block is being read
+    let mut in_block = false;
+
+    for (index, line) in source.lines().enumerate() {
+        let line_number = index + 1;
+        let Some(doc) = line.trim_start().strip_prefix("//!") else {
+            continue;
+        };
+        let doc = doc.strip_prefix(' ').unwrap_or(doc);
+
+        if let Some(info) = doc.trim().strip_prefix("```") {
+            if let Some(example) = open.take() {
+                examples.push(example);
+            } else if in_block {
+                in_block = false;
+            } else if info.split([' ', ',']).any(|tag| tag == "example") {
+                open = Some(Example {
+                    line: line_number,
+                    input: String::new(),
+                    answers: parse_answers(info.split([' ', ',']), line_number)?,
+                });
+            } else {
+                in_block = true;
+            }
+        } else if let Some(example) = &mut open {
+            example.input.push_str(doc);
+            example.input.push('\n');
+        } else if let Some(tag) = doc.trim().strip_prefix("<!-- example ") {
+            let tag = tag
+                .strip_suffix("-->")
+                .ok_or_else(|| format!("line {}: unterminated example tag", line_number))?;
+            let (input, tags) = tag
+                .trim()
+                .strip_prefix('"')
+                .and_then(|rest| rest.split_once('"'))
+                .ok_or_else(|| format!("line {}: expected a quoted example input", line_number))?;
+            examples.push(Example {
+                line: line_number,
+                input: input.to_string(),
+                answers: parse_answers(tags.split_whitespace(), line_number)?,
+            });
+        }
+    }
+
+    if let Some(example) = open {
+        return Err(format!("line {}: unterminated example", example.line));
+    }
+    Ok(examples)
+}
+
+/// Writes the tests for one day's examples as Rust source: a module named after the day holding
+/// the example inputs as `EXAMPLES` and one test per tagged answer.
+pub fn generate_tests(day: u8, examples: &[Example]) -> String {
+    let mut out = String::new();
+    let _ = writeln!(out, "pub mod day_{:02} {{", day);
+    let _ = writeln!(out, "    #[allow(dead_code)]");
+    let _ = writeln!(
+        out,
+        "    pub const EXAMPLES: [&str; {}] = [",
+        examples.len()
+    );
+    for example in examples {
+        let _ = writeln!(out, "        {:?},", example.input);
+    }
+    let _ = writeln!(out, "    ];");
+    for (index, example) in examples.iter().enumerate() {
+        for (part, answer) in &example.answers {
+            let name = match part {
+                Part::One => "part_one",
+                Part::Two => "part_two",
+            };
+            let _ = writeln!(out);
+            let _ = writeln!(out, "    /// The example on line {}.", example.line);
+            let _ = writeln!(out, "    #[test]");
+            let _ = writeln!(out, "    fn example_{}_{}() {{", index + 1, name);
+            let _ = writeln!(
+                out,
+                "        aoc_common::examples::check(crate::register, crate::YEAR, {}, aoc_common::Part::{:?}, EXAMPLES[{}], {:?});",
+                day, part, index, answer
+            );
+            let _ = writeln!(out, "    }}");
+        }
+    }
+    let _ = writeln!(out, "}}");
+    out
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const SOURCE: &str = r#"//! Some puzzle text.
+//! ```skip example part_one=514579 part_two=241861950
+//! 1721
+//!
+//! 979
+//! ```
+//!
+//! ```skip
+//! not an example
+//! ```
+//!   - Given the starting numbers 1,3,2, the 2020th number spoken is 1.
+//! <!-- example "1,3,2" part_one=1 -->
+
+pub fn part_one() {}
+"#;
+
+    #[test]
+    fn extracts_examples() {
+        let examples = extract(SOURCE).unwrap();
+        assert_eq!(
+            vec![
+                Example {
+                    line: 2,
+                    input: "1721\n\n979\n".to_string(),
+                    answers: vec![
+                        (Part::One, "514579".to_string()),
+                        (Part::Two, "241861950".to_string())
+                    ],
+                },
+                Example {
+                    line: 12,
+                    input: "1,3,2".to_string(),
+                    answers: vec![(Part::One, "1".to_string())],
+                },
+            ],
+            examples
+        );
+    }
+
+    #[test]
+    fn rejects_bad_tags() {
+        assert!(extract("//! ```example\n//! 1\n//! ```\n").is_err());
+        assert!(extract("//! ```example part_one=\n//! 1\n//! ```\n").is_err());
+        assert!(extract("//! ```example part_one=1\n//! 1\n").is_err());
+        assert!(extract("//! <!-- example 1,3,2 part_one=1 -->\n").is_err());
+        assert!(extract("//! <!-- example \"1,3,2\" part_one=1\n").is_err());
+    }
+
+    #[test]
+    fn generates_tests() {
+        let examples = extract(SOURCE).unwrap();
+        let tests = generate_tests(1, &examples);
+        assert!(tests.starts_with("pub mod day_01 {"));
+        assert!(tests.contains("\"1721\\n\\n979\\n\","));
+        assert!(tests.contains("fn example_1_part_two() {"));
+        assert!(tests.contains(
+            "check(crate::register, crate::YEAR, 1, aoc_common::Part::One, EXAMPLES[1], \"1\");"
+        ));
+    }
+}
@@ -0,0 +1,326 @@
+//! `aoc bench`: times parsing and each part separately over repeated runs, and compares the
+//! result against a saved baseline report.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::time::{Duration, Instant};
+
+use aoc_common::{input, AocResult, DynSolution, Part, Registry};
+use serde::{Deserialize, Serialize};
+
+/// Changes smaller than this are noise no matter how large they are relative to the baseline.
+const NOISE_FLOOR: Duration = Duration::from_micros(50);
+
+/// Summary of the samples for one stage, in nanoseconds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Stats {
+    pub samples: usize,
+    pub median_ns: u64,
+    /// Median absolute deviation from the median.
+    pub mad_ns: u64,
+    pub min_ns: u64,
+    pub max_ns: u64,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Stats {
+        assert!(!samples.is_empty(), "no samples to summarize");
+        let mut nanos: Vec<u64> = samples.iter().map(|d| d.as_nanos() as u64).collect();
+        nanos.sort_unstable();
+
+        let median_ns = median(&nanos);
+        let mut deviations: Vec<u64> = nanos.iter().map(|n| n.abs_diff(median_ns)).collect();
+        deviations.sort_unstable();
+
+        Stats {
+            samples: nanos.len(),
+            median_ns,
+            mad_ns: median(&deviations),
+            min_ns: nanos[0],
+            max_ns: nanos[nanos.len() - 1],
+        }
+    }
+
+    pub fn median(&self) -> Duration {
+        Duration::from_nanos(self.median_ns)
+    }
+}
+
+/// Median of already sorted values.
+fn median(sorted: &[u64]) -> u64 {
+    let mid = sorted.len() / 2;
+    if sorted.len().is_multiple_of(2) {
+        (sorted[mid - 1] + sorted[mid]) / 2
+    } else {
+        sorted[mid]
+    }
+}
+
+/// The timed pieces of a day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Stage {
+    Parse,
+    PartOne,
+    PartTwo,
+}
+
+impl Stage {
+    pub const ALL: [Stage; 3] = [Stage::Parse, Stage::PartOne, Stage::PartTwo];
+}
+
+impl fmt::Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(match self {
+            Stage::Parse => "parse",
+            Stage::PartOne => "part 1",
+            Stage::PartTwo => "part 2",
+        })
+    }
+}
+
+/// Timings for one registered day. Days that couldn't be run say why instead.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DayReport {
+    pub year: u16,
+    pub day: u8,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub skipped: Option<String>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub stages: BTreeMap<Stage, Stats>,
+}
+
+impl DayReport {
+    pub fn stage(&self, stage: Stage) -> Option<&Stats> {
+        self.stages.get(&stage)
+    }
+}
+
+/// A full benchmark run, as written to (and read back from) JSON.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BenchReport {
+    /// Whether the run was an optimized build. Debug timings aren't worth comparing.
+    pub optimized: bool,
+    pub days: Vec<DayReport>,
+}
+
+impl BenchReport {
+    pub fn get(&self, year: u16, day: u8) -> Option<&DayReport> {
+        self.days.iter().find(|d| d.year == year && d.day == day)
+    }
+}
+
+/// How hard to try for stable numbers.
+pub struct Options {
+    /// Runs per day. Every stage gets one sample per run.
+    pub runs: usize,
+    /// Stop repeating a day once it has used this much time, as long as it ran at least once.
+    pub budget: Duration,
+}
+
+/// Benchmarks every registered day matching the filters.
+pub fn bench(
+    registry: &Registry,
+    year: Option<u16>,
+    day: Option<u8>,
+    options: &Options,
+) -> BenchReport {
+    let mut days = Vec::new();
+    for ((y, d), entry) in registry.iter() {
+        if year.is_some_and(|year| year != y) || day.is_some_and(|day| day != d) {
+            continue;
+        }
+
+        let mut report = DayReport {
+            year: y,
+            day: d,
+            skipped: None,
+            stages: BTreeMap::new(),
+        };
+        match (entry.solution(), input::load(y, d)) {
+            (None, _) => report.skipped = Some("unimplemented".into()),
+            (Some(_), Err(_)) => report.skipped = Some("no input".into()),
+            (Some(solution), Ok(input)) => match bench_day(solution, &input, options) {
+                Ok(stages) => report.stages = stages,
+                Err(e) => report.skipped = Some(format!("error: {}", e.reason)),
+            },
+        }
+        days.push(report);
+    }
+
+    BenchReport {
+        optimized: !cfg!(debug_assertions),
+        days,
+    }
+}
+
+fn bench_day(
+    solution: &dyn DynSolution,
+    input: &str,
+    options: &Options,
+) -> AocResult<BTreeMap<Stage, Stats>> {
+    let mut samples: [Vec<Duration>; 3] = Default::default();
+
+    let started = Instant::now();
+    for run in 0..options.runs.max(1) {
+        if run > 0 && started.elapsed() > options.budget {
+            break;
+        }
+
+        let start = Instant::now();
+        let parsed = solution.parse(input)?;
+        samples[0].push(start.elapsed());
+
+        for (ind, part) in Part::BOTH.into_iter().enumerate() {
+            let start = Instant::now();
+            std::hint::black_box(solution.solve(parsed.as_ref(), part)?);
+            samples[ind + 1].push(start.elapsed());
+        }
+    }
+
+    Ok(Stage::ALL
+        .into_iter()
+        .zip(samples.iter())
+        .map(|(stage, samples)| (stage, Stats::from_samples(samples)))
+        .collect())
+}
+
+/// One stage's median measured against the baseline.
+#[derive(Debug, Clone, Copy)]
+pub struct Comparison {
+    pub baseline: Duration,
+    pub current: Duration,
+}
+
+impl Comparison {
+    /// Relative change from the baseline, e.g. `0.1` for 10% slower.
+    pub fn change(&self) -> f64 {
+        if self.baseline.is_zero() {
+            return 0.0;
+        }
+        self.current.as_secs_f64() / self.baseline.as_secs_f64() - 1.0
+    }
+
+    /// Slower than the baseline by more than `threshold` (relative) and more than noise.
+    pub fn is_regression(&self, threshold: f64) -> bool {
+        self.change() > threshold && self.current.saturating_sub(self.baseline) > NOISE_FLOOR
+    }
+}
+
+pub fn compare(current: &Stats, baseline: &Stats) -> Comparison {
+    Comparison {
+        baseline: baseline.median(),
+        current: current.median(),
+    }
+}
+
+/// Prints one row per stage, with the baseline comparison when there is one. Returns the
+/// number of regressions beyond `threshold`.
+pub fn print_report(report: &BenchReport, baseline: Option<&BenchReport>, threshold: f64) -> usize {
+    let mut regressions = 0;
+    println!(
+        "year day stage  {:>12} {:>12} runs {:>12} {:>8}",
+        "median", "spread", "baseline", "change"
+    );
+    for day in &report.days {
+        if let Some(reason) = &day.skipped {
+            println!("{} {:>3} skipped: {}", day.year, day.day, reason);
+            continue;
+        }
+
+        let baseline_day = baseline.and_then(|b| b.get(day.year, day.day));
+        for (stage, stats) in &day.stages {
+            let mut line = format!(
+                "{} {:>3} {:6} {:>12} {:>12} {:>4}",
+                day.year,
+                day.day,
+                stage,
+                format!("{:?}", stats.median()),
+                format!("±{:?}", Duration::from_nanos(stats.mad_ns)),
+                stats.samples
+            );
+            if let Some(base) = baseline_day.and_then(|b| b.stage(*stage)) {
+                let comparison = compare(stats, base);
+                line += &format!(
+                    " {:>12} {:>+7.1}%",
+                    format!("{:?}", comparison.baseline),
+                    comparison.change() * 100.0
+                );
+                if comparison.is_regression(threshold) {
+                    line += "  REGRESSION";
+                    regressions += 1;
+                }
+            }
+            println!("{}", line);
+        }
+    }
+
+    regressions
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn ms(millis: &[u64]) -> Vec<Duration> {
+        millis.iter().map(|m| Duration::from_millis(*m)).collect()
+    }
+
+    #[test]
+    fn stats() {
+        let stats = Stats::from_samples(&ms(&[5, 1, 3, 100, 4]));
+        assert_eq!(5, stats.samples);
+        assert_eq!(Duration::from_millis(4), stats.median());
+        // deviations are 1, 3, 1, 96, 0
+        assert_eq!(1_000_000, stats.mad_ns);
+        assert_eq!(1_000_000, stats.min_ns);
+        assert_eq!(100_000_000, stats.max_ns);
+
+        assert_eq!(
+            Duration::from_millis(3),
+            Stats::from_samples(&ms(&[2, 4])).median()
+        );
+    }
+
+    #[test]
+    fn regressions() {
+        let slower = Comparison {
+            baseline: Duration::from_millis(10),
+            current: Duration::from_millis(12),
+        };
+        assert!(slower.is_regression(0.1));
+        assert!(!slower.is_regression(0.25));
+
+        // 100% slower, but well within the noise
+        let tiny = Comparison {
+            baseline: Duration::from_micros(5),
+            current: Duration::from_micros(10),
+        };
+        assert!(!tiny.is_regression(0.1));
+    }
+
+    #[test]
+    fn report_round_trip() {
+        let report = BenchReport {
+            optimized: true,
+            days: vec![
+                DayReport {
+                    year: 2021,
+                    day: 1,
+                    skipped: None,
+                    stages: BTreeMap::from([(Stage::Parse, Stats::from_samples(&ms(&[1, 2, 3])))]),
+                },
+                DayReport {
+                    year: 2021,
+                    day: 18,
+                    skipped: Some("unimplemented".into()),
+                    stages: BTreeMap::new(),
+                },
+            ],
+        };
+        let json = serde_json::to_string(&report).unwrap();
+        assert_eq!(report, serde_json::from_str(&json).unwrap());
+        assert!(report.get(2021, 1).unwrap().stage(Stage::Parse).is_some());
+        assert!(report.get(2021, 1).unwrap().stage(Stage::PartOne).is_none());
+    }
+}
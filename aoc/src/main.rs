@@ -0,0 +1,500 @@
+//! `aoc`: runs any registered day against a puzzle input and reports the answer.
+
+mod bench;
+mod scaffold;
+
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::{Duration, Instant};
+
+use aoc_common::answers::{self, Answers};
+use aoc_common::image::{self, Image};
+use aoc_common::logging::{self, Filter};
+use aoc_common::render::{self, Frame, Player, Theme};
+use aoc_common::{input, AocResult, DynSolution, Part, Registry};
+use clap::{Args, Parser, Subcommand};
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code solutions runner")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+
+    /// What the solvers log to stderr: `quiet`, `info`, `trace`, ... optionally per day, as in
+    /// `info,aoc_2021::day_13=trace`. Falls back to `AOC_LOG`, then to `quiet`.
+    #[arg(long, global = true)]
+    log: Option<Filter>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run one day, or every implemented day of a year.
+    Run(RunArgs),
+    /// Play back a day's run through its input in the terminal.
+    Animate(AnimateArgs),
+    /// Check every implemented day against the recorded answers.
+    Verify(VerifyArgs),
+    /// Time parsing and both parts of every day, optionally against a baseline.
+    Bench(BenchArgs),
+    /// Generate a day, or a whole year crate, from the templates.
+    New(NewArgs),
+}
+
+#[derive(Args)]
+struct RunArgs {
+    /// The puzzle year.
+    #[arg(long)]
+    year: u16,
+
+    /// The puzzle day.
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25), required_unless_present = "all")]
+    day: Option<u8>,
+
+    /// Only run the given part; both parts are run otherwise.
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+
+    /// Run every implemented day of the year in order.
+    #[arg(long, conflicts_with = "day")]
+    all: bool,
+
+    /// The puzzle input, or `-` for stdin. With `--all` this is a directory holding one
+    /// `day_DD.txt` per day. Defaults to the input store (see `aoc.toml`).
+    #[arg(long)]
+    input: Option<PathBuf>,
+}
+
+#[derive(Args)]
+struct AnimateArgs {
+    /// The puzzle year.
+    #[arg(long)]
+    year: u16,
+
+    /// The puzzle day.
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+
+    /// The puzzle input, or `-` for stdin. Defaults to the input store (see `aoc.toml`).
+    #[arg(long)]
+    input: Option<PathBuf>,
+
+    /// Frames per second.
+    #[arg(long, default_value_t = 10.0)]
+    fps: f64,
+
+    /// `classic`, `vivid` or `plain`. Defaults to `classic`, or `plain` if `NO_COLOR` is set.
+    #[arg(long)]
+    theme: Option<Theme>,
+
+    /// Save the animation instead of playing it: all of it to a `.gif`, or its last frame to a
+    /// `.png` or `.ppm`.
+    #[arg(long)]
+    export: Option<PathBuf>,
+
+    /// Pixels per character in exported images.
+    #[arg(long, default_value_t = 4, value_parser = clap::value_parser!(u16).range(1..))]
+    scale: u16,
+}
+
+#[derive(Args)]
+struct VerifyArgs {
+    /// Only check the given year.
+    #[arg(long)]
+    year: Option<u16>,
+
+    /// Record answers that aren't in the answers file yet. Changed answers are never
+    /// overwritten; fix the solution or edit the file by hand.
+    #[arg(long)]
+    record: bool,
+}
+
+#[derive(Args)]
+struct BenchArgs {
+    /// Only benchmark the given year.
+    #[arg(long)]
+    year: Option<u16>,
+
+    /// Only benchmark the given day.
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: Option<u8>,
+
+    /// Runs per day.
+    #[arg(long, default_value_t = 10)]
+    runs: usize,
+
+    /// Seconds a day may spend on repeated runs; slow days stop early, after at least one run.
+    #[arg(long, default_value_t = 10.0)]
+    budget: f64,
+
+    /// Where to write the JSON report.
+    #[arg(long, default_value = "bench.json")]
+    report: PathBuf,
+
+    /// A previous report to compare against.
+    #[arg(long)]
+    baseline: Option<PathBuf>,
+
+    /// Flag stages whose median is this many percent slower than the baseline.
+    #[arg(long, default_value_t = 10.0)]
+    threshold: f64,
+
+    /// Overwrite the baseline with this run instead of comparing against it.
+    #[arg(long, requires = "baseline")]
+    save_baseline: bool,
+}
+
+#[derive(Args)]
+struct NewArgs {
+    /// The puzzle year. Its crate is created if it doesn't exist yet.
+    #[arg(long)]
+    year: u16,
+
+    /// The day to add. Without it, a new crate is created with all 25 days.
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: Option<u8>,
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    match cli.log {
+        Some(filter) => logging::init(filter),
+        None => logging::init_from_env(),
+    }
+    let result = match cli.command {
+        Command::Run(args) => run(&registry(), &args),
+        Command::Animate(args) => animate(&registry(), &args),
+        Command::Verify(args) => verify(&registry(), &args),
+        Command::Bench(args) => bench(&registry(), &args),
+        Command::New(args) => new(&args),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("error: {}", message);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Every year's days, registered in one place.
+fn registry() -> Registry {
+    let mut registry = Registry::new();
+    aoc_2020::register(&mut registry);
+    aoc_2021::register(&mut registry);
+    registry
+}
+
+fn run(registry: &Registry, args: &RunArgs) -> Result<(), String> {
+    let parts: &[Part] = match args.part {
+        Some(1) => &[Part::One],
+        Some(_) => &[Part::Two],
+        None => &Part::BOTH,
+    };
+
+    if !registry.years().contains(&args.year) {
+        return Err(format!("no solutions registered for {}", args.year));
+    }
+
+    if args.all {
+        let dir = match &args.input {
+            Some(dir) => dir.clone(),
+            None => input::input_dir().join(args.year.to_string()),
+        };
+
+        let mut failures = 0;
+        for (day, entry) in registry.year(args.year) {
+            let Some(solution) = entry.solution() else {
+                continue;
+            };
+            let path = dir.join(format!("day_{:02}.txt", day));
+            if !path.is_file() {
+                println!(
+                    "{} day {:02}: skipped, no {}",
+                    args.year,
+                    day,
+                    path.display()
+                );
+                continue;
+            }
+            if let Err(e) = run_day(args.year, day, solution, &read_input(&path)?, parts) {
+                println!("{} day {:02}: {}", args.year, day, e);
+                failures += 1;
+            }
+        }
+
+        if failures > 0 {
+            return Err(format!("{} days failed", failures));
+        }
+        return Ok(());
+    }
+
+    let day = args.day.expect("clap requires --day without --all");
+    let solution = registry
+        .solution(args.year, day)
+        .ok_or_else(|| format!("{} day {} is not implemented", args.year, day))?;
+
+    let input = day_input(args.year, day, args.input.as_deref())?;
+    run_day(args.year, day, solution, &input, parts)
+        .map_err(|e| format!("{} day {:02}: {}", args.year, day, e))
+}
+
+fn animate(registry: &Registry, args: &AnimateArgs) -> Result<(), String> {
+    if args.fps <= 0.0 {
+        return Err(format!("--fps must be positive, not {}", args.fps));
+    }
+    let solution = registry
+        .solution(args.year, args.day)
+        .ok_or_else(|| format!("{} day {} is not implemented", args.year, args.day))?;
+
+    let input = day_input(args.year, args.day, args.input.as_deref())?;
+    let frames = solution
+        .parse(&input)
+        .and_then(|parsed| solution.animate(parsed.as_ref()))
+        .map_err(|e| format!("{} day {:02}: {}", args.year, args.day, e))?;
+    if frames.is_empty() {
+        return Err(format!(
+            "{} day {:02} has nothing to animate",
+            args.year, args.day
+        ));
+    }
+
+    if let Some(path) = &args.export {
+        return export(&frames, path, usize::from(args.scale), args.fps);
+    }
+
+    // controls are read a line at a time, so stdin can't carry the input as well
+    let controls = if args.input.as_deref() == Some(Path::new("-")) {
+        std::sync::mpsc::channel().1
+    } else {
+        println!("p: pause, n: next, b: back, g <frame>: seek, +/-: speed, q: quit (then enter)");
+        render::stdin_controls()
+    };
+    let mut player = Player::new(frames)
+        .with_theme(args.theme.unwrap_or_else(Theme::from_env))
+        .with_fps(args.fps);
+    player
+        .play(&mut std::io::stdout().lock(), &controls)
+        .map_err(|e| format!("failed to write to stdout: {}", e))
+}
+
+fn verify(registry: &Registry, args: &VerifyArgs) -> Result<(), String> {
+    let path = answers::answers_path();
+    let mut answers = Answers::load(&path).map_err(|e| e.to_string())?;
+
+    let report = answers::verify(registry, &answers, args.year);
+    println!("{}", report);
+
+    if args.record {
+        let recorded = report.record_new(&mut answers);
+        answers
+            .save(&path)
+            .map_err(|e| format!("failed to write {}: {}", path.display(), e))?;
+        println!("recorded {} new answers in {}", recorded, path.display());
+    }
+
+    if report.has_failures() {
+        return Err(format!(
+            "{} answers changed, {} failed",
+            report.count(answers::Status::Changed),
+            report.count(answers::Status::Failed)
+        ));
+    }
+    Ok(())
+}
+
+fn bench(registry: &Registry, args: &BenchArgs) -> Result<(), String> {
+    if cfg!(debug_assertions) {
+        eprintln!("warning: benchmarking an unoptimized build; use `cargo run --release`");
+    }
+
+    let options = bench::Options {
+        runs: args.runs,
+        budget: Duration::from_secs_f64(args.budget),
+    };
+    let report = bench::bench(registry, args.year, args.day, &options);
+    write_json(&args.report, &report)?;
+
+    let baseline = match &args.baseline {
+        Some(path) if !args.save_baseline => {
+            let json = std::fs::read_to_string(path)
+                .map_err(|e| format!("failed to read {}: {}", path.display(), e))?;
+            let baseline: bench::BenchReport = serde_json::from_str(&json)
+                .map_err(|e| format!("invalid baseline {}: {}", path.display(), e))?;
+            if baseline.optimized != report.optimized {
+                eprintln!("warning: comparing optimized and unoptimized builds");
+            }
+            Some(baseline)
+        }
+        _ => None,
+    };
+
+    let regressions = bench::print_report(&report, baseline.as_ref(), args.threshold / 100.0);
+    println!("report written to {}", args.report.display());
+
+    if args.save_baseline {
+        let path = args.baseline.as_ref().expect("clap requires --baseline");
+        write_json(path, &report)?;
+        println!("baseline saved to {}", path.display());
+    }
+
+    if regressions > 0 {
+        return Err(format!(
+            "{} stages regressed by more than {}%",
+            regressions, args.threshold
+        ));
+    }
+    Ok(())
+}
+
+fn new(args: &NewArgs) -> Result<(), String> {
+    let cwd = std::env::current_dir().map_err(|e| e.to_string())?;
+    let workspace = scaffold::Workspace::find(&cwd)?;
+    let written = match args.day {
+        Some(day) => workspace.new_day(args.year, day)?,
+        None => workspace.new_year(args.year)?,
+    };
+    for path in written {
+        println!("wrote {}", path.display());
+    }
+    Ok(())
+}
+
+fn write_json(path: &Path, report: &bench::BenchReport) -> Result<(), String> {
+    let json = serde_json::to_string_pretty(report).expect("reports always serialize");
+    std::fs::write(path, json + "\n")
+        .map_err(|e| format!("failed to write {}: {}", path.display(), e))
+}
+
+/// Saves the frames as a GIF, or the last one as a still, going by `path`'s extension.
+fn export(frames: &[Frame], path: &Path, scale: usize, fps: f64) -> Result<(), String> {
+    let draw = |frame: &Frame| Image::from_frame(frame, scale, image::tone_color);
+    let gif = path.extension().is_some_and(|extension| extension == "gif");
+    let result = if gif {
+        let images: Vec<Image> = frames.iter().map(draw).collect();
+        image::save_gif(&images, Duration::from_secs_f64(1.0 / fps), path)
+    } else {
+        draw(frames.last().expect("there's at least one frame")).save(path)
+    };
+    result.map_err(|e| format!("failed to write {}: {}", path.display(), e))?;
+    println!("wrote {}", path.display());
+    Ok(())
+}
+
+/// The input at `path`, stdin for `-`, or the stored input for the day.
+fn day_input(year: u16, day: u8, path: Option<&Path>) -> Result<String, String> {
+    match path {
+        Some(path) if path == Path::new("-") => {
+            let mut input = String::new();
+            std::io::stdin()
+                .read_to_string(&mut input)
+                .map_err(|e| format!("failed to read stdin: {}", e))?;
+            Ok(input)
+        }
+        Some(path) => read_input(path),
+        None => input::load(year, day).map_err(|e| format!("no input: {}", e)),
+    }
+}
+
+fn read_input(path: &Path) -> Result<String, String> {
+    std::fs::read_to_string(path).map_err(|e| format!("failed to read {}: {}", path.display(), e))
+}
+
+/// Parses `input` once, then runs and times each requested part.
+fn run_day(
+    year: u16,
+    day: u8,
+    solution: &dyn DynSolution,
+    input: &str,
+    parts: &[Part],
+) -> AocResult<()> {
+    let start = Instant::now();
+    let parsed = solution.parse(input)?;
+    println!("{} day {:02} parse: {:?}", year, day, start.elapsed());
+
+    for part in parts {
+        let start = Instant::now();
+        let answer = solution.solve(parsed.as_ref(), *part)?;
+        println!(
+            "{} day {:02} part {}: {} ({:?})",
+            year,
+            day,
+            part,
+            answer,
+            start.elapsed()
+        );
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use clap::CommandFactory;
+
+    #[test]
+    fn cli_is_well_formed() {
+        Cli::command().debug_assert();
+    }
+
+    #[test]
+    fn day_or_all_required() {
+        assert!(Cli::try_parse_from(["aoc", "run", "--year", "2020"]).is_err());
+        assert!(Cli::try_parse_from(["aoc", "run", "--year", "2020", "--day", "26"]).is_err());
+        assert!(
+            Cli::try_parse_from(["aoc", "run", "--year", "2020", "--all", "--day", "1"]).is_err()
+        );
+        assert!(Cli::try_parse_from(["aoc", "run", "--year", "2020", "--all"]).is_ok());
+    }
+
+    #[test]
+    fn animate_args() {
+        let cli = Cli::try_parse_from([
+            "aoc", "animate", "--year", "2021", "--day", "11", "--theme", "vivid",
+        ])
+        .unwrap();
+        let Command::Animate(args) = cli.command else {
+            panic!("expected the animate command");
+        };
+        assert_eq!(Some(Theme::VIVID), args.theme);
+        assert_eq!(10.0, args.fps);
+        assert!(Cli::try_parse_from([
+            "aoc", "animate", "--year", "2021", "--day", "11", "--theme", "sepia"
+        ])
+        .is_err());
+    }
+
+    #[test]
+    fn log_filter() {
+        let cli = Cli::try_parse_from(["aoc", "bench", "--log", "info,aoc_2021=trace"]).unwrap();
+        assert!(cli.log.is_some());
+        assert!(Cli::try_parse_from(["aoc", "--log", "quiet", "verify"]).is_ok());
+        assert!(Cli::try_parse_from(["aoc", "verify", "--log", "loud"]).is_err());
+    }
+
+    /// Runs every day with an input against `answers.txt`.
+    #[test]
+    fn recorded_answers() {
+        use std::io::Write;
+
+        let answers = Answers::load(&answers::answers_path()).unwrap();
+        let report = answers::verify(&registry(), &answers, None);
+        // straight to stderr so the table shows up even when the test passes
+        let _ = writeln!(std::io::stderr(), "{}", report);
+        assert!(
+            !report.has_failures(),
+            "recorded answers changed or failed:\n{}",
+            report
+        );
+    }
+
+    #[test]
+    fn both_years_registered() {
+        let registry = registry();
+        assert_eq!(vec![2020, 2021], registry.years());
+        assert!(registry.solution(2020, 1).is_some());
+        assert!(registry.solution(2021, 17).is_some());
+    }
+}
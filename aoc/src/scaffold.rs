@@ -0,0 +1,321 @@
+//! `aoc new`: generates days and year crates from the templates in `aoc/templates`.
+//!
+//! A new day starts out as a stub module (`#[cfg(test)] mod day_DD;`) that its year registers
+//! as unimplemented, like any other stub. Once it's solved, make the module `pub` and register
+//! `day_DD::DayDD` in place of the stub.
+
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+const DAY_TEMPLATE: &str = include_str!("../templates/day.rs");
+const LIB_TEMPLATE: &str = include_str!("../templates/lib.rs");
+const MANIFEST_TEMPLATE: &str = include_str!("../templates/Cargo.toml.in");
+
+/// Fills in a template's `{{year}}`, `{{day}}` and `{{day02}}` placeholders.
+fn render(template: &str, year: u16, day: u8) -> String {
+    template
+        .replace("{{year}}", &year.to_string())
+        .replace("{{day02}}", &format!("{:02}", day))
+        .replace("{{day}}", &day.to_string())
+}
+
+/// The cargo workspace the solutions live in.
+pub struct Workspace {
+    root: PathBuf,
+}
+
+impl Workspace {
+    pub fn new(root: impl Into<PathBuf>) -> Workspace {
+        Workspace { root: root.into() }
+    }
+
+    /// The nearest directory at or above `dir` whose `Cargo.toml` declares a workspace.
+    pub fn find(dir: &Path) -> Result<Workspace, String> {
+        dir.ancestors()
+            .find(|dir| {
+                fs::read_to_string(dir.join("Cargo.toml"))
+                    .is_ok_and(|manifest| manifest.lines().any(|line| line.trim() == "[workspace]"))
+            })
+            .map(Workspace::new)
+            .ok_or_else(|| format!("no cargo workspace at or above {}", dir.display()))
+    }
+
+    fn crate_dir(&self, year: u16) -> PathBuf {
+        self.root.join(format!("aoc-{}", year))
+    }
+
+    /// Creates the crate for `year` with all 25 days, returning the files written.
+    pub fn new_year(&self, year: u16) -> Result<Vec<PathBuf>, String> {
+        let mut written = self.create_crate(year)?;
+        for day in 1..=25 {
+            for path in self.new_day(year, day)? {
+                if !written.contains(&path) {
+                    written.push(path);
+                }
+            }
+        }
+        Ok(written)
+    }
+
+    /// Adds `day` to the crate for `year`, creating the crate first if there isn't one. Never
+    /// touches an existing day.
+    pub fn new_day(&self, year: u16, day: u8) -> Result<Vec<PathBuf>, String> {
+        let mut written = vec![];
+        if !self.crate_dir(year).exists() {
+            written = self.create_crate(year)?;
+        }
+
+        let lib = self.crate_dir(year).join("src").join("lib.rs");
+        let contents = read(&lib)?;
+        let module = self
+            .crate_dir(year)
+            .join("src")
+            .join(format!("day_{:02}.rs", day));
+        if module.exists() {
+            return Err(format!("{} already exists", module.display()));
+        }
+        let contents = add_module(&contents, day)
+            .ok_or_else(|| format!("{} already declares day_{:02}", lib.display(), day))?;
+
+        create_new(&module, &render(DAY_TEMPLATE, year, day))?;
+        write(&lib, &contents)?;
+        written.extend([module, lib]);
+        Ok(written)
+    }
+
+    /// Creates the crate for `year` without any days, and wires it into the workspace and the
+    /// runner.
+    fn create_crate(&self, year: u16) -> Result<Vec<PathBuf>, String> {
+        let dir = self.crate_dir(year);
+        if dir.exists() {
+            return Err(format!("{} already exists", dir.display()));
+        }
+
+        let workspace_manifest = self.root.join("Cargo.toml");
+        let runner_manifest = self.root.join("aoc").join("Cargo.toml");
+        let runner_main = self.root.join("aoc").join("src").join("main.rs");
+        let missing =
+            |path: &Path| format!("don't know where to add aoc-{} in {}", year, path.display());
+        // work everything out before writing anything, so a failure leaves the tree untouched
+        let workspace = add_member(&read(&workspace_manifest)?, year)
+            .ok_or_else(|| missing(&workspace_manifest))?;
+        let runner = add_dependency(&read(&runner_manifest)?, year)
+            .ok_or_else(|| missing(&runner_manifest))?;
+        let main =
+            add_registration(&read(&runner_main)?, year).ok_or_else(|| missing(&runner_main))?;
+
+        fs::create_dir_all(dir.join("src"))
+            .map_err(|e| format!("failed to create {}: {}", dir.display(), e))?;
+        let manifest = dir.join("Cargo.toml");
+        let lib = dir.join("src").join("lib.rs");
+        create_new(&manifest, &render(MANIFEST_TEMPLATE, year, 0))?;
+        create_new(&lib, &render(LIB_TEMPLATE, year, 0))?;
+        write(&workspace_manifest, &workspace)?;
+        write(&runner_manifest, &runner)?;
+        write(&runner_main, &main)?;
+
+        Ok(vec![
+            manifest,
+            lib,
+            workspace_manifest,
+            runner_manifest,
+            runner_main,
+        ])
+    }
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|e| format!("failed to read {}: {}", path.display(), e))
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), String> {
+    fs::write(path, contents).map_err(|e| format!("failed to write {}: {}", path.display(), e))
+}
+
+/// Writes a file that mustn't exist yet.
+fn create_new(path: &Path, contents: &str) -> Result<(), String> {
+    OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(path)
+        .and_then(|mut file| file.write_all(contents.as_bytes()))
+        .map_err(|e| format!("failed to create {}: {}", path.display(), e))
+}
+
+/// The day number of a `mod day_DD;` or `pub mod day_DD;` line.
+fn module_day(line: &str) -> Option<u8> {
+    let line = line.trim();
+    let line = line.strip_prefix("pub ").unwrap_or(line);
+    line.strip_prefix("mod day_")?
+        .strip_suffix(';')?
+        .parse()
+        .ok()
+}
+
+/// Declares `day` as a stub module in a year's `lib.rs`, keeping the modules in day order.
+/// `None` if it's already declared.
+fn add_module(lib: &str, day: u8) -> Option<String> {
+    let mut lines: Vec<&str> = lib.lines().collect();
+    if lines.iter().any(|line| module_day(line) == Some(day)) {
+        return None;
+    }
+
+    let declaration = format!("mod day_{:02};", day);
+    let later = lines
+        .iter()
+        .position(|line| module_day(line).is_some_and(|other| other > day));
+    let last = lines.iter().rposition(|line| module_day(line).is_some());
+    match (later, last) {
+        (Some(mut index), _) => {
+            // keep a following module's `#[cfg(test)]` attached to it
+            if index > 0 && lines[index - 1].trim() == "#[cfg(test)]" {
+                index -= 1;
+            }
+            lines.splice(index..index, ["#[cfg(test)]", &declaration]);
+        }
+        (None, Some(index)) => {
+            lines.splice(index + 1..index + 1, ["#[cfg(test)]", &declaration]);
+        }
+        (None, None) => {
+            // the first module goes between the imports and the rest of the file
+            let index = lines.iter().position(|line| line.is_empty())?;
+            lines.splice(index + 1..index + 1, ["#[cfg(test)]", &declaration, ""]);
+        }
+    }
+
+    Some(lines.join("\n") + "\n")
+}
+
+/// Adds `aoc-<year>` to the workspace `members` list.
+fn add_member(manifest: &str, year: u16) -> Option<String> {
+    let start = manifest.find("members = [")?;
+    let end = start + manifest[start..].find(']')?;
+    let line_start = manifest[..end].rfind('\n')? + 1;
+    Some(format!(
+        "{}    \"aoc-{}\",\n{}",
+        &manifest[..line_start],
+        year,
+        &manifest[line_start..]
+    ))
+}
+
+/// Adds the year crate to the runner's dependencies, after the other `aoc-*` ones.
+fn add_dependency(manifest: &str, year: u16) -> Option<String> {
+    let mut lines: Vec<&str> = manifest.lines().collect();
+    let index = lines.iter().rposition(|line| line.starts_with("aoc-"))?;
+    let dependency = format!("aoc-{0} = {{ path = \"../aoc-{0}\" }}", year);
+    lines.insert(index + 1, &dependency);
+    Some(lines.join("\n") + "\n")
+}
+
+/// Adds the year's days to the runner's registry, after the other years.
+fn add_registration(main: &str, year: u16) -> Option<String> {
+    let mut lines: Vec<&str> = main.lines().collect();
+    let index = lines.iter().rposition(|line| {
+        line.trim_start().starts_with("aoc_") && line.contains("::register(&mut registry);")
+    })?;
+    let indent = &lines[index][..lines[index].len() - lines[index].trim_start().len()];
+    let registration = format!("{}aoc_{}::register(&mut registry);", indent, year);
+    lines.insert(index + 1, &registration);
+    Some(lines.join("\n") + "\n")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn renders_templates() {
+        let day = render(DAY_TEMPLATE, 2022, 3);
+        assert!(day.contains("pub struct Day03;"));
+        assert!(day.contains("personal_input!(crate::YEAR, 3)"));
+        assert!(!day.contains("{{"));
+        assert!(render(MANIFEST_TEMPLATE, 2022, 0).contains("name = \"aoc-2022\""));
+        assert!(render(LIB_TEMPLATE, 2022, 0).contains("pub const YEAR: u16 = 2022;"));
+    }
+
+    #[test]
+    fn adds_modules_in_order() {
+        let lib = "use aoc_common::Registry;\n\npub mod day_01;\n#[cfg(test)]\nmod day_04;\n\npub const YEAR: u16 = 2022;\n";
+        let lib = add_module(lib, 2).unwrap();
+        assert_eq!(
+            "use aoc_common::Registry;\n\npub mod day_01;\n#[cfg(test)]\nmod day_02;\n#[cfg(test)]\nmod day_04;\n\npub const YEAR: u16 = 2022;\n",
+            lib
+        );
+        let lib = add_module(&lib, 5).unwrap();
+        assert!(lib.contains("mod day_04;\n#[cfg(test)]\nmod day_05;\n\npub const"));
+        assert_eq!(None, add_module(&lib, 1));
+
+        let empty = render(LIB_TEMPLATE, 2022, 0);
+        let lib = add_module(&empty, 7).unwrap();
+        assert!(lib.starts_with(
+            "use aoc_common::Registry;\n\n#[cfg(test)]\nmod day_07;\n\n/// The event year"
+        ));
+    }
+
+    #[test]
+    fn wires_up_crates() {
+        let manifest = "[workspace]\nmembers = [\n    \"aoc\",\n    \"aoc-2021\",\n]\n";
+        assert_eq!(
+            "[workspace]\nmembers = [\n    \"aoc\",\n    \"aoc-2021\",\n    \"aoc-2022\",\n]\n",
+            add_member(manifest, 2022).unwrap()
+        );
+
+        let manifest = "[dependencies]\naoc-common = { path = \"../aoc-common\" }\naoc-2021 = { path = \"../aoc-2021\" }\nclap = \"4\"\n";
+        assert!(add_dependency(manifest, 2022).unwrap().contains(
+            "aoc-2021 = { path = \"../aoc-2021\" }\naoc-2022 = { path = \"../aoc-2022\" }\nclap"
+        ));
+
+        let main = "fn registry() -> Registry {\n    aoc_2021::register(&mut registry);\n    registry\n}\n";
+        assert!(add_registration(main, 2022).unwrap().contains(
+            "    aoc_2021::register(&mut registry);\n    aoc_2022::register(&mut registry);\n"
+        ));
+        assert_eq!(None, add_registration("fn main() {}\n", 2022));
+    }
+
+    #[test]
+    fn generates_days_without_overwriting() {
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("aoc").join("src")).unwrap();
+        fs::write(
+            root.join("Cargo.toml"),
+            "[workspace]\nmembers = [\n    \"aoc\",\n]\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join("aoc").join("Cargo.toml"),
+            "[dependencies]\naoc-common = { path = \"../aoc-common\" }\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join("aoc").join("src").join("main.rs"),
+            "fn registry() -> Registry {\n    aoc_2021::register(&mut registry);\n}\n",
+        )
+        .unwrap();
+
+        let workspace = Workspace::find(&root.join("aoc").join("src")).unwrap();
+        let written = workspace.new_day(2030, 3).unwrap();
+        assert!(written.contains(&root.join("aoc-2030").join("Cargo.toml")));
+        let day = root.join("aoc-2030").join("src").join("day_03.rs");
+        assert_eq!(
+            render(DAY_TEMPLATE, 2030, 3),
+            fs::read_to_string(&day).unwrap()
+        );
+
+        // a second run changes nothing
+        fs::write(&day, "solved").unwrap();
+        assert!(workspace.new_day(2030, 3).is_err());
+        assert!(workspace.new_year(2030).is_err());
+        assert_eq!("solved", fs::read_to_string(&day).unwrap());
+
+        workspace.new_day(2030, 1).unwrap();
+        let lib = fs::read_to_string(root.join("aoc-2030").join("src").join("lib.rs")).unwrap();
+        assert!(lib.contains("mod day_01;\n#[cfg(test)]\nmod day_03;\n"));
+        let workspace_manifest = fs::read_to_string(root.join("Cargo.toml")).unwrap();
+        assert_eq!(1, workspace_manifest.matches("\"aoc-2030\"").count());
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
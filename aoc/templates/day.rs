@@ -0,0 +1,55 @@
+//! AOC {{year}} day {{day}}
+
+use aoc_common::{Answer, AocResult, Solution};
+
+pub struct Day{{day02}};
+
+impl Solution for Day{{day02}} {
+    type Input = Vec<String>;
+
+    fn parse(&self, input: &str) -> AocResult<Self::Input> {
+        Ok(input.lines().map(str::to_string).collect())
+    }
+
+    fn part_one(&self, input: &Self::Input) -> AocResult<Answer> {
+        Ok(input.len().into())
+    }
+
+    fn part_two(&self, input: &Self::Input) -> AocResult<Answer> {
+        Ok(input.len().into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use aoc_common::personal_input;
+
+    const EXAMPLE_INPUT: &str = "";
+
+    #[test]
+    fn example_part_one() {
+        let input = Day{{day02}}.parse(EXAMPLE_INPUT).unwrap();
+        assert_eq!(0, Day{{day02}}.part_one(&input).unwrap());
+    }
+
+    #[test]
+    fn example_part_two() {
+        let input = Day{{day02}}.parse(EXAMPLE_INPUT).unwrap();
+        assert_eq!(0, Day{{day02}}.part_two(&input).unwrap());
+    }
+
+    #[test]
+    fn test_part_one() {
+        let input = personal_input!(crate::YEAR, {{day}});
+        let input = Day{{day02}}.parse(&input).unwrap();
+        println!("part one: {}", Day{{day02}}.part_one(&input).unwrap());
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = personal_input!(crate::YEAR, {{day}});
+        let input = Day{{day02}}.parse(&input).unwrap();
+        println!("part two: {}", Day{{day02}}.part_two(&input).unwrap());
+    }
+}
@@ -0,0 +1,19 @@
+use aoc_common::Registry;
+
+/// The event year these solutions belong to.
+pub const YEAR: u16 = {{year}};
+
+/// Installs the logger before any test runs, so `AOC_LOG` picks what the solvers print.
+#[cfg(test)]
+#[ctor::ctor]
+fn init_logging() {
+    aoc_common::logging::init_from_env();
+}
+
+/// Registers every {{year}} day with the given registry.
+pub fn register(registry: &mut Registry) {
+    // still stubs
+    for day in 1..=25 {
+        registry.register_unimplemented(YEAR, day);
+    }
+}